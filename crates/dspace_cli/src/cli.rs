@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::output::OutputFormat;
+
+
+pub const DATASPACE_PROTOCOL: &str = "dataspace-protocol-http";
+
+/// Manages EDC connectors through their management api
+#[derive(Debug, Parser)]
+#[command(name = "dspace", version)]
+pub struct Cli {
+    /// Config file with the connector profiles, by default `$XDG_CONFIG_HOME/dspace/config.toml`
+    #[arg(long, global = true, env = "DSPACE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Profile of the connector, by default the `default` profile of the config file
+    #[arg(short, long, global = true, env = "DSPACE_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Management api of the connector, overrides the base path of the profile
+    #[arg(long, global = true, env = "DSPACE_BASE_PATH")]
+    pub base_path: Option<String>,
+
+    /// Api key of the management api, overrides the api key of the profile
+    #[arg(long, global = true, env = "DSPACE_API_KEY", hide_env_values = true)]
+    pub api_key: Option<String>,
+
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Assets of the connector
+    #[command(subcommand)]
+    Assets(AssetsCommand),
+    /// Policy definitions of the connector
+    #[command(subcommand)]
+    Policies(PoliciesCommand),
+    /// Contract definitions of the connector
+    #[command(subcommand)]
+    ContractDefinitions(ContractDefinitionsCommand),
+    /// Creates, updates or deletes assets, policy and contract definitions until the connector matches a manifest
+    Apply(ApplyArgs),
+    /// Catalogs of other connectors
+    #[command(subcommand)]
+    Catalog(CatalogCommand),
+    /// Negotiates a contract for an asset of a provider
+    Negotiate(NegotiateArgs),
+    /// Contract negotiations of the connector
+    #[command(subcommand)]
+    Negotiations(ProcessCommand),
+    /// Starts a transfer for a contract agreement
+    Transfer(TransferArgs),
+    /// Transfer processes of the connector
+    #[command(subcommand)]
+    Transfers(ProcessCommand),
+    /// Endpoint data references of the connector
+    #[command(subcommand)]
+    Edr(EdrCommand),
+    /// Lists the profiles of the config file
+    Profiles,
+}
+
+#[derive(Debug, Args)]
+pub struct ListArgs {
+    /// Lists at most this many entries
+    #[arg(long)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+pub struct FileArgs {
+    /// JSON file with one definition or a list of them, `-` reads from stdin
+    #[arg(short, long)]
+    pub file: PathBuf,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AssetsCommand {
+    List(ListArgs),
+    Get { id: String },
+    /// Creates an asset from a file or from the given options
+    Create(CreateAssetArgs),
+    Delete { id: String },
+}
+
+#[derive(Debug, Args)]
+pub struct CreateAssetArgs {
+    /// JSON file with the asset, the other options are ignored if it is given
+    #[arg(short, long, conflicts_with_all = ["id", "base_url", "property"])]
+    pub file: Option<PathBuf>,
+    #[arg(long)]
+    pub id: Option<String>,
+    /// Base url of an `HttpData` data address
+    #[arg(long, required_unless_present = "file")]
+    pub base_url: Option<String>,
+    /// Property of the asset as `key=value`, can be repeated
+    #[arg(long, value_parser = parse_key_value)]
+    pub property: Vec<(String, String)>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PoliciesCommand {
+    List(ListArgs),
+    Get { id: String },
+    /// Creates the policy definitions of a file, or updates them if they exist
+    Apply(FileArgs),
+    Delete { id: String },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ContractDefinitionsCommand {
+    List(ListArgs),
+    Get { id: String },
+    /// Creates the contract definitions of a file, or updates them if they exist
+    Apply(FileArgs),
+    Delete { id: String },
+}
+
+#[derive(Debug, Args)]
+pub struct ApplyArgs {
+    /// YAML or JSON manifest with `assets`, `policyDefinitions` and `contractDefinitions`, `-` reads from stdin
+    #[arg(short, long)]
+    pub file: PathBuf,
+    /// Only prints the plan without changing the connector
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Also deletes the definitions of the connector that are not in the manifest
+    #[arg(long)]
+    pub prune: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CatalogCommand {
+    /// Requests the catalog of a provider and lists its datasets
+    Fetch(CounterPartyArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CounterPartyArgs {
+    /// Protocol address of the provider, e.g. `http://provider:9194/protocol`
+    #[arg(long)]
+    pub counterparty: String,
+    /// Participant id of the provider
+    #[arg(long)]
+    pub counterparty_id: Option<String>,
+    #[arg(long, default_value = DATASPACE_PROTOCOL)]
+    pub protocol: String,
+}
+
+#[derive(Debug, Args)]
+pub struct WaitArgs {
+    /// Waits until the process reached its goal
+    #[arg(long)]
+    pub wait: bool,
+    /// Seconds to wait at most
+    #[arg(long, default_value_t = 60)]
+    pub timeout: u64,
+}
+
+#[derive(Debug, Args)]
+pub struct NegotiateArgs {
+    #[command(flatten)]
+    pub counter_party: CounterPartyArgs,
+    /// Asset to negotiate a contract for
+    #[arg(long)]
+    pub asset: String,
+    /// Id of the offer, by default the first offer of the dataset in the catalog of the provider
+    #[arg(long)]
+    pub offer: Option<String>,
+    #[command(flatten)]
+    pub wait: WaitArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct TransferArgs {
+    #[command(flatten)]
+    pub counter_party: CounterPartyArgs,
+    /// Contract agreement the transfer is made under
+    #[arg(long)]
+    pub agreement: String,
+    /// Asset to transfer, by default the asset of the agreement
+    #[arg(long)]
+    pub asset: Option<String>,
+    #[arg(long, default_value = "HttpData-PULL")]
+    pub transfer_type: String,
+    /// JSON file with the data address of the destination, by default an `HttpProxy`
+    #[arg(long)]
+    pub destination: Option<PathBuf>,
+    #[command(flatten)]
+    pub wait: WaitArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProcessCommand {
+    List(ListArgs),
+    Get { id: String },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EdrCommand {
+    List(ListArgs),
+    /// Gets the data address of the EDR for a transfer process
+    Get { transfer_process_id: String },
+    Delete { transfer_process_id: String },
+}
+
+fn parse_key_value(value: &str) -> Result<(String, String), String> {
+    value.split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected key=value, got `{}`", value))
+}
@@ -0,0 +1,362 @@
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use futures::{Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use edc_api::contract_negotiation::ContractNegotiationState;
+use edc_api::transfer_state::TransferProcessState;
+use edc_client::json_ld::EDC_NS;
+use edc_client::pagination::DEFAULT_PAGE_SIZE;
+use edc_client::reconcile::Manifest;
+use edc_client::watch::WaitError;
+use edc_client::{EdcClient, EdcError, Error};
+
+use crate::cli::{ApplyArgs, AssetsCommand, CatalogCommand, Command, ContractDefinitionsCommand, CreateAssetArgs, EdrCommand, ListArgs, NegotiateArgs, PoliciesCommand, ProcessCommand, TransferArgs};
+use crate::config::Config;
+use crate::output::{self, or_dash, OutputFormat, Tabular};
+use crate::CliError;
+
+
+pub(crate) async fn run(client: &EdcClient, command: Command, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        Command::Assets(command) => assets(client, command, format, out).await,
+        Command::Policies(command) => policies(client, command, format, out).await,
+        Command::ContractDefinitions(command) => contract_definitions(client, command, format, out).await,
+        Command::Apply(args) => apply(client, args, format, out).await,
+        Command::Catalog(command) => catalog(client, command, format, out).await,
+        Command::Negotiate(args) => negotiate(client, args, format, out).await,
+        Command::Negotiations(ProcessCommand::List(args)) => {
+            let negotiations = collect(client.negotiations().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            Ok(output::print_list(out, format, &negotiations)?)
+        },
+        Command::Negotiations(ProcessCommand::Get { id }) => Ok(output::print_one(out, format, &client.negotiations().get(&id).await?)?),
+        Command::Transfer(args) => transfer(client, args, format, out).await,
+        Command::Transfers(ProcessCommand::List(args)) => {
+            let transfers = collect(client.transfers().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            Ok(output::print_list(out, format, &transfers)?)
+        },
+        Command::Transfers(ProcessCommand::Get { id }) => Ok(output::print_one(out, format, &client.transfers().get(&id).await?)?),
+        Command::Edr(command) => edr(client, command, format, out).await,
+        Command::Profiles => unreachable!("profiles don't need a connector"),
+    }
+}
+
+pub(crate) fn profiles(config: &Config, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    if format == OutputFormat::Json {
+        let profiles = config.profiles.iter()
+            .map(|(name, profile)| serde_json::json!({ "name": name, "basePath": profile.base_path, "default": config.default.as_deref() == Some(name) }))
+            .collect::<Vec<_>>();
+        return Ok(output::print_json(out, &profiles)?);
+    }
+    let rows = config.profiles.iter()
+        .map(|(name, profile)| vec![
+            name.clone(),
+            profile.base_path.clone(),
+            if config.default.as_deref() == Some(name) { "*".to_string() } else { String::new() },
+        ])
+        .collect();
+    Ok(output::render_table(out, &["NAME", "BASE PATH", "DEFAULT"], rows)?)
+}
+
+async fn assets(client: &EdcClient, command: AssetsCommand, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        AssetsCommand::List(args) => {
+            let assets = collect(client.assets().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            output::print_list(out, format, &assets)?;
+        },
+        AssetsCommand::Get { id } => output::print_one(out, format, &client.assets().get(&id).await?)?,
+        AssetsCommand::Create(args) => {
+            let mut created = Vec::new();
+            for asset in asset_inputs(args)? {
+                created.push(client.assets().create(asset).await?);
+            }
+            output::print_list(out, format, &created)?;
+        },
+        AssetsCommand::Delete { id } => client.assets().delete(&id).await?,
+    }
+    Ok(())
+}
+
+fn asset_inputs(args: CreateAssetArgs) -> Result<Vec<edc_api::AssetInput>, CliError> {
+    if let Some(file) = args.file {
+        return read_definitions(&file);
+    }
+    let data_address = edc_api::DataAddress::new(None, Some("HttpData".to_string()), args.base_url);
+    let properties = args.property.into_iter().map(|(key, value)| (key, serde_json::Value::String(value))).collect();
+    Ok(vec![edc_api::AssetInput {
+        at_id: args.id,
+        data_address: Box::new(data_address),
+        properties,
+        ..edc_api::AssetInput::default()
+    }])
+}
+
+async fn policies(client: &EdcClient, command: PoliciesCommand, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        PoliciesCommand::List(args) => {
+            let policies = collect(client.policies().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            output::print_list(out, format, &policies)?;
+        },
+        PoliciesCommand::Get { id } => output::print_one(out, format, &client.policies().get(&id).await?)?,
+        PoliciesCommand::Apply(args) => {
+            let mut applied = Vec::new();
+            for policy_definition in read_definitions::<edc_api::PolicyDefinitionInput>(&args.file)? {
+                let result = match policy_definition.at_id.clone() {
+                    Some(id) if exists(client.policies().get(&id).await)? => {
+                        client.policies().update(&id, policy_definition).await?;
+                        Applied::updated(id)
+                    },
+                    _ => Applied::created(client.policies().create(policy_definition).await?),
+                };
+                applied.push(result);
+            }
+            output::print_list(out, format, &applied)?;
+        },
+        PoliciesCommand::Delete { id } => client.policies().delete(&id).await?,
+    }
+    Ok(())
+}
+
+async fn contract_definitions(client: &EdcClient, command: ContractDefinitionsCommand, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        ContractDefinitionsCommand::List(args) => {
+            let contract_definitions = collect(client.contract_definitions().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            output::print_list(out, format, &contract_definitions)?;
+        },
+        ContractDefinitionsCommand::Get { id } => output::print_one(out, format, &client.contract_definitions().get(&id).await?)?,
+        ContractDefinitionsCommand::Apply(args) => {
+            let mut applied = Vec::new();
+            for contract_definition in read_definitions::<edc_api::ContractDefinitionInput>(&args.file)? {
+                let result = match contract_definition.at_id.clone() {
+                    Some(id) if exists(client.contract_definitions().get(&id).await)? => {
+                        client.contract_definitions().update(contract_definition).await?;
+                        Applied::updated(id)
+                    },
+                    _ => Applied::created(client.contract_definitions().create(contract_definition).await?),
+                };
+                applied.push(result);
+            }
+            output::print_list(out, format, &applied)?;
+        },
+        ContractDefinitionsCommand::Delete { id } => client.contract_definitions().delete(&id).await?,
+    }
+    Ok(())
+}
+
+async fn apply(client: &EdcClient, args: ApplyArgs, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    let manifest = read_manifest(&args.file)?;
+    let reconcile = client.reconcile(manifest).with_prune(args.prune);
+    let plan = if args.dry_run {
+        reconcile.plan().await?
+    } else {
+        reconcile.apply().await?
+    };
+    Ok(output::print_list(out, format, &plan.steps)?)
+}
+
+async fn catalog(client: &EdcClient, command: CatalogCommand, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        CatalogCommand::Fetch(args) => {
+            let catalog = client.catalog().request(edc_api::CatalogRequest {
+                counter_party_address: args.counterparty,
+                counter_party_id: args.counterparty_id,
+                protocol: args.protocol,
+                ..edc_api::CatalogRequest::default()
+            }).await?;
+            match format {
+                OutputFormat::Json => output::print_json(out, &catalog)?,
+                OutputFormat::Table => output::print_list(out, format, &catalog.dataset)?,
+            }
+        },
+    }
+    Ok(())
+}
+
+async fn negotiate(client: &EdcClient, args: NegotiateArgs, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    let counter_party = args.counter_party;
+    let (offer_id, assigner) = match args.offer {
+        Some(offer_id) => (offer_id, counter_party.counterparty_id.clone()),
+        None => {
+            let dataset = client.catalog().dataset(edc_api::DatasetRequest {
+                at_id: Some(args.asset.clone()),
+                counter_party_address: Some(counter_party.counterparty.clone()),
+                counter_party_id: counter_party.counterparty_id.clone(),
+                protocol: Some(counter_party.protocol.clone()),
+                ..edc_api::DatasetRequest::default()
+            }).await?;
+            let offer = dataset.has_policy.into_iter().next()
+                .ok_or_else(|| CliError::Api(format!("The provider offers no contract for asset {}", args.asset)))?;
+            (offer.at_id, offer.assigner.or(counter_party.counterparty_id.clone()))
+        },
+    };
+    let assigner = assigner.ok_or_else(|| CliError::Input("--counterparty-id is needed to negotiate a given offer".to_string()))?;
+
+    let contract_request = edc_api::ContractRequest {
+        counter_party_address: counter_party.counterparty,
+        policy: Some(edc_api::Offer {
+            context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String("http://www.w3.org/ns/odrl/2/".to_string()))]),
+            at_type: Some("Offer".to_string()),
+            at_id: offer_id,
+            assigner,
+            target: args.asset,
+        }),
+        protocol: counter_party.protocol,
+        ..edc_api::ContractRequest::default()
+    };
+    let negotiation_id = client.negotiations().initiate(contract_request).await?.at_id
+        .ok_or_else(|| CliError::Api("The connector returned no id for the contract negotiation".to_string()))?;
+
+    let negotiation = if args.wait.wait {
+        let finalized = |state: &ContractNegotiationState| *state == ContractNegotiationState::Finalized;
+        waited(client.negotiations().wait_until(&negotiation_id, finalized, Duration::from_secs(args.wait.timeout)).await, "Contract negotiation")?
+    } else {
+        client.negotiations().get(&negotiation_id).await?
+    };
+    Ok(output::print_one(out, format, &negotiation)?)
+}
+
+async fn transfer(client: &EdcClient, args: TransferArgs, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    let asset_id = match args.asset {
+        Some(asset_id) => asset_id,
+        None => client.agreements().get(&args.agreement).await?.asset_id
+            .ok_or_else(|| CliError::Api(format!("Contract agreement {} names no asset, use --asset", args.agreement)))?,
+    };
+    let data_destination = match &args.destination {
+        Some(file) => read_json(file)?,
+        None => edc_api::DataAddress::new(None, Some("HttpProxy".to_string()), None),
+    };
+
+    let transfer_request = edc_api::TransferRequest {
+        asset_id,
+        contract_id: args.agreement,
+        counter_party_address: args.counter_party.counterparty,
+        data_destination: Box::new(data_destination),
+        protocol: args.counter_party.protocol,
+        transfer_type: args.transfer_type,
+        ..edc_api::TransferRequest::default()
+    };
+    let transfer_process_id = client.transfers().initiate(transfer_request).await?.at_id
+        .ok_or_else(|| CliError::Api("The connector returned no id for the transfer process".to_string()))?;
+
+    let transfer_process = if args.wait.wait {
+        // Push transfers of small data may already be completed when they are polled
+        let started = |state: &TransferProcessState| matches!(state, TransferProcessState::Started | TransferProcessState::Completed);
+        waited(client.transfers().wait_until(&transfer_process_id, started, Duration::from_secs(args.wait.timeout)).await, "Transfer process")?
+    } else {
+        client.transfers().get(&transfer_process_id).await?
+    };
+    Ok(output::print_one(out, format, &transfer_process)?)
+}
+
+async fn edr(client: &EdcClient, command: EdrCommand, format: OutputFormat, out: &mut dyn Write) -> Result<(), CliError> {
+    match command {
+        EdrCommand::List(args) => {
+            let edrs = collect(client.edrs().query_stream(None, DEFAULT_PAGE_SIZE), &args).await?;
+            output::print_list(out, format, &edrs)?;
+        },
+        EdrCommand::Get { transfer_process_id } => {
+            let data_address = client.edrs().data_address(&transfer_process_id).await?;
+            output::print_data_address(out, format, &data_address)?;
+        },
+        EdrCommand::Delete { transfer_process_id } => client.edrs().delete(&transfer_process_id).await?,
+    }
+    Ok(())
+}
+
+/// Result of applying a definition
+#[derive(Debug, Serialize)]
+struct Applied {
+    #[serde(rename = "@id")]
+    id: Option<String>,
+    result: &'static str,
+}
+
+impl Applied {
+
+    fn created(response: edc_api::IdResponse) -> Applied {
+        Applied { id: response.at_id, result: "created" }
+    }
+
+    fn updated(id: String) -> Applied {
+        Applied { id: Some(id), result: "updated" }
+    }
+
+}
+
+impl Tabular for Applied {
+    const HEADERS: &'static [&'static str] = &["ID", "RESULT"];
+
+    fn row(&self) -> Vec<String> {
+        vec![or_dash(self.id.as_deref()), self.result.to_string()]
+    }
+}
+
+/// Pages through the stream until it ends or the limit is reached
+async fn collect<T>(stream: impl Stream<Item = Result<T, Error<EdcError>>>, args: &ListArgs) -> Result<Vec<T>, CliError> {
+    let stream = stream.take(args.limit.unwrap_or(usize::MAX));
+    Ok(stream.try_collect().await?)
+}
+
+fn exists<T>(response: Result<T, Error<EdcError>>) -> Result<bool, CliError> {
+    match response {
+        Ok(_) => Ok(true),
+        Err(e) if e.is_not_found() => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn waited<T, S: edc_client::watch::Stateful>(result: Result<T, WaitError<S, EdcError>>, process: &str) -> Result<T, CliError> {
+    result.map_err(|e| match e {
+        WaitError::Api(e) => e.into(),
+        e => CliError::Api(format!("{} {}", process, e)),
+    })
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, CliError> {
+    serde_json::from_value(read_value(path)?).map_err(|e| CliError::Input(format!("Invalid content of {}: {}", path.display(), e)))
+}
+
+/// Reads one definition or a list of them. Definitions without a context get the EDC vocabulary.
+fn read_definitions<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, CliError> {
+    let definitions = match read_value(path)? {
+        serde_json::Value::Array(definitions) => definitions,
+        definition => vec![definition],
+    };
+    definitions.into_iter()
+        .map(|mut definition| {
+            if let Some(definition) = definition.as_object_mut() {
+                definition.entry("@context").or_insert_with(|| serde_json::json!({ "@vocab": EDC_NS }));
+            }
+            serde_json::from_value(definition).map_err(|e| CliError::Input(format!("Invalid definition in {}: {}", path.display(), e)))
+        })
+        .collect()
+}
+
+/// Reads a JSON manifest if the file ends with `.json`, otherwise a YAML one
+fn read_manifest(path: &Path) -> Result<Manifest, CliError> {
+    let content = read_content(path)?;
+    let manifest = if path.extension().is_some_and(|extension| extension == "json") {
+        Manifest::from_json(&content).map_err(|e| e.to_string())
+    } else {
+        Manifest::from_yaml(&content).map_err(|e| e.to_string())
+    };
+    manifest.map_err(|e| CliError::Input(format!("Invalid manifest {}: {}", path.display(), e)))
+}
+
+fn read_value(path: &Path) -> Result<serde_json::Value, CliError> {
+    let content = read_content(path)?;
+    serde_json::from_str(&content).map_err(|e| CliError::Input(format!("Invalid JSON in {}: {}", path.display(), e)))
+}
+
+fn read_content(path: &Path) -> Result<String, CliError> {
+    if path == Path::new("-") {
+        let mut content = String::new();
+        std::io::stdin().read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        std::fs::read_to_string(path).map_err(|e| CliError::Input(format!("Could not read {}: {}", path.display(), e)))
+    }
+}
@@ -0,0 +1,96 @@
+//! Connector profiles of the config file, e.g.
+//!
+//! ```toml
+//! default = "provider"
+//!
+//! [profiles.provider]
+//! base_path = "http://localhost:29193/management"
+//! api_key = "123456"
+//!
+//! [profiles.consumer]
+//! base_path = "http://localhost:19193/management"
+//! api_key_env = "CONSUMER_API_KEY"
+//! ```
+
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use edc_client::configuration::{ApiKey, Configuration};
+use serde::Deserialize;
+
+use crate::CliError;
+
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Profile that is used when none is given
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Management api of the connector, e.g. `http://localhost:29193/management`
+    pub base_path: String,
+    pub api_key: Option<String>,
+    /// Environment variable that holds the api key, so it doesn't have to be stored in the file
+    pub api_key_env: Option<String>,
+}
+
+impl Config {
+
+    /// `$XDG_CONFIG_HOME/dspace/config.toml`, or `~/.config/dspace/config.toml` if the variable is not set
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|config_home| !config_home.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_home.join("dspace").join("config.toml"))
+    }
+
+    pub fn load(path: &Path) -> Result<Config, CliError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| CliError::Config(format!("Could not read {}: {}", path.display(), e)))?;
+        Config::parse(&content).map_err(|e| CliError::Config(format!("Invalid config {}: {}", path.display(), e)))
+    }
+
+    pub fn parse(content: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// The given profile, otherwise the default one. A config with a single profile doesn't need a default.
+    pub fn profile(&self, name: Option<&str>) -> Result<&Profile, CliError> {
+        let name = match name.or(self.default.as_deref()) {
+            Some(name) => name,
+            None if self.profiles.len() == 1 => return Ok(self.profiles.values().next().unwrap()),
+            None => return Err(CliError::Config("No profile given and the config has no default profile".to_string())),
+        };
+        self.profiles.get(name).ok_or_else(|| CliError::Config(format!("Unknown profile {}", name)))
+    }
+
+}
+
+impl Profile {
+
+    pub fn api_key(&self) -> Result<Option<String>, CliError> {
+        match &self.api_key_env {
+            Some(variable) => std::env::var(variable)
+                .map(Some)
+                .map_err(|_| CliError::Config(format!("Environment variable {} of the api key is not set", variable))),
+            None => Ok(self.api_key.clone()),
+        }
+    }
+
+    pub fn configuration(&self) -> Result<Configuration, CliError> {
+        Ok(Configuration {
+            base_path: self.base_path.trim_end_matches('/').to_string(),
+            api_key: self.api_key()?.map(ApiKey::new),
+            ..Configuration::default()
+        })
+    }
+
+}
@@ -0,0 +1,105 @@
+//! The `dspace` command line tool, which manages EDC connectors through their management api. The binary only parses
+//! the arguments and hands them to [`run`], so the commands can be run against any writer.
+
+
+pub mod cli;
+pub mod config;
+pub mod output;
+mod commands;
+
+use std::fmt;
+use std::io::Write;
+
+use edc_client::configuration::{ApiKey, Configuration};
+use edc_client::reconcile::ReconcileError;
+use edc_client::{EdcClient, EdcError};
+
+use cli::{Cli, Command};
+use config::Config;
+
+
+#[derive(Debug)]
+pub enum CliError {
+    /// The config file or the profile are missing or invalid
+    Config(String),
+    /// An input file could not be used
+    Input(String),
+    /// The connector rejected a request or could not be reached
+    Api(String),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Config(e) | CliError::Input(e) | CliError::Api(e) => write!(f, "{}", e),
+            CliError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<std::io::Error> for CliError {
+    fn from(e: std::io::Error) -> Self {
+        CliError::Io(e)
+    }
+}
+
+impl From<edc_client::Error<EdcError>> for CliError {
+    fn from(e: edc_client::Error<EdcError>) -> Self {
+        // The details of the connector say more than the status alone
+        match e.edc_error() {
+            Some(error) => CliError::Api(error.to_string()),
+            None => CliError::Api(e.to_string()),
+        }
+    }
+}
+
+impl From<ReconcileError> for CliError {
+    fn from(e: ReconcileError) -> Self {
+        match e {
+            ReconcileError::MissingId { .. } | ReconcileError::DuplicateId { .. } => CliError::Input(e.to_string()),
+            e => CliError::Api(e.to_string()),
+        }
+    }
+}
+
+/// Runs the command of the arguments and writes its output to `out`
+pub async fn run(cli: Cli, out: &mut dyn Write) -> Result<(), CliError> {
+    if let Command::Profiles = cli.command {
+        return commands::profiles(&load_config(&cli)?, cli.output, out);
+    }
+    let client = EdcClient::new(configuration(&cli)?);
+    commands::run(&client, cli.command, cli.output, out).await
+}
+
+fn load_config(cli: &Cli) -> Result<Config, CliError> {
+    match cli.config.clone().or_else(Config::default_path) {
+        Some(path) if path.exists() || cli.config.is_some() => Config::load(&path),
+        _ => Ok(Config::default()),
+    }
+}
+
+/// Configuration of the selected profile with the overrides of the arguments. No config file is needed if the base
+/// path is given as argument.
+fn configuration(cli: &Cli) -> Result<Configuration, CliError> {
+    let mut configuration = match &cli.base_path {
+        Some(base_path) if cli.profile.is_none() => Configuration {
+            base_path: base_path.trim_end_matches('/').to_string(),
+            ..Configuration::default()
+        },
+        _ => {
+            let mut configuration = load_config(cli)?.profile(cli.profile.as_deref())?.configuration()?;
+            if let Some(base_path) = &cli.base_path {
+                configuration.base_path = base_path.trim_end_matches('/').to_string();
+            }
+            configuration
+        },
+    };
+    if let Some(api_key) = &cli.api_key {
+        configuration.api_key = Some(ApiKey::new(api_key.as_str()));
+    }
+    configuration.user_agent = Some(format!("dspace/{}", env!("CARGO_PKG_VERSION")));
+    Ok(configuration)
+}
@@ -0,0 +1,20 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use dspace_cli::cli::Cli;
+
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut stdout = std::io::stdout().lock();
+
+    match dspace_cli::run(cli, &mut stdout).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
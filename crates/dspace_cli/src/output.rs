@@ -0,0 +1,210 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for reading
+    Table,
+    /// The resources as the management api returned them, for scripts
+    Json,
+}
+
+/// Resources that can be printed as a row of a table
+pub trait Tabular {
+    const HEADERS: &'static [&'static str];
+
+    fn row(&self) -> Vec<String>;
+}
+
+/// Prints the resources as a table or as a JSON array
+pub fn print_list<T: Serialize + Tabular>(out: &mut dyn Write, format: OutputFormat, items: &[T]) -> io::Result<()> {
+    match format {
+        OutputFormat::Table => render_table(out, T::HEADERS, items.iter().map(Tabular::row).collect()),
+        OutputFormat::Json => print_json(out, &items),
+    }
+}
+
+/// Prints a single resource as a table with one row or as a JSON object
+pub fn print_one<T: Serialize + Tabular>(out: &mut dyn Write, format: OutputFormat, item: &T) -> io::Result<()> {
+    match format {
+        OutputFormat::Table => render_table(out, T::HEADERS, vec![item.row()]),
+        OutputFormat::Json => print_json(out, item),
+    }
+}
+
+pub fn print_json<T: Serialize + ?Sized>(out: &mut dyn Write, value: &T) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value)?;
+    writeln!(out)
+}
+
+/// Writes the rows in columns that are as wide as their widest cell, the last column is not padded
+pub fn render_table(out: &mut dyn Write, headers: &[&str], rows: Vec<Vec<String>>) -> io::Result<()> {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header = headers.iter().map(|header| header.to_string()).collect();
+    for row in std::iter::once(header).chain(rows) {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            if i + 1 < row.len() {
+                line.push_str(&format!("{:<width$}  ", cell, width = widths[i]));
+            } else {
+                line.push_str(cell);
+            }
+        }
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Text of a JSON value without the quotes of strings, `-` for missing values
+pub(crate) fn text(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => "-".to_string(),
+        Some(serde_json::Value::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Name of an enum as it is serialized, e.g. `FINALIZED`
+pub(crate) fn serialized_name<T: Serialize>(value: &T) -> String {
+    text(serde_json::to_value(value).ok().as_ref())
+}
+
+pub(crate) fn or_dash(value: Option<&str>) -> String {
+    value.unwrap_or("-").to_string()
+}
+
+impl Tabular for edc_api::IdResponse {
+    const HEADERS: &'static [&'static str] = &["ID", "CREATED AT"];
+
+    fn row(&self) -> Vec<String> {
+        vec![or_dash(self.at_id.as_deref()), self.created_at.map(|created_at| created_at.to_string()).unwrap_or_else(|| "-".to_string())]
+    }
+}
+
+impl Tabular for edc_api::AssetOutput {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "DATA ADDRESS", "CREATED AT"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            or_dash(self.at_id.as_deref()),
+            text(self.properties.as_ref().and_then(|properties| properties.get("name"))),
+            or_dash(self.data_address.as_ref().and_then(|data_address| data_address.r#type.as_deref())),
+            self.created_at.map(|created_at| created_at.to_string()).unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+}
+
+impl Tabular for edc_api::PolicyDefinitionOutput {
+    const HEADERS: &'static [&'static str] = &["ID", "TYPE", "PERMISSIONS", "PROHIBITIONS", "OBLIGATIONS"];
+
+    fn row(&self) -> Vec<String> {
+        let count = |rule: &str| match self.policy.as_ref().and_then(|policy| policy.get(rule)) {
+            Some(serde_json::Value::Array(rules)) => rules.len(),
+            Some(serde_json::Value::Null) | None => 0,
+            Some(_) => 1,
+        };
+        vec![
+            or_dash(self.at_id.as_deref()),
+            text(self.policy.as_ref().and_then(|policy| policy.get("@type"))),
+            count("permission").to_string(),
+            count("prohibition").to_string(),
+            count("obligation").to_string(),
+        ]
+    }
+}
+
+impl Tabular for edc_api::ContractDefinitionOutput {
+    const HEADERS: &'static [&'static str] = &["ID", "ACCESS POLICY", "CONTRACT POLICY", "ASSETS SELECTOR"];
+
+    fn row(&self) -> Vec<String> {
+        let selector = self.assets_selector.iter().flatten()
+            .map(|criterion| format!("{} {} {}", text(Some(&criterion.operand_left)), criterion.operator, text(Some(&criterion.operand_right))))
+            .collect::<Vec<_>>();
+        vec![
+            or_dash(self.at_id.as_deref()),
+            or_dash(self.access_policy_id.as_deref()),
+            or_dash(self.contract_policy_id.as_deref()),
+            if selector.is_empty() { "all assets".to_string() } else { selector.join(", ") },
+        ]
+    }
+}
+
+impl Tabular for edc_api::Dataset {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "OFFERS"];
+
+    fn row(&self) -> Vec<String> {
+        let offers = self.has_policy.iter().map(|offer| offer.at_id.as_str()).collect::<Vec<_>>();
+        vec![or_dash(self.at_id.as_deref()), text(self.properties.get("name")), offers.join(", ")]
+    }
+}
+
+impl Tabular for edc_api::ContractNegotiation {
+    const HEADERS: &'static [&'static str] = &["ID", "TYPE", "STATE", "COUNTER PARTY", "AGREEMENT", "ERROR"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            or_dash(self.at_id.as_deref()),
+            self.r#type.as_ref().map(serialized_name).unwrap_or_else(|| "-".to_string()),
+            serialized_name(&self.state),
+            or_dash(self.counter_party_id.as_deref().or(self.counter_party_address.as_deref())),
+            or_dash(self.contract_agreement_id.as_deref()),
+            or_dash(self.error_detail.as_deref()),
+        ]
+    }
+}
+
+impl Tabular for edc_api::TransferProcess {
+    const HEADERS: &'static [&'static str] = &["ID", "TYPE", "STATE", "ASSET", "AGREEMENT", "TRANSFER TYPE", "ERROR"];
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            or_dash(self.at_id.as_deref()),
+            self.r#type.as_ref().map(serialized_name).unwrap_or_else(|| "-".to_string()),
+            self.state.as_ref().map(serialized_name).unwrap_or_else(|| "-".to_string()),
+            or_dash(self.asset_id.as_deref()),
+            or_dash(self.contract_agreement_id.as_deref()),
+            or_dash(self.transfer_type.as_deref()),
+            or_dash(self.error_detail.as_deref()),
+        ]
+    }
+}
+
+impl Tabular for edc_api::EndpointDataReferenceEntry {
+    const HEADERS: &'static [&'static str] = &["ID", "TYPE"];
+
+    fn row(&self) -> Vec<String> {
+        vec![or_dash(self.at_id.as_deref()), or_dash(self.at_type.as_deref())]
+    }
+}
+
+impl Tabular for edc_client::reconcile::Step {
+    const HEADERS: &'static [&'static str] = &["ACTION", "KIND", "ID", "CHANGES"];
+
+    fn row(&self) -> Vec<String> {
+        vec![serialized_name(&self.action), self.kind.to_string(), self.id.clone(), self.changes.join(", ")]
+    }
+}
+
+/// Data addresses are printed as one row per property, since their properties differ by type
+pub fn print_data_address(out: &mut dyn Write, format: OutputFormat, data_address: &edc_api::DataAddress) -> io::Result<()> {
+    if format == OutputFormat::Json {
+        return print_json(out, data_address);
+    }
+    let mut rows = vec![vec!["type".to_string(), or_dash(data_address.r#type.as_deref())]];
+    if let Some(base_url) = &data_address.base_url {
+        rows.push(vec!["baseUrl".to_string(), base_url.clone()]);
+    }
+    let mut properties = data_address.additional_properties.iter().collect::<Vec<_>>();
+    properties.sort_by(|a, b| a.0.cmp(b.0));
+    rows.extend(properties.into_iter().filter(|(key, _)| !key.starts_with('@')).map(|(key, value)| vec![key.clone(), text(Some(value))]));
+    render_table(out, &["PROPERTY", "VALUE"], rows)
+}
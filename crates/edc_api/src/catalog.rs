@@ -0,0 +1,62 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+use serde_with::{formats::PreferMany, serde_as, OneOrMany};
+
+/// DCAT catalog of a connector as returned by the catalog request
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Catalog {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub at_id: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dcat:dataset", alias = "dataset", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    pub dataset: Vec<crate::Dataset>,
+    #[serde(rename = "dcat:service", alias = "service", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    pub service: Vec<crate::DataService>,
+    #[serde(rename = "participantId", alias = "dspace:participantId", skip_serializing_if = "Option::is_none")]
+    pub participant_id: Option<String>,
+}
+
+impl Catalog {
+
+    pub fn new(context: std::collections::HashMap<String, serde_json::Value>, at_id: Option<String>, at_type: Option<String>, dataset: Vec<crate::Dataset>,
+               service: Vec<crate::DataService>, participant_id: Option<String>) -> Catalog {
+        Catalog {
+            context,
+            at_id,
+            at_type,
+            dataset,
+            service,
+            participant_id,
+        }
+    }
+
+    pub fn default() -> Catalog {
+        Catalog {
+            context: std::collections::HashMap::new(),
+            at_id: None,
+            at_type: Some("dcat:Catalog".to_string()),
+            dataset: Vec::new(),
+            service: Vec::new(),
+            participant_id: None,
+        }
+    }
+
+    /// Finds the dataset with the given id, usually the id of the offered asset
+    pub fn find_dataset(&self, dataset_id: &str) -> Option<&crate::Dataset> {
+        self.dataset.iter().find(|dataset| dataset.at_id.as_deref() == Some(dataset_id))
+    }
+
+}
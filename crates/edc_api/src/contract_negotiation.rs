@@ -124,6 +124,15 @@ pub enum ContractNegotiationState {
     Terminated,     // Consumer and Provider
 }
 
+impl ContractNegotiationState {
+
+    /// Whether the negotiation has ended, it doesn't change its state anymore
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ContractNegotiationState::Finalized | ContractNegotiationState::Terminated)
+    }
+
+}
+
 impl Default for ContractNegotiationState {
     fn default() -> ContractNegotiationState {
         Self::Initial
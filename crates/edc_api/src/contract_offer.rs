@@ -0,0 +1,57 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+/// ODRL offer of a dataset (`odrl:hasPolicy`). The rules are kept as JSON-LD, as they are free to use any ODRL profile,
+/// `odrl::functions::edc_policy::offer_policy` reads them into an `OfferPolicy`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractOffer {
+    #[serde(rename = "@id")]
+    pub at_id: String,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "odrl:assigner", alias = "assigner", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub assigner: Option<String>,
+    #[serde(rename = "odrl:target", alias = "target", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub target: Option<String>,
+    #[serde(rename = "odrl:permission", alias = "permission", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub permission: Vec<serde_json::Value>,
+    #[serde(rename = "odrl:prohibition", alias = "prohibition", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub prohibition: Vec<serde_json::Value>,
+    #[serde(rename = "odrl:obligation", alias = "obligation", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub obligation: Vec<serde_json::Value>,
+}
+
+impl ContractOffer {
+
+    pub fn new(at_id: String, at_type: Option<String>, assigner: Option<String>, target: Option<String>, permission: Vec<serde_json::Value>,
+               prohibition: Vec<serde_json::Value>, obligation: Vec<serde_json::Value>) -> ContractOffer {
+        ContractOffer {
+            at_id,
+            at_type,
+            assigner,
+            target,
+            permission,
+            prohibition,
+            obligation,
+        }
+    }
+
+    pub fn default() -> ContractOffer {
+        ContractOffer {
+            at_id: String::new(),
+            at_type: Some("odrl:Offer".to_string()),
+            assigner: None,
+            target: None,
+            permission: Vec::new(),
+            prohibition: Vec::new(),
+            obligation: Vec::new(),
+        }
+    }
+
+}
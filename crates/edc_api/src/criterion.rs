@@ -16,12 +16,12 @@ pub struct Criterion {
     #[serde(rename = "operandRight")]
     pub operand_right: serde_json::Value,
     #[serde(rename = "operator")]
-    pub operator: String,
+    pub operator: CriterionOperator,
 }
 
 impl Criterion {
 
-    pub fn new(at_type: Option<String>, operand_left: serde_json::Value, operand_right: serde_json::Value, operator: String) -> Criterion {
+    pub fn new(at_type: Option<String>, operand_left: serde_json::Value, operand_right: serde_json::Value, operator: CriterionOperator) -> Criterion {
         Criterion {
             at_type,
             operand_left,
@@ -30,13 +30,98 @@ impl Criterion {
         }
     }
 
+    /// Criterion with a typed operator, `operand_left` is the property path and `operand_right` the value to compare with
+    pub fn with_operator(operand_left: impl Into<String>, operator: CriterionOperator, operand_right: serde_json::Value) -> Criterion {
+        Criterion {
+            at_type: Some("Criterion".to_string()),
+            operand_left: serde_json::Value::String(operand_left.into()),
+            operand_right,
+            operator,
+        }
+    }
+
     pub fn default() -> Criterion {
         Criterion {
             at_type: Some("Criterion".to_string()),
             operand_left: serde_json::Value::default(),
             operand_right: serde_json::Value::default(),
-            operator: String::new(),
+            operator: CriterionOperator::Eq,
+        }
+    }
+
+}
+
+/// Operators the query endpoints of the EDC understand. Connectors compare operators case insensitive, so `IN` is
+/// deserialized as well. Operators a connector registers itself are kept as [`CriterionOperator::Other`], so objects
+/// that use them still deserialize.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CriterionOperator {
+    #[default]
+    Eq,
+    Ne,
+    In,
+    Like,
+    ILike,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    Other(String),
+}
+
+impl CriterionOperator {
+
+    /// All operators known to this crate
+    pub const ALL: [CriterionOperator; 10] = [Self::Eq, Self::Ne, Self::In, Self::Like, Self::ILike, Self::Lt, Self::Le, Self::Gt, Self::Ge, Self::Contains];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::In => "in",
+            Self::Like => "like",
+            Self::ILike => "ilike",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Contains => "contains",
+            Self::Other(operator) => operator,
         }
     }
 
-}
\ No newline at end of file
+    fn from_string(operator: String) -> Self {
+        Self::ALL.into_iter()
+            .find(|candidate| candidate.as_str().eq_ignore_ascii_case(&operator))
+            .unwrap_or(Self::Other(operator))
+    }
+
+}
+
+impl std::fmt::Display for CriterionOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CriterionOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(operator: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_string(operator.to_string()))
+    }
+}
+
+impl serde::Serialize for CriterionOperator {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CriterionOperator {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let operator = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(Self::from_string(operator))
+    }
+}
@@ -7,6 +7,19 @@
  *
  */
 
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+
+pub const HTTP_DATA_TYPE: &str = "HttpData";
+pub const AMAZON_S3_TYPE: &str = "AmazonS3";
+pub const AZURE_STORAGE_TYPE: &str = "AzureStorage";
+pub const KAFKA_TYPE: &str = "Kafka";
+
+/// Where data is read from or written to. Which properties are needed depends on the `type`, all properties besides
+/// `@type`, `type` and `baseUrl` are kept in `additional_properties`. See [`DataAddress::typed`] for the common types.
 #[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct DataAddress {
     #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
@@ -15,6 +28,8 @@ pub struct DataAddress {
     pub r#type: Option<String>,
     #[serde(rename = "baseUrl", skip_serializing_if = "Option::is_none")]
     pub base_url: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, serde_json::Value>,
 }
 
 impl DataAddress {
@@ -24,6 +39,7 @@ impl DataAddress {
             at_type,
             r#type,
             base_url,
+            additional_properties: HashMap::new(),
         }
     }
 
@@ -32,7 +48,286 @@ impl DataAddress {
             at_type: Some("DataAddress".to_string()),
             r#type: None,
             base_url: None,
+            additional_properties: HashMap::new(),
+        }
+    }
+
+    pub fn property(&self, key: &str) -> Option<&serde_json::Value> {
+        self.additional_properties.get(key)
+    }
+
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.additional_properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Reads the properties of the known address types. Addresses of other types are returned as they are.
+    pub fn typed(&self) -> Result<TypedDataAddress, serde_json::Error> {
+        Ok(match self.r#type.as_deref() {
+            Some(HTTP_DATA_TYPE) => TypedDataAddress::HttpData(self.properties()?),
+            Some(AMAZON_S3_TYPE) => TypedDataAddress::AmazonS3(self.properties()?),
+            Some(AZURE_STORAGE_TYPE) => TypedDataAddress::AzureStorage(self.properties()?),
+            Some(KAFKA_TYPE) => TypedDataAddress::Kafka(self.properties()?),
+            _ => TypedDataAddress::Other(self.clone()),
+        })
+    }
+
+    fn properties<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let mut properties = serde_json::Map::from_iter(self.additional_properties.clone());
+        if let Some(base_url) = &self.base_url {
+            properties.insert("baseUrl".to_string(), serde_json::Value::String(base_url.clone()));
+        }
+        serde_json::from_value(serde_json::Value::Object(properties))
+    }
+
+    fn from_properties<T: Serialize>(r#type: &str, properties: &T) -> DataAddress {
+        let mut additional_properties = match serde_json::to_value(properties) {
+            Ok(serde_json::Value::Object(properties)) => HashMap::from_iter(properties),
+            _ => HashMap::new(),
+        };
+        let base_url = match additional_properties.remove("baseUrl") {
+            Some(serde_json::Value::String(base_url)) => Some(base_url),
+            _ => None,
+        };
+        DataAddress {
+            at_type: Some("DataAddress".to_string()),
+            r#type: Some(r#type.to_string()),
+            base_url,
+            additional_properties,
+        }
+    }
+
+}
+
+/// [`DataAddress`] of a known type, see [`DataAddress::typed`]. Converts back with `DataAddress::from`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypedDataAddress {
+    HttpData(HttpDataAddress),
+    AmazonS3(S3DataAddress),
+    AzureStorage(AzureStorageDataAddress),
+    Kafka(KafkaDataAddress),
+    Other(DataAddress),
+}
+
+impl From<TypedDataAddress> for DataAddress {
+    fn from(data_address: TypedDataAddress) -> Self {
+        match data_address {
+            TypedDataAddress::HttpData(data_address) => data_address.into(),
+            TypedDataAddress::AmazonS3(data_address) => data_address.into(),
+            TypedDataAddress::AzureStorage(data_address) => data_address.into(),
+            TypedDataAddress::Kafka(data_address) => data_address.into(),
+            TypedDataAddress::Other(data_address) => data_address,
+        }
+    }
+}
+
+/// Properties of an `HttpData` address. The `proxy*` flags let the consumer set the corresponding part of the request
+/// when pulling the data, additional headers are stored as `header:<name>`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct HttpDataAddress {
+    #[serde(rename = "baseUrl", skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(rename = "path", skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(rename = "queryParams", skip_serializing_if = "Option::is_none")]
+    pub query_params: Option<String>,
+    #[serde(rename = "method", skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(rename = "contentType", skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(rename = "body", skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(rename = "proxyPath", default, skip_serializing_if = "Option::is_none", with = "flag")]
+    pub proxy_path: Option<bool>,
+    #[serde(rename = "proxyQueryParams", default, skip_serializing_if = "Option::is_none", with = "flag")]
+    pub proxy_query_params: Option<bool>,
+    #[serde(rename = "proxyMethod", default, skip_serializing_if = "Option::is_none", with = "flag")]
+    pub proxy_method: Option<bool>,
+    #[serde(rename = "proxyBody", default, skip_serializing_if = "Option::is_none", with = "flag")]
+    pub proxy_body: Option<bool>,
+    /// Name of the header that carries the credentials, e.g. `Authorization`
+    #[serde(rename = "authKey", skip_serializing_if = "Option::is_none")]
+    pub auth_key: Option<String>,
+    #[serde(rename = "authCode", skip_serializing_if = "Option::is_none")]
+    pub auth_code: Option<String>,
+    /// Alias of the vault secret that holds the `authCode`
+    #[serde(rename = "secretName", skip_serializing_if = "Option::is_none")]
+    pub secret_name: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, serde_json::Value>,
+}
+
+impl HttpDataAddress {
+
+    const HEADER_PREFIX: &'static str = "header:";
+
+    pub fn new(base_url: impl Into<String>) -> HttpDataAddress {
+        HttpDataAddress {
+            base_url: Some(base_url.into()),
+            ..HttpDataAddress::default()
+        }
+    }
+
+    /// Header that is sent with every request for the data
+    pub fn with_header(mut self, name: impl AsRef<str>, value: impl Into<String>) -> Self {
+        let key = format!("{}{}", Self::HEADER_PREFIX, name.as_ref());
+        self.additional_properties.insert(key, serde_json::Value::String(value.into()));
+        self
+    }
+
+    pub fn headers(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.additional_properties.iter().filter_map(|(key, value)| {
+            Some((key.strip_prefix(Self::HEADER_PREFIX)?, value.as_str()?))
+        })
+    }
+
+}
+
+impl From<HttpDataAddress> for DataAddress {
+    fn from(data_address: HttpDataAddress) -> Self {
+        DataAddress::from_properties(HTTP_DATA_TYPE, &data_address)
+    }
+}
+
+/// Properties of an `AmazonS3` address
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct S3DataAddress {
+    #[serde(rename = "region", skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(rename = "bucketName", skip_serializing_if = "Option::is_none")]
+    pub bucket_name: Option<String>,
+    #[serde(rename = "objectName", skip_serializing_if = "Option::is_none")]
+    pub object_name: Option<String>,
+    #[serde(rename = "objectPrefix", skip_serializing_if = "Option::is_none")]
+    pub object_prefix: Option<String>,
+    /// Alias of the vault secret that holds the credentials
+    #[serde(rename = "keyName", skip_serializing_if = "Option::is_none")]
+    pub key_name: Option<String>,
+    #[serde(rename = "endpointOverride", skip_serializing_if = "Option::is_none")]
+    pub endpoint_override: Option<String>,
+    #[serde(rename = "accessKeyId", skip_serializing_if = "Option::is_none")]
+    pub access_key_id: Option<String>,
+    #[serde(rename = "secretAccessKey", skip_serializing_if = "Option::is_none")]
+    pub secret_access_key: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, serde_json::Value>,
+}
+
+impl S3DataAddress {
+
+    pub fn new(region: impl Into<String>, bucket_name: impl Into<String>) -> S3DataAddress {
+        S3DataAddress {
+            region: Some(region.into()),
+            bucket_name: Some(bucket_name.into()),
+            ..S3DataAddress::default()
+        }
+    }
+
+}
+
+impl From<S3DataAddress> for DataAddress {
+    fn from(data_address: S3DataAddress) -> Self {
+        DataAddress::from_properties(AMAZON_S3_TYPE, &data_address)
+    }
+}
+
+/// Properties of an `AzureStorage` address
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct AzureStorageDataAddress {
+    #[serde(rename = "account", skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    #[serde(rename = "container", skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    #[serde(rename = "blobName", skip_serializing_if = "Option::is_none")]
+    pub blob_name: Option<String>,
+    #[serde(rename = "blobPrefix", skip_serializing_if = "Option::is_none")]
+    pub blob_prefix: Option<String>,
+    /// Alias of the vault secret that holds the account key or SAS token
+    #[serde(rename = "keyName", skip_serializing_if = "Option::is_none")]
+    pub key_name: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, serde_json::Value>,
+}
+
+impl AzureStorageDataAddress {
+
+    pub fn new(account: impl Into<String>, container: impl Into<String>) -> AzureStorageDataAddress {
+        AzureStorageDataAddress {
+            account: Some(account.into()),
+            container: Some(container.into()),
+            ..AzureStorageDataAddress::default()
+        }
+    }
+
+}
+
+impl From<AzureStorageDataAddress> for DataAddress {
+    fn from(data_address: AzureStorageDataAddress) -> Self {
+        DataAddress::from_properties(AZURE_STORAGE_TYPE, &data_address)
+    }
+}
+
+/// Properties of a `Kafka` address. Further `kafka.*` consumer and producer settings are kept in `additional_properties`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct KafkaDataAddress {
+    #[serde(rename = "topic", skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    #[serde(rename = "kafka.bootstrap.servers", skip_serializing_if = "Option::is_none")]
+    pub bootstrap_servers: Option<String>,
+    #[serde(rename = "kafka.security.protocol", skip_serializing_if = "Option::is_none")]
+    pub security_protocol: Option<String>,
+    #[serde(rename = "kafka.sasl.mechanism", skip_serializing_if = "Option::is_none")]
+    pub sasl_mechanism: Option<String>,
+    /// ISO-8601 duration, e.g. `PT1S`
+    #[serde(rename = "kafka.poll.duration", skip_serializing_if = "Option::is_none")]
+    pub poll_duration: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, serde_json::Value>,
+}
+
+impl KafkaDataAddress {
+
+    pub fn new(bootstrap_servers: impl Into<String>, topic: impl Into<String>) -> KafkaDataAddress {
+        KafkaDataAddress {
+            topic: Some(topic.into()),
+            bootstrap_servers: Some(bootstrap_servers.into()),
+            ..KafkaDataAddress::default()
+        }
+    }
+
+}
+
+impl From<KafkaDataAddress> for DataAddress {
+    fn from(data_address: KafkaDataAddress) -> Self {
+        DataAddress::from_properties(KAFKA_TYPE, &data_address)
+    }
+}
+
+/// Flags are sent as the strings `"true"` and `"false"`, which is how connectors store them
+mod flag {
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(flag: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+        match flag {
+            Some(flag) => serializer.serialize_str(if *flag { "true" } else { "false" }),
+            None => serializer.serialize_none(),
         }
     }
 
-}
\ No newline at end of file
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Flag {
+            Bool(bool),
+            String(String),
+        }
+
+        Ok(match Option::<Flag>::deserialize(deserializer)? {
+            Some(Flag::Bool(flag)) => Some(flag),
+            Some(Flag::String(flag)) => Some(flag.eq_ignore_ascii_case("true")),
+            None => None,
+        })
+    }
+
+}
@@ -0,0 +1,47 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+/// DCAT data service, i.e. the connector endpoint that serves the datasets of a catalog
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct DataService {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub at_id: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dct:terms", alias = "terms", skip_serializing_if = "Option::is_none")]
+    pub terms: Option<String>,
+    #[serde(rename = "dct:endpointUrl", alias = "dcat:endpointURL", alias = "endpointUrl", skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+    #[serde(rename = "dcat:endpointDescription", alias = "endpointDescription", skip_serializing_if = "Option::is_none")]
+    pub endpoint_description: Option<String>,
+}
+
+impl DataService {
+
+    pub fn new(at_id: Option<String>, at_type: Option<String>, terms: Option<String>, endpoint_url: Option<String>, endpoint_description: Option<String>) -> DataService {
+        DataService {
+            at_id,
+            at_type,
+            terms,
+            endpoint_url,
+            endpoint_description,
+        }
+    }
+
+    pub fn default() -> DataService {
+        DataService {
+            at_id: None,
+            at_type: Some("dcat:DataService".to_string()),
+            terms: None,
+            endpoint_url: None,
+            endpoint_description: None,
+        }
+    }
+
+}
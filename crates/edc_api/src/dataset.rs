@@ -0,0 +1,63 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+use serde_with::{formats::PreferMany, serde_as, OneOrMany};
+
+/// DCAT dataset of a catalog. Every offer under which the dataset can be negotiated is listed in `has_policy`.
+/// All other properties, e.g. the public properties of the underlying asset, are kept in `properties`.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Dataset {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub at_id: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "odrl:hasPolicy", alias = "hasPolicy", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    pub has_policy: Vec<crate::ContractOffer>,
+    #[serde(rename = "dcat:distribution", alias = "distribution", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    pub distribution: Vec<crate::Distribution>,
+    #[serde(flatten)]
+    pub properties: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl Dataset {
+
+    pub fn new(context: std::collections::HashMap<String, serde_json::Value>, at_id: Option<String>, at_type: Option<String>, has_policy: Vec<crate::ContractOffer>,
+               distribution: Vec<crate::Distribution>, properties: std::collections::HashMap<String, serde_json::Value>) -> Dataset {
+        Dataset {
+            context,
+            at_id,
+            at_type,
+            has_policy,
+            distribution,
+            properties,
+        }
+    }
+
+    pub fn default() -> Dataset {
+        Dataset {
+            context: std::collections::HashMap::new(),
+            at_id: None,
+            at_type: Some("dcat:Dataset".to_string()),
+            has_policy: Vec::new(),
+            distribution: Vec::new(),
+            properties: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Finds the offer with the given id
+    pub fn find_offer(&self, offer_id: &str) -> Option<&crate::ContractOffer> {
+        self.has_policy.iter().find(|offer| offer.at_id == offer_id)
+    }
+
+}
@@ -0,0 +1,39 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+/// DCAT distribution of a dataset. The format is the transfer type, e.g. `HttpData-PULL`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Distribution {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dct:format", alias = "format", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub format: Option<String>,
+    #[serde(rename = "dcat:accessService", alias = "accessService", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_node")]
+    pub access_service: Option<crate::DataService>,
+}
+
+impl Distribution {
+
+    pub fn new(at_type: Option<String>, format: Option<String>, access_service: Option<crate::DataService>) -> Distribution {
+        Distribution {
+            at_type,
+            format,
+            access_service,
+        }
+    }
+
+    pub fn default() -> Distribution {
+        Distribution {
+            at_type: Some("dcat:Distribution".to_string()),
+            format: None,
+            access_service: None,
+        }
+    }
+
+}
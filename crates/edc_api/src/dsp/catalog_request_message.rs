@@ -0,0 +1,37 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Requests the catalog of a provider. The filter is passed on as is, its format is defined by the provider.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CatalogRequestMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:filter", alias = "filter", default, skip_serializing_if = "Vec::is_empty", deserialize_with = "crate::json_ld::deserialize_values")]
+    pub filter: Vec<serde_json::Value>,
+}
+
+impl CatalogRequestMessage {
+
+    pub fn new() -> CatalogRequestMessage {
+        CatalogRequestMessage {
+            ..CatalogRequestMessage::default()
+        }
+    }
+
+    pub fn default() -> CatalogRequestMessage {
+        CatalogRequestMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:CatalogRequestMessage".to_string()),
+            filter: Vec::new(),
+        }
+    }
+
+}
@@ -0,0 +1,101 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Sends the agreement of the provider to the consumer, who answers with a [`crate::dsp::ContractAgreementVerificationMessage`]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractAgreementMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:agreement", alias = "agreement")]
+    pub agreement: Agreement,
+    #[serde(rename = "dspace:callbackAddress", alias = "callbackAddress", default, skip_serializing_if = "Option::is_none")]
+    pub callback_address: Option<String>,
+}
+
+impl ContractAgreementMessage {
+
+    pub fn new(provider_pid: String, consumer_pid: String, agreement: Agreement) -> ContractAgreementMessage {
+        ContractAgreementMessage {
+            provider_pid,
+            consumer_pid,
+            agreement,
+            ..ContractAgreementMessage::default()
+        }
+    }
+
+    pub fn default() -> ContractAgreementMessage {
+        ContractAgreementMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractAgreementMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            agreement: Agreement::default(),
+            callback_address: None,
+        }
+    }
+
+}
+
+/// ODRL agreement between provider (`assigner`) and consumer (`assignee`). The rules are kept as JSON-LD like the ones of a
+/// [`crate::ContractOffer`].
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct Agreement {
+    #[serde(rename = "@id")]
+    pub at_id: String,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "odrl:target", alias = "target", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub target: Option<String>,
+    #[serde(rename = "odrl:assigner", alias = "assigner", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub assigner: Option<String>,
+    #[serde(rename = "odrl:assignee", alias = "assignee", default, skip_serializing_if = "Option::is_none", deserialize_with = "crate::json_ld::deserialize_id")]
+    pub assignee: Option<String>,
+    #[serde(rename = "dspace:timestamp", alias = "timestamp", default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(rename = "odrl:permission", alias = "permission", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub permission: Vec<serde_json::Value>,
+    #[serde(rename = "odrl:prohibition", alias = "prohibition", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub prohibition: Vec<serde_json::Value>,
+    #[serde(rename = "odrl:obligation", alias = "obligation", default, deserialize_with = "crate::json_ld::deserialize_values")]
+    pub obligation: Vec<serde_json::Value>,
+}
+
+impl Agreement {
+
+    pub fn new(at_id: String, target: Option<String>, assigner: Option<String>, assignee: Option<String>) -> Agreement {
+        Agreement {
+            at_id,
+            target,
+            assigner,
+            assignee,
+            ..Agreement::default()
+        }
+    }
+
+    pub fn default() -> Agreement {
+        Agreement {
+            at_id: String::new(),
+            at_type: Some("odrl:Agreement".to_string()),
+            target: None,
+            assigner: None,
+            assignee: None,
+            timestamp: None,
+            permission: Vec::new(),
+            prohibition: Vec::new(),
+            obligation: Vec::new(),
+        }
+    }
+
+}
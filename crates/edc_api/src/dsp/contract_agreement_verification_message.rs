@@ -0,0 +1,42 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Verifies the agreement the consumer received from the provider
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractAgreementVerificationMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+}
+
+impl ContractAgreementVerificationMessage {
+
+    pub fn new(provider_pid: String, consumer_pid: String) -> ContractAgreementVerificationMessage {
+        ContractAgreementVerificationMessage {
+            provider_pid,
+            consumer_pid,
+            ..ContractAgreementVerificationMessage::default()
+        }
+    }
+
+    pub fn default() -> ContractAgreementVerificationMessage {
+        ContractAgreementVerificationMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractAgreementVerificationMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+        }
+    }
+
+}
@@ -0,0 +1,65 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// State of a negotiation as reported by the counter party
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractNegotiation {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:state", alias = "state")]
+    pub state: NegotiationState,
+}
+
+impl ContractNegotiation {
+
+    pub fn new(provider_pid: String, consumer_pid: String, state: NegotiationState) -> ContractNegotiation {
+        ContractNegotiation {
+            provider_pid,
+            consumer_pid,
+            state,
+            ..ContractNegotiation::default()
+        }
+    }
+
+    pub fn default() -> ContractNegotiation {
+        ContractNegotiation {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractNegotiation".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            state: NegotiationState::default(),
+        }
+    }
+
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum NegotiationState {
+    #[default]
+    #[serde(rename = "dspace:REQUESTED", alias = "REQUESTED")]
+    Requested,
+    #[serde(rename = "dspace:OFFERED", alias = "OFFERED")]
+    Offered,
+    #[serde(rename = "dspace:ACCEPTED", alias = "ACCEPTED")]
+    Accepted,
+    #[serde(rename = "dspace:AGREED", alias = "AGREED")]
+    Agreed,
+    #[serde(rename = "dspace:VERIFIED", alias = "VERIFIED")]
+    Verified,
+    #[serde(rename = "dspace:FINALIZED", alias = "FINALIZED")]
+    Finalized,
+    #[serde(rename = "dspace:TERMINATED", alias = "TERMINATED")]
+    Terminated,
+}
@@ -0,0 +1,56 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Notifies the counter party of an event within a negotiation. The consumer accepts an offer with [`NegotiationEventType::Accepted`],
+/// the provider finalizes the negotiation with [`NegotiationEventType::Finalized`].
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractNegotiationEventMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:eventType", alias = "eventType")]
+    pub event_type: NegotiationEventType,
+}
+
+impl ContractNegotiationEventMessage {
+
+    pub fn new(provider_pid: String, consumer_pid: String, event_type: NegotiationEventType) -> ContractNegotiationEventMessage {
+        ContractNegotiationEventMessage {
+            provider_pid,
+            consumer_pid,
+            event_type,
+            ..ContractNegotiationEventMessage::default()
+        }
+    }
+
+    pub fn default() -> ContractNegotiationEventMessage {
+        ContractNegotiationEventMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractNegotiationEventMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            event_type: NegotiationEventType::default(),
+        }
+    }
+
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum NegotiationEventType {
+    #[default]
+    #[serde(rename = "dspace:ACCEPTED", alias = "ACCEPTED")]
+    Accepted,
+    #[serde(rename = "dspace:FINALIZED", alias = "FINALIZED")]
+    Finalized,
+}
@@ -0,0 +1,49 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Offers a contract to the consumer. Without `consumer_pid` a new negotiation is started by the provider, otherwise it is
+/// a counter offer within an existing negotiation.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractOfferMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid", default, skip_serializing_if = "Option::is_none")]
+    pub consumer_pid: Option<String>,
+    #[serde(rename = "dspace:offer", alias = "offer")]
+    pub offer: crate::ContractOffer,
+    #[serde(rename = "dspace:callbackAddress", alias = "callbackAddress", default, skip_serializing_if = "Option::is_none")]
+    pub callback_address: Option<String>,
+}
+
+impl ContractOfferMessage {
+
+    pub fn new(provider_pid: String, offer: crate::ContractOffer) -> ContractOfferMessage {
+        ContractOfferMessage {
+            provider_pid,
+            offer,
+            ..ContractOfferMessage::default()
+        }
+    }
+
+    pub fn default() -> ContractOfferMessage {
+        ContractOfferMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractOfferMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: None,
+            offer: crate::ContractOffer::default(),
+            callback_address: None,
+        }
+    }
+
+}
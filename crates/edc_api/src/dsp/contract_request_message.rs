@@ -0,0 +1,50 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Requests a contract for an offer of the provider. Without `provider_pid` a new negotiation is started, otherwise it is a
+/// counter request within an existing negotiation.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractRequestMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid", default, skip_serializing_if = "Option::is_none")]
+    pub provider_pid: Option<String>,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:offer", alias = "offer")]
+    pub offer: crate::ContractOffer,
+    #[serde(rename = "dspace:callbackAddress", alias = "callbackAddress")]
+    pub callback_address: String,
+}
+
+impl ContractRequestMessage {
+
+    pub fn new(consumer_pid: String, offer: crate::ContractOffer, callback_address: String) -> ContractRequestMessage {
+        ContractRequestMessage {
+            consumer_pid,
+            offer,
+            callback_address,
+            ..ContractRequestMessage::default()
+        }
+    }
+
+    pub fn default() -> ContractRequestMessage {
+        ContractRequestMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:ContractRequestMessage".to_string()),
+            provider_pid: None,
+            consumer_pid: String::new(),
+            offer: crate::ContractOffer::default(),
+            callback_address: String::new(),
+        }
+    }
+
+}
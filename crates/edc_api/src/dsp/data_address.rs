@@ -0,0 +1,79 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+use serde_with::{formats::PreferMany, serde_as, OneOrMany};
+
+/// Endpoint of a transfer, e.g. the endpoint a consumer pulls the data from
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct DataAddress {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:endpointType", alias = "endpointType")]
+    pub endpoint_type: String,
+    #[serde(rename = "dspace:endpoint", alias = "endpoint", default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    #[serde(rename = "dspace:endpointProperties", alias = "endpointProperties", default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    pub endpoint_properties: Vec<EndpointProperty>,
+}
+
+impl DataAddress {
+
+    pub fn new(endpoint_type: String) -> DataAddress {
+        DataAddress {
+            endpoint_type,
+            ..DataAddress::default()
+        }
+    }
+
+    pub fn default() -> DataAddress {
+        DataAddress {
+            at_type: Some("dspace:DataAddress".to_string()),
+            endpoint_type: String::new(),
+            endpoint: None,
+            endpoint_properties: Vec::new(),
+        }
+    }
+
+}
+
+/// Property of a [`DataAddress`], e.g. the `authorization` token for the endpoint
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct EndpointProperty {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:name", alias = "name")]
+    pub name: String,
+    #[serde(rename = "dspace:value", alias = "value")]
+    pub value: String,
+}
+
+impl EndpointProperty {
+
+    pub fn new(name: String, value: String) -> EndpointProperty {
+        EndpointProperty {
+            at_type: Some("dspace:EndpointProperty".to_string()),
+            name,
+            value,
+        }
+    }
+
+}
+
+impl DataAddress {
+
+    /// Value of the endpoint property with the given name
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.endpoint_properties.iter()
+            .find(|property| property.name == name || property.name.strip_prefix("dspace:") == Some(name))
+            .map(|property| property.value.as_str())
+    }
+
+}
@@ -0,0 +1,57 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+//! Messages exchanged between connectors over the Dataspace Protocol (DSP). Unlike the models of the management api they
+//! are sent to the `/protocol` endpoint of the counter party.
+
+pub mod catalog_request_message;
+pub use self::catalog_request_message::CatalogRequestMessage;
+pub mod contract_agreement_message;
+pub use self::contract_agreement_message::{Agreement, ContractAgreementMessage};
+pub mod contract_agreement_verification_message;
+pub use self::contract_agreement_verification_message::ContractAgreementVerificationMessage;
+pub mod contract_negotiation;
+pub use self::contract_negotiation::{ContractNegotiation, NegotiationState};
+pub mod contract_negotiation_event_message;
+pub use self::contract_negotiation_event_message::{ContractNegotiationEventMessage, NegotiationEventType};
+pub mod contract_negotiation_termination_message;
+pub use self::contract_negotiation_termination_message::ContractNegotiationTerminationMessage;
+pub mod contract_offer_message;
+pub use self::contract_offer_message::ContractOfferMessage;
+pub mod contract_request_message;
+pub use self::contract_request_message::ContractRequestMessage;
+pub mod data_address;
+pub use self::data_address::{DataAddress, EndpointProperty};
+pub mod protocol_error;
+pub use self::protocol_error::ProtocolError;
+pub mod transfer_completion_message;
+pub use self::transfer_completion_message::TransferCompletionMessage;
+pub mod transfer_process;
+pub use self::transfer_process::{TransferProcess, TransferState};
+pub mod transfer_request_message;
+pub use self::transfer_request_message::TransferRequestMessage;
+pub mod transfer_start_message;
+pub use self::transfer_start_message::TransferStartMessage;
+pub mod transfer_suspension_message;
+pub use self::transfer_suspension_message::TransferSuspensionMessage;
+pub mod transfer_termination_message;
+pub use self::transfer_termination_message::TransferTerminationMessage;
+
+pub const DSPACE_NS: &str = "https://w3id.org/dspace/v0.8/";
+pub const ODRL_NS: &str = "http://www.w3.org/ns/odrl/2/";
+pub const DCT_NS: &str = "http://purl.org/dc/terms/";
+
+/// Context of all messages, it defines the `dspace`, `odrl` and `dct` prefixes used in the keys
+pub fn context() -> std::collections::HashMap<String, serde_json::Value> {
+    std::collections::HashMap::from([
+        ("dspace".to_string(), serde_json::Value::String(DSPACE_NS.to_string())),
+        ("odrl".to_string(), serde_json::Value::String(ODRL_NS.to_string())),
+        ("dct".to_string(), serde_json::Value::String(DCT_NS.to_string())),
+    ])
+}
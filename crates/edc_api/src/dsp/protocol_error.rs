@@ -0,0 +1,47 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Error returned by the protocol endpoints, e.g. `dspace:ContractNegotiationError`
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ProtocolError {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid", default, skip_serializing_if = "Option::is_none")]
+    pub provider_pid: Option<String>,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid", default, skip_serializing_if = "Option::is_none")]
+    pub consumer_pid: Option<String>,
+    #[serde(rename = "dspace:code", alias = "code", default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(rename = "dspace:reason", alias = "reason", default, skip_serializing_if = "Vec::is_empty", deserialize_with = "crate::json_ld::deserialize_values")]
+    pub reason: Vec<serde_json::Value>,
+}
+
+impl ProtocolError {
+
+    pub fn new(at_type: String) -> ProtocolError {
+        ProtocolError {
+            at_type: Some(at_type),
+            ..ProtocolError::default()
+        }
+    }
+
+    pub fn default() -> ProtocolError {
+        ProtocolError {
+            context: crate::dsp::context(),
+            at_type: None,
+            provider_pid: None,
+            consumer_pid: None,
+            code: None,
+            reason: Vec::new(),
+        }
+    }
+
+}
@@ -0,0 +1,61 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// State of a transfer process as reported by the counter party
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TransferProcess {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:state", alias = "state")]
+    pub state: TransferState,
+}
+
+impl TransferProcess {
+
+    pub fn new(provider_pid: String, consumer_pid: String, state: TransferState) -> TransferProcess {
+        TransferProcess {
+            provider_pid,
+            consumer_pid,
+            state,
+            ..TransferProcess::default()
+        }
+    }
+
+    pub fn default() -> TransferProcess {
+        TransferProcess {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:TransferProcess".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            state: TransferState::default(),
+        }
+    }
+
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum TransferState {
+    #[default]
+    #[serde(rename = "dspace:REQUESTED", alias = "REQUESTED")]
+    Requested,
+    #[serde(rename = "dspace:STARTED", alias = "STARTED")]
+    Started,
+    #[serde(rename = "dspace:SUSPENDED", alias = "SUSPENDED")]
+    Suspended,
+    #[serde(rename = "dspace:COMPLETED", alias = "COMPLETED")]
+    Completed,
+    #[serde(rename = "dspace:TERMINATED", alias = "TERMINATED")]
+    Terminated,
+}
@@ -0,0 +1,52 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Requests a transfer of the data covered by an agreement. Push transfers name the destination in `data_address`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TransferRequestMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:agreementId", alias = "agreementId")]
+    pub agreement_id: String,
+    #[serde(rename = "dct:format", alias = "format", default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(rename = "dspace:dataAddress", alias = "dataAddress", default, skip_serializing_if = "Option::is_none")]
+    pub data_address: Option<crate::dsp::DataAddress>,
+    #[serde(rename = "dspace:callbackAddress", alias = "callbackAddress")]
+    pub callback_address: String,
+}
+
+impl TransferRequestMessage {
+
+    pub fn new(consumer_pid: String, agreement_id: String, callback_address: String) -> TransferRequestMessage {
+        TransferRequestMessage {
+            consumer_pid,
+            agreement_id,
+            callback_address,
+            ..TransferRequestMessage::default()
+        }
+    }
+
+    pub fn default() -> TransferRequestMessage {
+        TransferRequestMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:TransferRequestMessage".to_string()),
+            consumer_pid: String::new(),
+            agreement_id: String::new(),
+            format: None,
+            data_address: None,
+            callback_address: String::new(),
+        }
+    }
+
+}
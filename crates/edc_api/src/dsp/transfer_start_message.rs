@@ -0,0 +1,45 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Starts or resumes a transfer. For pull transfers the provider hands out the endpoint in `data_address`.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TransferStartMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:dataAddress", alias = "dataAddress", default, skip_serializing_if = "Option::is_none")]
+    pub data_address: Option<crate::dsp::DataAddress>,
+}
+
+impl TransferStartMessage {
+
+    pub fn new(provider_pid: String, consumer_pid: String) -> TransferStartMessage {
+        TransferStartMessage {
+            provider_pid,
+            consumer_pid,
+            ..TransferStartMessage::default()
+        }
+    }
+
+    pub fn default() -> TransferStartMessage {
+        TransferStartMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:TransferStartMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            data_address: None,
+        }
+    }
+
+}
@@ -0,0 +1,48 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+/// Terminates a transfer
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TransferTerminationMessage {
+    #[serde(rename = "@context", default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub at_type: Option<String>,
+    #[serde(rename = "dspace:providerPid", alias = "providerPid")]
+    pub provider_pid: String,
+    #[serde(rename = "dspace:consumerPid", alias = "consumerPid")]
+    pub consumer_pid: String,
+    #[serde(rename = "dspace:code", alias = "code", default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(rename = "dspace:reason", alias = "reason", default, skip_serializing_if = "Vec::is_empty", deserialize_with = "crate::json_ld::deserialize_values")]
+    pub reason: Vec<serde_json::Value>,
+}
+
+impl TransferTerminationMessage {
+
+    pub fn new(provider_pid: String, consumer_pid: String) -> TransferTerminationMessage {
+        TransferTerminationMessage {
+            provider_pid,
+            consumer_pid,
+            ..TransferTerminationMessage::default()
+        }
+    }
+
+    pub fn default() -> TransferTerminationMessage {
+        TransferTerminationMessage {
+            context: crate::dsp::context(),
+            at_type: Some("dspace:TransferTerminationMessage".to_string()),
+            provider_pid: String::new(),
+            consumer_pid: String::new(),
+            code: None,
+            reason: Vec::new(),
+        }
+    }
+
+}
@@ -0,0 +1,174 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Events that a connector posts to the `uri` of a [`CallbackAddress`](crate::CallbackAddress). The `events` of the
+//! callback address select them by [`Event::name`], or by a prefix of it like `contract.negotiation`.
+
+
+/// Body of a callback request
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    #[serde(rename = "id")]
+    pub id: String,
+    /// Milliseconds since the epoch
+    #[serde(rename = "at")]
+    pub at: i64,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
+pub enum Event {
+    ContractNegotiationInitiated(ContractNegotiationEvent),
+    ContractNegotiationRequested(ContractNegotiationEvent),
+    ContractNegotiationOffered(ContractNegotiationEvent),
+    ContractNegotiationAccepted(ContractNegotiationEvent),
+    ContractNegotiationAgreed(ContractNegotiationEvent),
+    ContractNegotiationVerified(ContractNegotiationEvent),
+    ContractNegotiationFinalized(ContractNegotiationEvent),
+    ContractNegotiationTerminated(ContractNegotiationEvent),
+    TransferProcessInitiated(TransferProcessEvent),
+    TransferProcessProvisioningRequested(TransferProcessEvent),
+    TransferProcessProvisioned(TransferProcessEvent),
+    TransferProcessRequested(TransferProcessEvent),
+    TransferProcessStarted(TransferProcessEvent),
+    TransferProcessSuspended(TransferProcessEvent),
+    TransferProcessCompleted(TransferProcessEvent),
+    TransferProcessTerminated(TransferProcessEvent),
+    TransferProcessDeprovisioningRequested(TransferProcessEvent),
+    TransferProcessDeprovisioned(TransferProcessEvent),
+    /// Events without a typed variant, e.g. those of asset or policy changes
+    #[serde(untagged)]
+    Other {
+        #[serde(rename = "type")]
+        r#type: String,
+        #[serde(rename = "payload", default)]
+        payload: serde_json::Value,
+    },
+}
+
+impl Event {
+
+    /// Type of the event as sent by the connector, e.g. `ContractNegotiationFinalized`
+    pub fn r#type(&self) -> &str {
+        match self {
+            Event::ContractNegotiationInitiated(_) => "ContractNegotiationInitiated",
+            Event::ContractNegotiationRequested(_) => "ContractNegotiationRequested",
+            Event::ContractNegotiationOffered(_) => "ContractNegotiationOffered",
+            Event::ContractNegotiationAccepted(_) => "ContractNegotiationAccepted",
+            Event::ContractNegotiationAgreed(_) => "ContractNegotiationAgreed",
+            Event::ContractNegotiationVerified(_) => "ContractNegotiationVerified",
+            Event::ContractNegotiationFinalized(_) => "ContractNegotiationFinalized",
+            Event::ContractNegotiationTerminated(_) => "ContractNegotiationTerminated",
+            Event::TransferProcessInitiated(_) => "TransferProcessInitiated",
+            Event::TransferProcessProvisioningRequested(_) => "TransferProcessProvisioningRequested",
+            Event::TransferProcessProvisioned(_) => "TransferProcessProvisioned",
+            Event::TransferProcessRequested(_) => "TransferProcessRequested",
+            Event::TransferProcessStarted(_) => "TransferProcessStarted",
+            Event::TransferProcessSuspended(_) => "TransferProcessSuspended",
+            Event::TransferProcessCompleted(_) => "TransferProcessCompleted",
+            Event::TransferProcessTerminated(_) => "TransferProcessTerminated",
+            Event::TransferProcessDeprovisioningRequested(_) => "TransferProcessDeprovisioningRequested",
+            Event::TransferProcessDeprovisioned(_) => "TransferProcessDeprovisioned",
+            Event::Other { r#type, .. } => r#type,
+        }
+    }
+
+    /// Name of the event as used in the `events` of a callback address, e.g. `contract.negotiation.finalized`
+    pub fn name(&self) -> String {
+        let r#type = self.r#type();
+        for (prefix, namespace) in [("ContractNegotiation", "contract.negotiation"), ("TransferProcess", "transfer.process")] {
+            if let Some(event) = r#type.strip_prefix(prefix) {
+                let mut chars = event.chars();
+                return match chars.next() {
+                    Some(first) => format!("{}.{}{}", namespace, first.to_ascii_lowercase(), chars.as_str()),
+                    None => namespace.to_string(),
+                };
+            }
+        }
+        r#type.to_string()
+    }
+
+    pub fn contract_negotiation(&self) -> Option<&ContractNegotiationEvent> {
+        match self {
+            Event::ContractNegotiationInitiated(event)
+            | Event::ContractNegotiationRequested(event)
+            | Event::ContractNegotiationOffered(event)
+            | Event::ContractNegotiationAccepted(event)
+            | Event::ContractNegotiationAgreed(event)
+            | Event::ContractNegotiationVerified(event)
+            | Event::ContractNegotiationFinalized(event)
+            | Event::ContractNegotiationTerminated(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    pub fn transfer_process(&self) -> Option<&TransferProcessEvent> {
+        match self {
+            Event::TransferProcessInitiated(event)
+            | Event::TransferProcessProvisioningRequested(event)
+            | Event::TransferProcessProvisioned(event)
+            | Event::TransferProcessRequested(event)
+            | Event::TransferProcessStarted(event)
+            | Event::TransferProcessSuspended(event)
+            | Event::TransferProcessCompleted(event)
+            | Event::TransferProcessTerminated(event)
+            | Event::TransferProcessDeprovisioningRequested(event)
+            | Event::TransferProcessDeprovisioned(event) => Some(event),
+            _ => None,
+        }
+    }
+
+}
+
+/// Payload of the contract negotiation events. Offers and the agreement are sent as the connector stores them
+/// internally, not in their JSON-LD form, so they are kept as plain json.
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct ContractNegotiationEvent {
+    #[serde(rename = "contractNegotiationId")]
+    pub contract_negotiation_id: String,
+    #[serde(rename = "counterPartyAddress", skip_serializing_if = "Option::is_none")]
+    pub counter_party_address: Option<String>,
+    #[serde(rename = "counterPartyId", skip_serializing_if = "Option::is_none")]
+    pub counter_party_id: Option<String>,
+    #[serde(rename = "protocol", skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(rename = "callbackAddresses", default, skip_serializing_if = "Vec::is_empty")]
+    pub callback_addresses: Vec<crate::CallbackAddress>,
+    #[serde(rename = "contractOffers", default, skip_serializing_if = "Vec::is_empty")]
+    pub contract_offers: Vec<serde_json::Value>,
+    #[serde(rename = "lastContractOffer", skip_serializing_if = "Option::is_none")]
+    pub last_contract_offer: Option<serde_json::Value>,
+    /// Only sent once the negotiation is agreed
+    #[serde(rename = "contractAgreement", skip_serializing_if = "Option::is_none")]
+    pub contract_agreement: Option<serde_json::Value>,
+}
+
+/// Payload of the transfer process events
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct TransferProcessEvent {
+    #[serde(rename = "transferProcessId")]
+    pub transfer_process_id: String,
+    #[serde(rename = "callbackAddresses", default, skip_serializing_if = "Vec::is_empty")]
+    pub callback_addresses: Vec<crate::CallbackAddress>,
+    #[serde(rename = "assetId", skip_serializing_if = "Option::is_none")]
+    pub asset_id: Option<String>,
+    /// `CONSUMER` or `PROVIDER`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(rename = "contractId", skip_serializing_if = "Option::is_none")]
+    pub contract_id: Option<String>,
+    /// Only sent with [`Event::TransferProcessStarted`] of pull transfers, the data address to pull the data from
+    #[serde(rename = "dataAddress", skip_serializing_if = "Option::is_none")]
+    pub data_address: Option<serde_json::Value>,
+    /// Only sent with [`Event::TransferProcessTerminated`]
+    #[serde(rename = "reason", skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
@@ -0,0 +1,41 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+/// Reads a reference that is either given as plain IRI or as node object `{"@id": "..."}`
+pub(crate) fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(match value {
+        Some(serde_json::Value::String(id)) => Some(id),
+        Some(serde_json::Value::Object(node)) => node.get("@id").and_then(|id| id.as_str()).map(|id| id.to_string()),
+        _ => None,
+    })
+}
+
+/// Reads a node that is either embedded as object or only referenced by its IRI
+pub(crate) fn deserialize_node<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<Option<T>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(id)) => serde_json::from_value(serde_json::json!({ "@id": id })).map(Some).map_err(serde::de::Error::custom),
+        Some(node) => serde_json::from_value(node).map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// Reads a list of nodes that is compacted to a single node if it has only one element
+pub(crate) fn deserialize_values<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<serde_json::Value>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(match value {
+        None | Some(serde_json::Value::Null) => Vec::new(),
+        Some(serde_json::Value::Array(values)) => values,
+        Some(value) => vec![value],
+    })
+}
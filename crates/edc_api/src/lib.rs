@@ -9,6 +9,8 @@ pub mod asset_output;
 pub use self::asset_output::AssetOutput;
 pub mod callback_address;
 pub use self::callback_address::CallbackAddress;
+pub mod catalog;
+pub use self::catalog::Catalog;
 pub mod catalog_request;
 pub use self::catalog_request::CatalogRequest;
 pub mod contract_agreement;
@@ -21,22 +23,33 @@ pub mod contract_negotiation;
 pub use self::contract_negotiation::ContractNegotiation;
 pub use self::contract_negotiation::NegotiationState;
 pub use self::contract_negotiation::ContractNegotiationState;
+pub mod contract_offer;
+pub use self::contract_offer::ContractOffer;
 pub mod contract_offer_description;
 pub use self::contract_offer_description::ContractOfferDescription;
 pub mod contract_request;
 pub use self::contract_request::ContractRequest;
 pub mod criterion;
-pub use self::criterion::Criterion;
+pub use self::criterion::{Criterion, CriterionOperator};
 pub mod data_address;
 pub use self::data_address::DataAddress;
+pub mod data_service;
+pub use self::data_service::DataService;
 pub mod dataplane_instance_schema;
 pub use self::dataplane_instance_schema::DataPlaneInstanceSchema;
+pub mod dataset;
+pub use self::dataset::Dataset;
 pub mod dataset_request;
 pub use self::dataset_request::DatasetRequest;
 pub mod deprovisioned_resource;
 pub use self::deprovisioned_resource::DeprovisionedResource;
+pub mod distribution;
+pub use self::distribution::Distribution;
+pub mod dsp;
 pub mod endpoint_data_reference_entry;
 pub use self::endpoint_data_reference_entry::EndpointDataReferenceEntry;
+pub mod event;
+pub use self::event::{Event, EventEnvelope};
 pub mod failure;
 pub use self::failure::Failure;
 pub mod health_check_result;
@@ -45,6 +58,7 @@ pub mod health_status;
 pub use self::health_status::HealthStatus;
 pub mod id_response;
 pub use self::id_response::IdResponse;
+pub(crate) mod json_ld;
 pub mod negotiation_state;
 pub mod offer;
 pub use self::offer::Offer;
@@ -54,6 +68,8 @@ pub mod policy_definition_output;
 pub use self::policy_definition_output::PolicyDefinitionOutput;
 pub mod provisioner_webhook_request;
 pub use self::provisioner_webhook_request::ProvisionerWebhookRequest;
+pub mod query;
+pub use self::query::Query;
 pub mod query_spec;
 pub use self::query_spec::QuerySpec;
 pub mod secret_input;
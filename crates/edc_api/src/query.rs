@@ -0,0 +1,289 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Fluent builder for [`QuerySpec`]s, e.g.
+//!
+//! ```
+//! use edc_api::Query;
+//! use edc_api::query_spec::SortOrder;
+//!
+//! let query_spec = Query::assets()
+//!     .where_("edc:id").eq("asset-1")
+//!     .sort_by("name", SortOrder::Asc)
+//!     .limit(50)
+//!     .build();
+//! ```
+
+use crate::query_spec::SortOrder;
+use crate::{Criterion, CriterionOperator, QuerySpec};
+
+
+const EDC_NS: &str = "https://w3id.org/edc/v0.0.1/ns/";
+
+/// Compact IRI prefixes that are expanded in property paths
+const PREFIXES: [(&str, &str); 5] = [
+    ("edc", EDC_NS),
+    ("odrl", "http://www.w3.org/ns/odrl/2/"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dct", "http://purl.org/dc/terms/"),
+    ("dspace", "https://w3id.org/dspace/v0.8/"),
+];
+
+/// Path to a (nested) property of the queried objects.
+///
+/// Segments are separated by `.`. Since IRIs contain dots themselves, a segment is either quoted with `'`, or an IRI
+/// that extends to the end of the path, e.g. `privateProperties.'https://w3id.org/edc/v0.0.1/ns/key'` or
+/// `https://w3id.org/edc/v0.0.1/ns/id`. Compact IRIs like `edc:id` are expanded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyPath {
+    segments: Vec<String>,
+}
+
+impl PropertyPath {
+
+    pub fn new<S: Into<String>>(segments: impl IntoIterator<Item = S>) -> PropertyPath {
+        PropertyPath {
+            segments: segments.into_iter().map(|segment| expand(segment.into())).collect(),
+        }
+    }
+
+    pub fn parse(path: &str) -> PropertyPath {
+        let mut segments = Vec::new();
+        let mut rest = path;
+        while !rest.is_empty() {
+            let (segment, remainder) = if let Some(quoted) = rest.strip_prefix('\'') {
+                match quoted.find('\'') {
+                    Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                    None => (quoted, ""),
+                }
+            } else if is_iri(rest) {
+                (rest, "")
+            } else {
+                match rest.find('.') {
+                    Some(end) => (&rest[..end], &rest[end..]),
+                    None => (rest, ""),
+                }
+            };
+            segments.push(segment.to_string());
+            rest = remainder.strip_prefix('.').unwrap_or(remainder);
+        }
+        PropertyPath::new(segments)
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Expands the segments that are neither IRIs nor compact IRIs with the given vocabulary
+    fn with_vocab(self, vocab: &str) -> PropertyPath {
+        PropertyPath {
+            segments: self.segments.into_iter()
+                .map(|segment| if segment.contains(':') { segment } else { format!("{}{}", vocab, segment) })
+                .collect(),
+        }
+    }
+
+}
+
+impl std::fmt::Display for PropertyPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A single IRI is sent as is, that is how connectors expect top level properties of assets
+        if let [segment] = self.segments.as_slice() {
+            return f.write_str(segment);
+        }
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            if segment.contains('.') {
+                write!(f, "'{}'", segment)?;
+            } else {
+                f.write_str(segment)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for PropertyPath {
+    fn from(path: &str) -> Self {
+        PropertyPath::parse(path)
+    }
+}
+
+impl From<String> for PropertyPath {
+    fn from(path: String) -> Self {
+        PropertyPath::parse(&path)
+    }
+}
+
+fn is_iri(value: &str) -> bool {
+    match value.split_once("://") {
+        Some((scheme, _)) => !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.')),
+        None => false,
+    }
+}
+
+fn expand(segment: String) -> String {
+    if let Some((prefix, local)) = segment.split_once(':') {
+        if let Some((_, namespace)) = PREFIXES.iter().find(|(candidate, _)| *candidate == prefix) {
+            if !local.starts_with("//") {
+                return format!("{}{}", namespace, local);
+            }
+        }
+    }
+    segment
+}
+
+/// Builder for a [`QuerySpec`]. Conditions added with [`Query::where_`] must all match.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Query {
+    vocab: Option<&'static str>,
+    filter_expression: Vec<Criterion>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    sort_field: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl Query {
+
+    /// Query that uses the property paths as given, e.g. `state` or `contractAgreement.assetId` for negotiations
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    /// Query for assets. Asset properties are stored expanded, so plain names like `name` refer to the EDC namespace.
+    pub fn assets() -> Query {
+        Query {
+            vocab: Some(EDC_NS),
+            ..Query::default()
+        }
+    }
+
+    /// Starts a condition on the property at the given path
+    pub fn where_(self, path: impl Into<PropertyPath>) -> Condition {
+        let path = path.into();
+        let path = match self.vocab {
+            Some(vocab) => path.with_vocab(vocab),
+            None => path,
+        };
+        Condition { query: self, path }
+    }
+
+    /// Same as [`Query::where_`], reads better after the first condition
+    pub fn and(self, path: impl Into<PropertyPath>) -> Condition {
+        self.where_(path)
+    }
+
+    pub fn sort_by(mut self, path: impl Into<PropertyPath>, sort_order: SortOrder) -> Query {
+        let path = path.into();
+        let path = match self.vocab {
+            Some(vocab) => path.with_vocab(vocab),
+            None => path,
+        };
+        self.sort_field = Some(path.to_string());
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn limit(mut self, limit: i32) -> Query {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i32) -> Query {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Only the conditions, e.g. for the assets selector of a contract definition
+    pub fn criteria(self) -> Vec<Criterion> {
+        self.filter_expression
+    }
+
+    pub fn build(self) -> QuerySpec {
+        QuerySpec {
+            at_context: Some(std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(EDC_NS.to_string()))])),
+            at_type: Some("QuerySpec".to_string()),
+            filter_expression: self.filter_expression,
+            limit: self.limit,
+            offset: self.offset,
+            sort_field: self.sort_field,
+            sort_order: self.sort_order,
+        }
+    }
+
+}
+
+impl From<Query> for QuerySpec {
+    fn from(query: Query) -> Self {
+        query.build()
+    }
+}
+
+/// Condition on a property, completed by choosing the operator
+#[must_use = "the condition is only added to the query once an operator is chosen"]
+#[derive(Clone, Debug)]
+pub struct Condition {
+    query: Query,
+    path: PropertyPath,
+}
+
+impl Condition {
+
+    pub fn matches(mut self, operator: CriterionOperator, operand_right: serde_json::Value) -> Query {
+        self.query.filter_expression.push(Criterion::with_operator(self.path.to_string(), operator, operand_right));
+        self.query
+    }
+
+    pub fn eq(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Eq, value.into())
+    }
+
+    pub fn ne(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Ne, value.into())
+    }
+
+    pub fn in_<V: Into<serde_json::Value>>(self, values: impl IntoIterator<Item = V>) -> Query {
+        self.matches(CriterionOperator::In, serde_json::Value::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    /// SQL like pattern, `%` matches any sequence of characters
+    pub fn like(self, pattern: impl Into<String>) -> Query {
+        self.matches(CriterionOperator::Like, serde_json::Value::String(pattern.into()))
+    }
+
+    /// Case insensitive [`Condition::like`]
+    pub fn ilike(self, pattern: impl Into<String>) -> Query {
+        self.matches(CriterionOperator::ILike, serde_json::Value::String(pattern.into()))
+    }
+
+    pub fn lt(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Lt, value.into())
+    }
+
+    pub fn le(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Le, value.into())
+    }
+
+    pub fn gt(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Gt, value.into())
+    }
+
+    pub fn ge(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Ge, value.into())
+    }
+
+    /// The property is a list that contains the value
+    pub fn contains(self, value: impl Into<serde_json::Value>) -> Query {
+        self.matches(CriterionOperator::Contains, value.into())
+    }
+
+}
@@ -13,7 +13,7 @@ pub struct QuerySpec {
     pub at_context: Option<std::collections::HashMap<String, serde_json::Value>>,
     #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
     pub at_type: Option<String>,
-    #[serde(rename = "filterExpression", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "filterExpression", default, skip_serializing_if = "Vec::is_empty")]
     pub filter_expression: Vec<crate::Criterion>,
     #[serde(rename = "limit", skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
@@ -15,7 +15,7 @@ pub struct SecretOutput {
     pub at_type: Option<String>,
     #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
     pub at_id: Option<String>,
-    #[serde(rename = "https://w3id.org/edc/v0.0.1/ns/value")]
+    #[serde(rename = "https://w3id.org/edc/v0.0.1/ns/value", alias = "value")]
     pub value: String,
 }
 
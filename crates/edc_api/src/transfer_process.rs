@@ -21,7 +21,7 @@ pub struct TransferProcess {
     pub at_id: Option<String>,
     #[serde(rename = "correlationId", skip_serializing_if = "Option::is_none")]
     pub correlation_id: Option<String>,
-    #[serde(rename = "callbackAddresses", skip_serializing_if = "Vec::is_empty")]
+    #[serde(rename = "callbackAddresses", default, skip_serializing_if = "Vec::is_empty")]
     #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
     pub callback_addresses: Vec<crate::CallbackAddress>,
     #[serde(rename = "assetId", skip_serializing_if = "Option::is_none")]
@@ -56,6 +56,16 @@ pub enum TransferProcessState {
     Deprovisioned,
 }
 
+impl TransferProcessState {
+
+    /// Whether the transfer process has ended. A completed or terminated process may still be deprovisioned, but it
+    /// doesn't transfer data anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, TransferProcessState::Completed | TransferProcessState::Terminated | TransferProcessState::Deprovisioned)
+    }
+
+}
+
 impl Default for TransferState {
     fn default() -> Self {
         Self {
@@ -9,152 +9,35 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use super::{Error, configuration};
-
-
-/// struct for typed errors of method [`check_health`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CheckHealthError {
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_liveness`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetLivenessError {
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_readiness`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetReadinessError {
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_startup`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetStartupError {
-    UnknownValue(serde_json::Value),
-}
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Performs a liveness probe to determine whether the runtime is working properly.
 #[deprecated(note = "This endpoint is deprecated since management api version 0.5.2-SNAPSHOT")]
-pub async fn check_health(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<CheckHealthError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/check/health", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CheckHealthError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn check_health(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, "/check/health")
+        .execute().await
 }
 
 /// Performs a liveness probe to determine whether the runtime is working properly.
 #[deprecated(note = "This endpoint is deprecated since management api version 0.5.2-SNAPSHOT")]
-pub async fn get_liveness(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<GetLivenessError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/check/liveness", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetLivenessError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_liveness(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, "/check/liveness")
+        .execute().await
 }
 
 /// Performs a readiness probe to determine whether the runtime is able to accept requests.
 #[deprecated(note = "This endpoint is deprecated since management api version 0.5.2-SNAPSHOT")]
-pub async fn get_readiness(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<GetReadinessError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/check/readiness", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetReadinessError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_readiness(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, "/check/readiness")
+        .execute().await
 }
 
 /// Performs a startup probe to determine whether the runtime has completed startup.
 #[deprecated(note = "This endpoint is deprecated since management api version 0.5.2-SNAPSHOT")]
-pub async fn get_startup(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<GetStartupError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/check/startup", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetStartupError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_startup(configuration: &configuration::Configuration) -> Result<edc_api::HealthStatus, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, "/check/startup")
+        .execute().await
 }
 
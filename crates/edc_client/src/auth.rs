@@ -0,0 +1,194 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Access tokens for connectors that sit behind an identity provider. A [`TokenProvider`] on the configuration is asked
+//! for a token before every management request, and told to drop it when the connector answers with `401`.
+
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+
+
+/// Source of bearer tokens for the management api
+pub trait TokenProvider: fmt::Debug + Send + Sync {
+
+    /// Returns a valid access token, fetching a new one if necessary
+    fn token(&self) -> BoxFuture<'_, Result<String, TokenError>>;
+
+    /// Drops the token `rejected` by the connector, e.g. because it was revoked before it expired. The next call of
+    /// [`TokenProvider::token`] fetches a new one, unless a concurrent request already replaced it.
+    fn invalidate(&self, _rejected: &str) {}
+
+}
+
+#[derive(Debug)]
+pub enum TokenError {
+    Reqwest(reqwest::Error),
+    /// The token endpoint answered with an error, e.g. because of wrong client credentials
+    ResponseError { status: reqwest::StatusCode, content: String },
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::Reqwest(e) => write!(f, "token request failed: {}", e),
+            TokenError::ResponseError { status, .. } => write!(f, "token endpoint answered with status code {}", status),
+        }
+    }
+}
+
+impl std::error::Error for TokenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TokenError::Reqwest(e) => Some(e),
+            TokenError::ResponseError { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for TokenError {
+    fn from(e: reqwest::Error) -> Self {
+        TokenError::Reqwest(e)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    refresh_at: Option<Instant>,
+}
+
+/// OAuth2 client credentials grant, e.g. against a Keycloak realm at
+/// `https://keycloak/realms/<realm>/protocol/openid-connect/token`.
+///
+/// Tokens are cached and refreshed `refresh_before` their expiry, so requests don't fail with an expired token. Concurrent
+/// requests share one refresh.
+pub struct ClientCredentials {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    refresh_before: Duration,
+    client: reqwest::Client,
+    cached: Mutex<Option<CachedToken>>,
+    refresh: tokio::sync::Mutex<()>,
+}
+
+impl ClientCredentials {
+
+    pub fn new(token_url: impl Into<String>, client_id: impl Into<String>, client_secret: impl Into<String>) -> ClientCredentials {
+        ClientCredentials {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scope: None,
+            refresh_before: Duration::from_secs(30),
+            client: reqwest::Client::new(),
+            cached: Mutex::new(None),
+            refresh: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    pub fn with_refresh_before(mut self, refresh_before: Duration) -> Self {
+        self.refresh_before = refresh_before;
+        self
+    }
+
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    fn cached_token(&self) -> Option<String> {
+        let cached = self.cached.lock().unwrap();
+        cached.as_ref()
+            .filter(|token| token.refresh_at.is_none_or(|refresh_at| Instant::now() < refresh_at))
+            .map(|token| token.access_token.clone())
+    }
+
+    async fn fetch(&self) -> Result<String, TokenError> {
+        let _refresh = self.refresh.lock().await;
+        // Another request may have refreshed the token while this one waited
+        if let Some(token) = self.cached_token() {
+            return Ok(token);
+        }
+
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope.as_str()));
+        }
+
+        let requested_at = Instant::now();
+        let response = self.client.post(&self.token_url).form(&form).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let content = response.text().await?;
+            tracing::debug!(status = status.as_u16(), "Token request failed");
+            return Err(TokenError::ResponseError { status, content });
+        }
+        let token: TokenResponse = response.json().await?;
+        tracing::debug!(expires_in = token.expires_in, "Fetched access token");
+
+        // Short lived tokens are refreshed after half of their lifetime at the latest
+        let refresh_at = token.expires_in.map(Duration::from_secs).map(|lifetime| requested_at + lifetime - self.refresh_before.min(lifetime / 2));
+        *self.cached.lock().unwrap() = Some(CachedToken { access_token: token.access_token.clone(), refresh_at });
+        Ok(token.access_token)
+    }
+
+}
+
+impl fmt::Debug for ClientCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Secrets stay out of debug output
+        f.debug_struct("ClientCredentials")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("scope", &self.scope)
+            .field("refresh_before", &self.refresh_before)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenProvider for ClientCredentials {
+
+    fn token(&self) -> BoxFuture<'_, Result<String, TokenError>> {
+        Box::pin(async move {
+            match self.cached_token() {
+                Some(token) => Ok(token),
+                None => self.fetch().await,
+            }
+        })
+    }
+
+    fn invalidate(&self, rejected: &str) {
+        let mut cached = self.cached.lock().unwrap();
+        if cached.as_ref().is_some_and(|token| token.access_token == rejected) {
+            *cached = None;
+        }
+    }
+
+}
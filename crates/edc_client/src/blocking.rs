@@ -0,0 +1,553 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Synchronous facade of the [`crate::EdcClient`], enabled with the `blocking` feature. It offers the same resources
+//! with the same models and errors, e.g.
+//!
+//! ```no_run
+//! use edc_client::blocking::EdcClient;
+//! use edc_client::configuration::Configuration;
+//!
+//! let client = EdcClient::new(Configuration::default());
+//! let asset = client.assets().get("asset-id")?;
+//! # Ok::<(), edc_client::Error<edc_client::EdcError>>(())
+//! ```
+//!
+//! The client drives the requests on its own tokio runtime, so it must not be used from within an async context.
+//! Streams become iterators that request the next page or state whenever they are advanced.
+
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+
+use super::configuration::Configuration;
+use super::watch::WaitError;
+use super::{EdcError, Error};
+
+
+/// Blocking client for the management api of an EDC connector, see [`crate::EdcClient`]
+#[derive(Debug, Clone)]
+pub struct EdcClient {
+    inner: crate::EdcClient,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EdcClient {
+
+    /// Creates the client together with a single threaded runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime can't be created, like `reqwest::blocking::Client::new` does.
+    pub fn new(configuration: Configuration) -> EdcClient {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create the runtime of the blocking client");
+        EdcClient {
+            inner: crate::EdcClient::new(configuration),
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    pub fn configuration(&self) -> &Configuration {
+        self.inner.configuration()
+    }
+
+    /// The async client the requests are made with, e.g. to configure a [`crate::workflow::NegotiateAndTransfer`] and
+    /// run it with [`EdcClient::block_on`]
+    pub fn as_async(&self) -> &crate::EdcClient {
+        &self.inner
+    }
+
+    /// Runs a future of the async api to completion on the runtime of the client
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn iter<'a, T>(&'a self, stream: impl Stream<Item = T> + 'a) -> Iter<'a, T> {
+        Iter { runtime: &self.runtime, stream: Box::pin(stream) }
+    }
+
+    pub fn assets(&self) -> AssetsClient<'_> {
+        AssetsClient { client: self }
+    }
+
+    pub fn policies(&self) -> PoliciesClient<'_> {
+        PoliciesClient { client: self }
+    }
+
+    pub fn contract_definitions(&self) -> ContractDefinitionsClient<'_> {
+        ContractDefinitionsClient { client: self }
+    }
+
+    pub fn catalog(&self) -> CatalogClient<'_> {
+        CatalogClient { client: self }
+    }
+
+    pub fn negotiations(&self) -> NegotiationsClient<'_> {
+        NegotiationsClient { client: self }
+    }
+
+    pub fn agreements(&self) -> AgreementsClient<'_> {
+        AgreementsClient { client: self }
+    }
+
+    pub fn transfers(&self) -> TransfersClient<'_> {
+        TransfersClient { client: self }
+    }
+
+    pub fn edrs(&self) -> EdrsClient<'_> {
+        EdrsClient { client: self }
+    }
+
+    pub fn dataplanes(&self) -> DataplanesClient<'_> {
+        DataplanesClient { client: self }
+    }
+
+    pub fn secrets(&self) -> SecretsClient<'_> {
+        SecretsClient { client: self }
+    }
+
+    pub fn webhooks(&self) -> WebhooksClient<'_> {
+        WebhooksClient { client: self }
+    }
+
+}
+
+impl From<Configuration> for EdcClient {
+    fn from(configuration: Configuration) -> Self {
+        EdcClient::new(configuration)
+    }
+}
+
+/// Blocking iterator over a stream of the async api, every call to `next` waits for the next item
+pub struct Iter<'a, T> {
+    runtime: &'a tokio::runtime::Runtime,
+    stream: Pin<Box<dyn Stream<Item = T> + 'a>>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl<T> std::fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Iter").finish_non_exhaustive()
+    }
+}
+
+/// Assets of the connector, see [`EdcClient::assets`]
+#[derive(Debug, Clone, Copy)]
+pub struct AssetsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> AssetsClient<'a> {
+
+    /// Creates a new asset together with a data address
+    pub fn create(&self, asset: edc_api::AssetInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.assets().create(asset))
+    }
+
+    /// Gets an asset with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::AssetOutput, Error<EdcError>> {
+        self.client.block_on(self.client.inner.assets().get(id))
+    }
+
+    /// Updates an asset with the given ID if it exists
+    pub fn update(&self, asset: edc_api::AssetInput) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.assets().update(asset))
+    }
+
+    /// Removes an asset with the given ID if possible
+    pub fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.assets().delete(id))
+    }
+
+    /// Request all assets according to a particular query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::AssetOutput>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.assets().query(query_spec))
+    }
+
+    /// Iterates over all assets according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::AssetOutput, Error<EdcError>>> {
+        self.client.iter(self.client.inner.assets().query_stream(query_spec, page_size))
+    }
+
+}
+
+/// Policy definitions of the connector, see [`EdcClient::policies`]
+#[derive(Debug, Clone, Copy)]
+pub struct PoliciesClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> PoliciesClient<'a> {
+
+    /// Creates a new policy definition
+    pub fn create(&self, policy_definition: edc_api::PolicyDefinitionInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.policies().create(policy_definition))
+    }
+
+    /// Gets a policy definition with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::PolicyDefinitionOutput, Error<EdcError>> {
+        self.client.block_on(self.client.inner.policies().get(id))
+    }
+
+    /// Updates an existing policy definition
+    pub fn update(&self, id: &str, policy_definition: edc_api::PolicyDefinitionInput) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.policies().update(id, policy_definition))
+    }
+
+    /// Removes a policy definition with the given ID if possible
+    pub fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.policies().delete(id))
+    }
+
+    /// Returns all policy definitions according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::PolicyDefinitionOutput>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.policies().query(query_spec))
+    }
+
+    /// Iterates over all policy definitions according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::PolicyDefinitionOutput, Error<EdcError>>> {
+        self.client.iter(self.client.inner.policies().query_stream(query_spec, page_size))
+    }
+
+}
+
+/// Contract definitions of the connector, see [`EdcClient::contract_definitions`]
+#[derive(Debug, Clone, Copy)]
+pub struct ContractDefinitionsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> ContractDefinitionsClient<'a> {
+
+    /// Creates a new contract definition
+    pub fn create(&self, contract_definition: edc_api::ContractDefinitionInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.contract_definitions().create(contract_definition))
+    }
+
+    /// Gets a contract definition with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::ContractDefinitionOutput, Error<EdcError>> {
+        self.client.block_on(self.client.inner.contract_definitions().get(id))
+    }
+
+    /// Updates the contract definition with the given ID if it exists
+    pub fn update(&self, contract_definition: edc_api::ContractDefinitionInput) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.contract_definitions().update(contract_definition))
+    }
+
+    /// Removes a contract definition with the given ID if possible
+    pub fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.contract_definitions().delete(id))
+    }
+
+    /// Returns all contract definitions according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractDefinitionOutput>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.contract_definitions().query(query_spec))
+    }
+
+    /// Iterates over all contract definitions according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::ContractDefinitionOutput, Error<EdcError>>> {
+        self.client.iter(self.client.inner.contract_definitions().query_stream(query_spec, page_size))
+    }
+
+}
+
+/// Catalogs and datasets of other connectors, see [`EdcClient::catalog`]
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl CatalogClient<'_> {
+
+    /// Obtains the catalog of a provider connector
+    pub fn request(&self, catalog_request: edc_api::CatalogRequest) -> Result<edc_api::Catalog, Error<EdcError>> {
+        self.client.block_on(self.client.inner.catalog().request(catalog_request))
+    }
+
+    /// Obtains a single dataset from a provider connector
+    pub fn dataset(&self, dataset_request: edc_api::DatasetRequest) -> Result<edc_api::Dataset, Error<EdcError>> {
+        self.client.block_on(self.client.inner.catalog().dataset(dataset_request))
+    }
+
+}
+
+/// Contract negotiations of the connector, see [`EdcClient::negotiations`]
+#[derive(Debug, Clone, Copy)]
+pub struct NegotiationsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> NegotiationsClient<'a> {
+
+    /// Initiates a contract negotiation for a given offer
+    pub fn initiate(&self, contract_request: edc_api::ContractRequest) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().initiate(contract_request))
+    }
+
+    /// Gets a contract negotiation with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::ContractNegotiation, Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().get(id))
+    }
+
+    /// Gets the state of a contract negotiation with the given ID
+    pub fn state(&self, id: &str) -> Result<edc_api::NegotiationState, Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().state(id))
+    }
+
+    /// Gets the agreement of a contract negotiation with the given ID
+    pub fn agreement(&self, id: &str) -> Result<edc_api::ContractAgreement, Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().agreement(id))
+    }
+
+    /// Terminates the contract negotiation
+    pub fn terminate(&self, id: &str, terminate_negotiation: edc_api::TerminateNegotiationSchema) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().terminate(id, terminate_negotiation))
+    }
+
+    /// Returns all contract negotiations according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractNegotiation>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().query(query_spec))
+    }
+
+    /// Iterates over all contract negotiations according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::ContractNegotiation, Error<EdcError>>> {
+        self.client.iter(self.client.inner.negotiations().query_stream(query_spec, page_size))
+    }
+
+    /// Iterates over the contract negotiation with the given ID whenever its state changes, until it is `FINALIZED` or `TERMINATED`
+    pub fn watch(&self, id: &'a str) -> Iter<'a, Result<edc_api::ContractNegotiation, Error<EdcError>>> {
+        self.client.iter(self.client.inner.negotiations().watch(id))
+    }
+
+    /// Waits until the state of the contract negotiation with the given ID matches `predicate`
+    #[allow(clippy::result_large_err)]
+    pub fn wait_until(&self, id: &str, predicate: impl Fn(&edc_api::ContractNegotiationState) -> bool, timeout: Duration) -> Result<edc_api::ContractNegotiation, WaitError<edc_api::ContractNegotiation, EdcError>> {
+        self.client.block_on(self.client.inner.negotiations().wait_until(id, predicate, timeout))
+    }
+
+}
+
+/// Contract agreements of the connector, see [`EdcClient::agreements`]
+#[derive(Debug, Clone, Copy)]
+pub struct AgreementsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> AgreementsClient<'a> {
+
+    /// Gets a contract agreement with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::ContractAgreement, Error<EdcError>> {
+        self.client.block_on(self.client.inner.agreements().get(id))
+    }
+
+    /// Gets the contract negotiation that produced the agreement with the given ID
+    pub fn negotiation(&self, id: &str) -> Result<edc_api::ContractNegotiation, Error<EdcError>> {
+        self.client.block_on(self.client.inner.agreements().negotiation(id))
+    }
+
+    /// Returns all contract agreements according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractAgreement>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.agreements().query(query_spec))
+    }
+
+    /// Iterates over all contract agreements according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::ContractAgreement, Error<EdcError>>> {
+        self.client.iter(self.client.inner.agreements().query_stream(query_spec, page_size))
+    }
+
+}
+
+/// Transfer processes of the connector, see [`EdcClient::transfers`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransfersClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> TransfersClient<'a> {
+
+    /// Initiates a data transfer with the given parameters
+    pub fn initiate(&self, transfer_request: edc_api::TransferRequest) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().initiate(transfer_request))
+    }
+
+    /// Gets a transfer process with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::TransferProcess, Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().get(id))
+    }
+
+    /// Gets the state of a transfer process with the given ID
+    pub fn state(&self, id: &str) -> Result<edc_api::TransferState, Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().state(id))
+    }
+
+    /// Requests the suspension of a transfer process
+    pub fn suspend(&self, id: &str, suspend_transfer: edc_api::SuspendTransfer) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().suspend(id, suspend_transfer))
+    }
+
+    /// Requests the resumption of a suspended transfer process
+    pub fn resume(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().resume(id))
+    }
+
+    /// Requests the termination of a transfer process
+    pub fn terminate(&self, id: &str, terminate_transfer: edc_api::TerminateTransfer) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().terminate(id, terminate_transfer))
+    }
+
+    /// Requests the deprovisioning of a transfer process
+    pub fn deprovision(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().deprovision(id))
+    }
+
+    /// Returns all transfer processes according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::TransferProcess>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.transfers().query(query_spec))
+    }
+
+    /// Iterates over all transfer processes according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::TransferProcess, Error<EdcError>>> {
+        self.client.iter(self.client.inner.transfers().query_stream(query_spec, page_size))
+    }
+
+    /// Iterates over the transfer process with the given ID whenever its state changes, until it is `COMPLETED`, `TERMINATED` or `DEPROVISIONED`
+    pub fn watch(&self, id: &'a str) -> Iter<'a, Result<edc_api::TransferProcess, Error<EdcError>>> {
+        self.client.iter(self.client.inner.transfers().watch(id))
+    }
+
+    /// Waits until the state of the transfer process with the given ID matches `predicate`
+    #[allow(clippy::result_large_err)]
+    pub fn wait_until(&self, id: &str, predicate: impl Fn(&edc_api::transfer_state::TransferProcessState) -> bool, timeout: Duration) -> Result<edc_api::TransferProcess, WaitError<edc_api::TransferProcess, EdcError>> {
+        self.client.block_on(self.client.inner.transfers().wait_until(id, predicate, timeout))
+    }
+
+}
+
+/// Endpoint data references cached by the connector, see [`EdcClient::edrs`]
+#[derive(Debug, Clone, Copy)]
+pub struct EdrsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl<'a> EdrsClient<'a> {
+
+    /// Gets the data address of the EDR for the given transfer process
+    pub fn data_address(&self, transfer_process_id: &str) -> Result<edc_api::DataAddress, Error<EdcError>> {
+        self.client.block_on(self.client.inner.edrs().data_address(transfer_process_id))
+    }
+
+    /// Removes the EDR for the given transfer process
+    pub fn delete(&self, transfer_process_id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.edrs().delete(transfer_process_id))
+    }
+
+    /// Returns all EDR entries according to a query
+    pub fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::EndpointDataReferenceEntry>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.edrs().query(query_spec))
+    }
+
+    /// Iterates over all Edr entries according to a query, requesting `page_size` of them at a time
+    pub fn query_iter(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> Iter<'a, Result<edc_api::EndpointDataReferenceEntry, Error<EdcError>>> {
+        self.client.iter(self.client.inner.edrs().query_stream(query_spec, page_size))
+    }
+
+}
+
+/// Data plane instances registered with the connector, see [`EdcClient::dataplanes`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataplanesClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl DataplanesClient<'_> {
+
+    /// Adds a data plane instance
+    #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT; Dataplanes should register themselves through control-api")]
+    #[allow(deprecated)]
+    pub fn add(&self, data_plane_instance: edc_api::DataPlaneInstanceSchema) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.dataplanes().add(data_plane_instance))
+    }
+
+    /// Finds the best fitting data plane instance for the given request
+    #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT")]
+    #[allow(deprecated)]
+    pub fn find(&self, selection_request: edc_api::SelectionRequestSchema) -> Result<edc_api::DataPlaneInstanceSchema, Error<EdcError>> {
+        self.client.block_on(self.client.inner.dataplanes().find(selection_request))
+    }
+
+    /// Returns all registered data plane instances
+    pub fn all(&self) -> Result<Vec<edc_api::DataPlaneInstanceSchema>, Error<EdcError>> {
+        self.client.block_on(self.client.inner.dataplanes().all())
+    }
+
+}
+
+/// Secrets of the connector, see [`EdcClient::secrets`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretsClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl SecretsClient<'_> {
+
+    /// Creates a new secret
+    pub fn create(&self, secret: edc_api::SecretInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        self.client.block_on(self.client.inner.secrets().create(secret))
+    }
+
+    /// Gets a secret with the given ID
+    pub fn get(&self, id: &str) -> Result<edc_api::SecretOutput, Error<EdcError>> {
+        self.client.block_on(self.client.inner.secrets().get(id))
+    }
+
+    /// Updates a secret with the given ID if it exists
+    pub fn update(&self, secret: edc_api::SecretInput) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.secrets().update(secret))
+    }
+
+    /// Removes a secret with the given ID if possible
+    pub fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.secrets().delete(id))
+    }
+
+}
+
+/// Callbacks of the HTTP provisioner, see [`EdcClient::webhooks`]
+#[derive(Debug, Clone, Copy)]
+pub struct WebhooksClient<'a> {
+    client: &'a EdcClient,
+}
+
+impl WebhooksClient<'_> {
+
+    /// Notifies the connector that a resource was provisioned
+    pub fn provision(&self, process_id: &str, request: edc_api::ProvisionerWebhookRequest) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.webhooks().provision(process_id, request))
+    }
+
+    /// Notifies the connector that a resource was deprovisioned
+    pub fn deprovision(&self, process_id: &str, resource: edc_api::DeprovisionedResource) -> Result<(), Error<EdcError>> {
+        self.client.block_on(self.client.inner.webhooks().deprovision(process_id, resource))
+    }
+
+}
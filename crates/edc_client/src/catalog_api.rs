@@ -10,87 +10,23 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
-
-
-/// struct for typed errors of method [`get_dataset`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetDatasetError {
-    DefaultResponse(serde_json::Value),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`request_catalog`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum RequestCatalogError {
-    DefaultResponse(serde_json::Value),
-    UnknownValue(serde_json::Value),
-}
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Gets single dataset from a connector
-pub async fn get_dataset(configuration: &configuration::Configuration, dataset_request: Option<edc_api::DatasetRequest>) -> Result<serde_json::Value, Error<GetDatasetError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/catalog/dataset/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&dataset_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetDatasetError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_dataset(configuration: &configuration::Configuration, dataset_request: Option<edc_api::DatasetRequest>) -> Result<edc_api::Dataset, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/catalog/dataset/request")
+        .idempotent()
+        .json(&dataset_request)
+        .execute().await
 }
 
 /// Gets contract offers (=catalog) of a single connector
-pub async fn request_catalog(configuration: &configuration::Configuration, catalog_request: Option<edc_api::CatalogRequest>) -> Result<serde_json::Value, Error<RequestCatalogError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/catalog/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&catalog_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<RequestCatalogError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn request_catalog(configuration: &configuration::Configuration, catalog_request: Option<edc_api::CatalogRequest>) -> Result<edc_api::Catalog, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/catalog/request")
+        .idempotent()
+        .json(&catalog_request)
+        .execute().await
 }
 
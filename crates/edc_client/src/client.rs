@@ -0,0 +1,503 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::Stream;
+
+use super::configuration::Configuration;
+use super::watch::{PollInterval, WaitError};
+use super::reconcile::{Manifest, Reconcile};
+use super::workflow::NegotiateAndTransfer;
+use super::{EdcError, Error, asset_api, catalog_api, contract_agreement_api, contract_definition_api, contract_negotiation_api, dataplane_selector_api, edr_cache_api, http_provisioner_webhook_api, policy_definition_api, secret_api, transfer_process_api};
+
+
+/// Client for the management api of an EDC connector.
+///
+/// The client groups the api by resource, e.g. `client.assets().get("asset-id")`. Every call goes through the same
+/// request pipeline, so authentication, the user agent and error decoding are configured once on the [`Configuration`].
+#[derive(Debug, Clone)]
+pub struct EdcClient {
+    configuration: Arc<Configuration>,
+}
+
+impl EdcClient {
+
+    pub fn new(configuration: Configuration) -> EdcClient {
+        EdcClient {
+            configuration: Arc::new(configuration),
+        }
+    }
+
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    pub fn assets(&self) -> AssetsClient<'_> {
+        AssetsClient { configuration: &self.configuration }
+    }
+
+    pub fn policies(&self) -> PoliciesClient<'_> {
+        PoliciesClient { configuration: &self.configuration }
+    }
+
+    pub fn contract_definitions(&self) -> ContractDefinitionsClient<'_> {
+        ContractDefinitionsClient { configuration: &self.configuration }
+    }
+
+    pub fn catalog(&self) -> CatalogClient<'_> {
+        CatalogClient { configuration: &self.configuration }
+    }
+
+    pub fn negotiations(&self) -> NegotiationsClient<'_> {
+        NegotiationsClient { configuration: &self.configuration }
+    }
+
+    pub fn agreements(&self) -> AgreementsClient<'_> {
+        AgreementsClient { configuration: &self.configuration }
+    }
+
+    pub fn transfers(&self) -> TransfersClient<'_> {
+        TransfersClient { configuration: &self.configuration }
+    }
+
+    /// Negotiates a contract for the asset of the provider and starts a transfer, see [`NegotiateAndTransfer`]
+    pub fn negotiate_and_transfer(&self, counter_party_address: impl Into<String>, counter_party_id: impl Into<String>, asset_id: impl Into<String>) -> NegotiateAndTransfer<'_> {
+        NegotiateAndTransfer::new(&self.configuration, counter_party_address, counter_party_id, asset_id)
+    }
+
+    /// Creates, updates or deletes definitions until the connector matches the manifest, see [`Reconcile`]
+    pub fn reconcile(&self, manifest: Manifest) -> Reconcile<'_> {
+        Reconcile::new(&self.configuration, manifest)
+    }
+
+    pub fn edrs(&self) -> EdrsClient<'_> {
+        EdrsClient { configuration: &self.configuration }
+    }
+
+    pub fn dataplanes(&self) -> DataplanesClient<'_> {
+        DataplanesClient { configuration: &self.configuration }
+    }
+
+    pub fn secrets(&self) -> SecretsClient<'_> {
+        SecretsClient { configuration: &self.configuration }
+    }
+
+    pub fn webhooks(&self) -> WebhooksClient<'_> {
+        WebhooksClient { configuration: &self.configuration }
+    }
+
+}
+
+impl From<Configuration> for EdcClient {
+    fn from(configuration: Configuration) -> Self {
+        EdcClient::new(configuration)
+    }
+}
+
+/// Assets of the connector, see [`EdcClient::assets`]
+#[derive(Debug, Clone, Copy)]
+pub struct AssetsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> AssetsClient<'a> {
+
+    /// Creates a new asset together with a data address
+    pub async fn create(&self, asset: edc_api::AssetInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        asset_api::create_asset(self.configuration, Some(asset)).await
+    }
+
+    /// Gets an asset with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::AssetOutput, Error<EdcError>> {
+        asset_api::get_asset(self.configuration, id).await
+    }
+
+    /// Updates an asset with the given ID if it exists
+    pub async fn update(&self, asset: edc_api::AssetInput) -> Result<(), Error<EdcError>> {
+        asset_api::update_asset(self.configuration, Some(asset)).await
+    }
+
+    /// Removes an asset with the given ID if possible
+    pub async fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        asset_api::remove_asset(self.configuration, id).await
+    }
+
+    /// Request all assets according to a particular query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::AssetOutput>, Error<EdcError>> {
+        asset_api::request_assets(self.configuration, query_spec).await
+    }
+
+    /// Streams all assets according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::AssetOutput, Error<EdcError>>> + 'a {
+        asset_api::request_assets_stream(self.configuration, query_spec, page_size)
+    }
+
+}
+
+/// Policy definitions of the connector, see [`EdcClient::policies`]
+#[derive(Debug, Clone, Copy)]
+pub struct PoliciesClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> PoliciesClient<'a> {
+
+    /// Creates a new policy definition
+    pub async fn create(&self, policy_definition: edc_api::PolicyDefinitionInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        policy_definition_api::create_policy_definition(self.configuration, Some(policy_definition)).await
+    }
+
+    /// Gets a policy definition with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::PolicyDefinitionOutput, Error<EdcError>> {
+        policy_definition_api::get_policy_definition(self.configuration, id).await
+    }
+
+    /// Updates an existing policy definition
+    pub async fn update(&self, id: &str, policy_definition: edc_api::PolicyDefinitionInput) -> Result<(), Error<EdcError>> {
+        policy_definition_api::update_policy_definition(self.configuration, id, Some(policy_definition)).await
+    }
+
+    /// Removes a policy definition with the given ID if possible
+    pub async fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        policy_definition_api::delete_policy_definition(self.configuration, id).await
+    }
+
+    /// Returns all policy definitions according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::PolicyDefinitionOutput>, Error<EdcError>> {
+        policy_definition_api::query_policy_definitions(self.configuration, query_spec).await
+    }
+
+    /// Streams all policy definitions according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::PolicyDefinitionOutput, Error<EdcError>>> + 'a {
+        policy_definition_api::query_policy_definitions_stream(self.configuration, query_spec, page_size)
+    }
+
+}
+
+/// Contract definitions of the connector, see [`EdcClient::contract_definitions`]
+#[derive(Debug, Clone, Copy)]
+pub struct ContractDefinitionsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> ContractDefinitionsClient<'a> {
+
+    /// Creates a new contract definition
+    pub async fn create(&self, contract_definition: edc_api::ContractDefinitionInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        contract_definition_api::create_contract_definition(self.configuration, Some(contract_definition)).await
+    }
+
+    /// Gets a contract definition with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::ContractDefinitionOutput, Error<EdcError>> {
+        contract_definition_api::get_contract_definition(self.configuration, id).await
+    }
+
+    /// Updates the contract definition with the given ID if it exists
+    pub async fn update(&self, contract_definition: edc_api::ContractDefinitionInput) -> Result<(), Error<EdcError>> {
+        contract_definition_api::update_contract_definition(self.configuration, Some(contract_definition)).await
+    }
+
+    /// Removes a contract definition with the given ID if possible
+    pub async fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        contract_definition_api::delete_contract_definition(self.configuration, id).await
+    }
+
+    /// Returns all contract definitions according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractDefinitionOutput>, Error<EdcError>> {
+        contract_definition_api::query_all_contract_definitions(self.configuration, query_spec).await
+    }
+
+    /// Streams all contract definitions according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::ContractDefinitionOutput, Error<EdcError>>> + 'a {
+        contract_definition_api::query_all_contract_definitions_stream(self.configuration, query_spec, page_size)
+    }
+
+}
+
+/// Catalogs and datasets of other connectors, see [`EdcClient::catalog`]
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl CatalogClient<'_> {
+
+    /// Obtains the catalog of a provider connector
+    pub async fn request(&self, catalog_request: edc_api::CatalogRequest) -> Result<edc_api::Catalog, Error<EdcError>> {
+        catalog_api::request_catalog(self.configuration, Some(catalog_request)).await
+    }
+
+    /// Obtains a single dataset from a provider connector
+    pub async fn dataset(&self, dataset_request: edc_api::DatasetRequest) -> Result<edc_api::Dataset, Error<EdcError>> {
+        catalog_api::get_dataset(self.configuration, Some(dataset_request)).await
+    }
+
+}
+
+/// Contract negotiations of the connector, see [`EdcClient::negotiations`]
+#[derive(Debug, Clone, Copy)]
+pub struct NegotiationsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> NegotiationsClient<'a> {
+
+    /// Initiates a contract negotiation for a given offer
+    pub async fn initiate(&self, contract_request: edc_api::ContractRequest) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        contract_negotiation_api::initiate_contract_negotiation(self.configuration, Some(contract_request)).await
+    }
+
+    /// Gets a contract negotiation with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::ContractNegotiation, Error<EdcError>> {
+        contract_negotiation_api::get_negotiation(self.configuration, id).await
+    }
+
+    /// Gets the state of a contract negotiation with the given ID
+    pub async fn state(&self, id: &str) -> Result<edc_api::NegotiationState, Error<EdcError>> {
+        contract_negotiation_api::get_negotiation_state(self.configuration, id).await
+    }
+
+    /// Gets the agreement of a contract negotiation with the given ID
+    pub async fn agreement(&self, id: &str) -> Result<edc_api::ContractAgreement, Error<EdcError>> {
+        contract_negotiation_api::get_agreement_for_negotiation(self.configuration, id).await
+    }
+
+    /// Terminates the contract negotiation
+    pub async fn terminate(&self, id: &str, terminate_negotiation: edc_api::TerminateNegotiationSchema) -> Result<(), Error<EdcError>> {
+        contract_negotiation_api::terminate_negotiation(self.configuration, id, Some(terminate_negotiation)).await
+    }
+
+    /// Returns all contract negotiations according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractNegotiation>, Error<EdcError>> {
+        contract_negotiation_api::query_negotiations(self.configuration, query_spec).await
+    }
+
+    /// Streams all contract negotiations according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::ContractNegotiation, Error<EdcError>>> + 'a {
+        contract_negotiation_api::query_negotiations_stream(self.configuration, query_spec, page_size)
+    }
+
+    /// Streams the contract negotiation with the given ID whenever its state changes, until it is `FINALIZED` or `TERMINATED`
+    pub fn watch(&self, id: &'a str) -> impl Stream<Item = Result<edc_api::ContractNegotiation, Error<EdcError>>> + 'a {
+        contract_negotiation_api::watch_negotiation(self.configuration, id, PollInterval::default())
+    }
+
+    /// Waits until the state of the contract negotiation with the given ID matches `predicate`
+    pub async fn wait_until(&self, id: &str, predicate: impl Fn(&edc_api::ContractNegotiationState) -> bool, timeout: Duration) -> Result<edc_api::ContractNegotiation, WaitError<edc_api::ContractNegotiation, EdcError>> {
+        contract_negotiation_api::wait_for_negotiation(self.configuration, id, predicate, timeout).await
+    }
+
+}
+
+/// Contract agreements of the connector, see [`EdcClient::agreements`]
+#[derive(Debug, Clone, Copy)]
+pub struct AgreementsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> AgreementsClient<'a> {
+
+    /// Gets a contract agreement with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::ContractAgreement, Error<EdcError>> {
+        contract_agreement_api::get_agreement_by_id(self.configuration, id).await
+    }
+
+    /// Gets the contract negotiation that produced the agreement with the given ID
+    pub async fn negotiation(&self, id: &str) -> Result<edc_api::ContractNegotiation, Error<EdcError>> {
+        contract_agreement_api::get_negotiation_by_agreement_id(self.configuration, id).await
+    }
+
+    /// Returns all contract agreements according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractAgreement>, Error<EdcError>> {
+        contract_agreement_api::query_all_agreements(self.configuration, query_spec).await
+    }
+
+    /// Streams all contract agreements according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::ContractAgreement, Error<EdcError>>> + 'a {
+        contract_agreement_api::query_all_agreements_stream(self.configuration, query_spec, page_size)
+    }
+
+}
+
+/// Transfer processes of the connector, see [`EdcClient::transfers`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransfersClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> TransfersClient<'a> {
+
+    /// Initiates a data transfer with the given parameters
+    pub async fn initiate(&self, transfer_request: edc_api::TransferRequest) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        transfer_process_api::initiate_transfer_process(self.configuration, Some(transfer_request)).await
+    }
+
+    /// Gets a transfer process with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::TransferProcess, Error<EdcError>> {
+        transfer_process_api::get_transfer_process(self.configuration, id).await
+    }
+
+    /// Gets the state of a transfer process with the given ID
+    pub async fn state(&self, id: &str) -> Result<edc_api::TransferState, Error<EdcError>> {
+        transfer_process_api::get_transfer_process_state(self.configuration, id).await
+    }
+
+    /// Requests the suspension of a transfer process
+    pub async fn suspend(&self, id: &str, suspend_transfer: edc_api::SuspendTransfer) -> Result<(), Error<EdcError>> {
+        transfer_process_api::suspend_transfer_process(self.configuration, id, Some(suspend_transfer)).await
+    }
+
+    /// Requests the resumption of a suspended transfer process
+    pub async fn resume(&self, id: &str) -> Result<(), Error<EdcError>> {
+        transfer_process_api::resume_transfer_process(self.configuration, id).await
+    }
+
+    /// Requests the termination of a transfer process
+    pub async fn terminate(&self, id: &str, terminate_transfer: edc_api::TerminateTransfer) -> Result<(), Error<EdcError>> {
+        transfer_process_api::terminate_transfer_process(self.configuration, id, Some(terminate_transfer)).await
+    }
+
+    /// Requests the deprovisioning of a transfer process
+    pub async fn deprovision(&self, id: &str) -> Result<(), Error<EdcError>> {
+        transfer_process_api::deprovision_transfer_process(self.configuration, id).await
+    }
+
+    /// Returns all transfer processes according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::TransferProcess>, Error<EdcError>> {
+        transfer_process_api::query_transfer_processes(self.configuration, query_spec).await
+    }
+
+    /// Streams all transfer processes according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::TransferProcess, Error<EdcError>>> + 'a {
+        transfer_process_api::query_transfer_processes_stream(self.configuration, query_spec, page_size)
+    }
+
+    /// Streams the transfer process with the given ID whenever its state changes, until it is `COMPLETED`, `TERMINATED` or `DEPROVISIONED`
+    pub fn watch(&self, id: &'a str) -> impl Stream<Item = Result<edc_api::TransferProcess, Error<EdcError>>> + 'a {
+        transfer_process_api::watch_transfer_process(self.configuration, id, PollInterval::default())
+    }
+
+    /// Waits until the state of the transfer process with the given ID matches `predicate`
+    pub async fn wait_until(&self, id: &str, predicate: impl Fn(&edc_api::transfer_state::TransferProcessState) -> bool, timeout: Duration) -> Result<edc_api::TransferProcess, WaitError<edc_api::TransferProcess, EdcError>> {
+        transfer_process_api::wait_for_transfer_process(self.configuration, id, predicate, timeout).await
+    }
+
+}
+
+/// Endpoint data references cached by the connector, see [`EdcClient::edrs`]
+#[derive(Debug, Clone, Copy)]
+pub struct EdrsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl<'a> EdrsClient<'a> {
+
+    /// Gets the data address of the EDR for the given transfer process
+    pub async fn data_address(&self, transfer_process_id: &str) -> Result<edc_api::DataAddress, Error<EdcError>> {
+        edr_cache_api::get_edr_data_address(self.configuration, transfer_process_id).await
+    }
+
+    /// Removes the EDR for the given transfer process
+    pub async fn delete(&self, transfer_process_id: &str) -> Result<(), Error<EdcError>> {
+        edr_cache_api::delete_edr(self.configuration, transfer_process_id).await
+    }
+
+    /// Returns all EDR entries according to a query
+    pub async fn query(&self, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::EndpointDataReferenceEntry>, Error<EdcError>> {
+        edr_cache_api::query_edrs(self.configuration, query_spec).await
+    }
+
+    /// Streams all Edr entries according to a query, requesting `page_size` of them at a time
+    pub fn query_stream(&self, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::EndpointDataReferenceEntry, Error<EdcError>>> + 'a {
+        edr_cache_api::query_edrs_stream(self.configuration, query_spec, page_size)
+    }
+
+}
+
+/// Data plane instances registered with the connector, see [`EdcClient::dataplanes`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataplanesClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl DataplanesClient<'_> {
+
+    /// Adds a data plane instance
+    #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT; Dataplanes should register themselves through control-api")]
+    #[allow(deprecated)]
+    pub async fn add(&self, data_plane_instance: edc_api::DataPlaneInstanceSchema) -> Result<(), Error<EdcError>> {
+        dataplane_selector_api::add_entry(self.configuration, Some(data_plane_instance)).await
+    }
+
+    /// Finds the best fitting data plane instance for the given request
+    #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT")]
+    #[allow(deprecated)]
+    pub async fn find(&self, selection_request: edc_api::SelectionRequestSchema) -> Result<edc_api::DataPlaneInstanceSchema, Error<EdcError>> {
+        dataplane_selector_api::find(self.configuration, Some(selection_request)).await
+    }
+
+    /// Returns all registered data plane instances
+    pub async fn all(&self) -> Result<Vec<edc_api::DataPlaneInstanceSchema>, Error<EdcError>> {
+        dataplane_selector_api::get_all(self.configuration).await
+    }
+
+}
+
+/// Secrets of the connector, see [`EdcClient::secrets`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretsClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl SecretsClient<'_> {
+
+    /// Creates a new secret
+    pub async fn create(&self, secret: edc_api::SecretInput) -> Result<edc_api::IdResponse, Error<EdcError>> {
+        secret_api::create_secret(self.configuration, Some(secret)).await
+    }
+
+    /// Gets a secret with the given ID
+    pub async fn get(&self, id: &str) -> Result<edc_api::SecretOutput, Error<EdcError>> {
+        secret_api::get_secret(self.configuration, id).await
+    }
+
+    /// Updates a secret with the given ID if it exists
+    pub async fn update(&self, secret: edc_api::SecretInput) -> Result<(), Error<EdcError>> {
+        secret_api::update_secret(self.configuration, Some(secret)).await
+    }
+
+    /// Removes a secret with the given ID if possible
+    pub async fn delete(&self, id: &str) -> Result<(), Error<EdcError>> {
+        secret_api::delete_secret(self.configuration, id).await
+    }
+
+}
+
+/// Callbacks of the HTTP provisioner, see [`EdcClient::webhooks`]
+#[derive(Debug, Clone, Copy)]
+pub struct WebhooksClient<'a> {
+    configuration: &'a Configuration,
+}
+
+impl WebhooksClient<'_> {
+
+    /// Notifies the connector that a resource was provisioned
+    pub async fn provision(&self, process_id: &str, request: edc_api::ProvisionerWebhookRequest) -> Result<(), Error<EdcError>> {
+        http_provisioner_webhook_api::call_provision_webhook(self.configuration, process_id, Some(request)).await
+    }
+
+    /// Notifies the connector that a resource was deprovisioned
+    pub async fn deprovision(&self, process_id: &str, resource: edc_api::DeprovisionedResource) -> Result<(), Error<EdcError>> {
+        http_provisioner_webhook_api::call_deprovision_webhook(self.configuration, process_id, Some(resource)).await
+    }
+
+}
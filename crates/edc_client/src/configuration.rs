@@ -8,8 +8,15 @@
  */
 
 
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
 use reqwest;
-use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+
+use crate::auth::TokenProvider;
 
 
 #[derive(Debug, Clone)]
@@ -21,17 +28,43 @@ pub struct Configuration {
     pub oauth_access_token: Option<String>,
     pub bearer_access_token: Option<String>,
     pub api_key: Option<ApiKey>,
-    // TODO: take an oauth2 token source, similar to the go one
+    pub retry: RetryPolicy,
+    /// Source of bearer tokens for management requests, takes precedence over the static access tokens
+    pub token_provider: Option<Arc<dyn TokenProvider>>,
 }
 
+/// Header the management api expects the api key in
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
 pub type BasicAuth = (String, Option<String>);
 
+/// Key for the `X-Api-Key` header. A `prefix` is put in front of the key, separated by a space, e.g. `Some("Key")` sends
+/// `X-Api-Key: Key <key>`.
 #[derive(Debug, Clone)]
 pub struct ApiKey {
     pub prefix: Option<String>,
     pub key: String,
 }
 
+impl ApiKey {
+
+    pub fn new(key: impl Into<String>) -> ApiKey {
+        ApiKey {
+            prefix: None,
+            key: key.into(),
+        }
+    }
+
+    /// Value of the api key header
+    pub fn value(&self) -> String {
+        match &self.prefix {
+            Some(prefix) => format!("{} {}", prefix, self.key),
+            None => self.key.clone(),
+        }
+    }
+
+}
+
 
 impl Configuration {
     pub fn new(base_path: String, user_agent: Option<String>, client: reqwest::Client, basic_auth: Option<BasicAuth>, oauth_access_token: Option<String>,
@@ -44,18 +77,24 @@ impl Configuration {
             oauth_access_token,
             bearer_access_token,
             api_key,
+            retry: RetryPolicy::default(),
+            token_provider: None,
         }
     }
 
-    pub fn with_headers(mut self) -> Self {
-        let mut headers = HeaderMap::new();
-        if let Some(api_key) = &self.api_key {
-            headers.insert("x-api-key", HeaderValue::from_str(&api_key.key).unwrap());
-        }
-        self.client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .unwrap();
+    pub fn with_token_provider(mut self, token_provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(token_provider));
+        self
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Does nothing anymore. Api key, basic auth and tokens are applied to every request, and the `client` is used as given.
+    #[deprecated(note = "Authentication is applied to every request, the configuration can be used as is")]
+    pub fn with_headers(self) -> Self {
         self
     }
 }
@@ -72,8 +111,106 @@ impl Default for Configuration {
             api_key: Some(ApiKey {
                 prefix: None,
                 key: "ApiKeyDefaultValue".to_owned(),
-            })
+            }),
+            retry: RetryPolicy::default(),
+            token_provider: None,
+        }
+    }
+}
+
+/// When and how often failed requests are sent again.
+///
+/// Requests are retried after transport errors and responses with one of the `retryable_status_codes`. Between two attempts
+/// the client waits `initial_backoff * multiplier^(attempt - 1)`, at most `max_backoff`, or as long as a `Retry-After`
+/// header asks for. With `jitter` the wait is randomly shortened by up to half, so clients don't retry in lockstep.
+///
+/// Requests that are not idempotent, like initiating a negotiation, are only retried if `retry_non_idempotent` is set,
+/// since the connector may have processed the first attempt before the connection broke. Failed connects are always
+/// retried, because nothing reached the connector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts including the first one, `1` disables retries
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: bool,
+    pub retryable_status_codes: Vec<StatusCode>,
+    pub retry_non_idempotent: bool,
+}
 
+impl RetryPolicy {
+
+    /// Sends every request exactly once
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
         }
     }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_retryable_status_codes(mut self, retryable_status_codes: Vec<StatusCode>) -> Self {
+        self.retryable_status_codes = retryable_status_codes;
+        self
+    }
+
+    /// Also retry requests that are not idempotent, e.g. because duplicates are handled on the connector side
+    pub fn with_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.retryable_status_codes.contains(&status)
+    }
+
+    /// Time to wait after the given failed attempt, starting at `1`
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        let backoff = Duration::from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()));
+        if self.jitter {
+            backoff.mul_f64(1.0 - random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            retryable_status_codes: vec![StatusCode::TOO_MANY_REQUESTS, StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE, StatusCode::GATEWAY_TIMEOUT],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+/// Random number in `[0, 1)`, good enough to spread retries without pulling in a random number generator
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_nanos());
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
 }
@@ -10,126 +10,35 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
+use futures::Stream;
 
-
-/// struct for typed errors of method [`get_agreement_by_id`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetAgreementByIdError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_negotiation_by_agreement_id`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetNegotiationByAgreementIdError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`query_all_agreements`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum QueryAllAgreementsError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
+use crate::pagination::paginate;
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Gets an contract agreement with the given ID
-pub async fn get_agreement_by_id(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractAgreement, Error<GetAgreementByIdError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractagreements/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAgreementByIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_agreement_by_id(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractAgreement, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, format!("/v2/contractagreements/{id}", id = crate::urlencode(id)))
+        .execute().await
 }
 
 /// Gets a contract negotiation with the given contract agreement ID
-pub async fn get_negotiation_by_agreement_id(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractNegotiation, Error<GetNegotiationByAgreementIdError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractagreements/{id}/negotiation", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetNegotiationByAgreementIdError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_negotiation_by_agreement_id(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractNegotiation, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, format!("/v2/contractagreements/{id}/negotiation", id = crate::urlencode(id)))
+        .execute().await
 }
 
 /// Gets all contract agreements according to a particular query
-pub async fn query_all_agreements(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractAgreement>, Error<QueryAllAgreementsError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractagreements/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&query_spec);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+pub async fn query_all_agreements(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractAgreement>, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/contractagreements/request")
+        .idempotent()
+        .json(&query_spec)
+        .execute().await
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<QueryAllAgreementsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// Streams all contract agreements according to a query, requesting `page_size` of them at a time. `limit` and `offset` of the query are ignored.
+pub fn query_all_agreements_stream(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::ContractAgreement, Error<EdcError>>> + '_ {
+    paginate(query_spec, page_size, move |query_spec| query_all_agreements(configuration, Some(query_spec)))
 }
 
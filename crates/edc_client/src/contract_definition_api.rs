@@ -10,201 +10,49 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
+use futures::Stream;
 
-/// struct for typed errors of method [`create_contract_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CreateContractDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status409(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`delete_contract_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum DeleteContractDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_contract_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetContractDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`query_all_contract_definitions`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum QueryAllContractDefinitionsError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`update_contract_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum UpdateContractDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
+use crate::pagination::paginate;
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Creates a new contract definition
-pub async fn create_contract_definition(configuration: &configuration::Configuration, contract_definition_input: Option<edc_api::ContractDefinitionInput>) -> Result<edc_api::IdResponse, Error<CreateContractDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractdefinitions", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&contract_definition_input);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreateContractDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn create_contract_definition(configuration: &configuration::Configuration, contract_definition_input: Option<edc_api::ContractDefinitionInput>) -> Result<edc_api::IdResponse, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/contractdefinitions")
+        .json(&contract_definition_input)
+        .execute().await
 }
 
 /// Removes a contract definition with the given ID if possible. DANGER ZONE: Note that deleting contract definitions can have unexpected results, especially for contract offers that have been sent out or ongoing or contract negotiations.
-pub async fn delete_contract_definition(configuration: &configuration::Configuration, id: &str) -> Result<(), Error<DeleteContractDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractdefinitions/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteContractDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn delete_contract_definition(configuration: &configuration::Configuration, id: &str) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::DELETE, format!("/v2/contractdefinitions/{id}", id = crate::urlencode(id)))
+        .execute_empty().await
 }
 
 /// Gets an contract definition with the given ID
-pub async fn get_contract_definition(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractDefinitionOutput, Error<GetContractDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractdefinitions/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetContractDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_contract_definition(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::ContractDefinitionOutput, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, format!("/v2/contractdefinitions/{id}", id = crate::urlencode(id)))
+        .execute().await
 }
 
 /// Returns all contract definitions according to a query
-pub async fn query_all_contract_definitions(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractDefinitionOutput>, Error<QueryAllContractDefinitionsError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractdefinitions/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&query_spec);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+pub async fn query_all_contract_definitions(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::ContractDefinitionOutput>, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/contractdefinitions/request")
+        .idempotent()
+        .json(&query_spec)
+        .execute().await
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<QueryAllContractDefinitionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// Streams all contract definitions according to a query, requesting `page_size` of them at a time. `limit` and `offset` of the query are ignored.
+pub fn query_all_contract_definitions_stream(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::ContractDefinitionOutput, Error<EdcError>>> + '_ {
+    paginate(query_spec, page_size, move |query_spec| query_all_contract_definitions(configuration, Some(query_spec)))
 }
 
 /// Updated a contract definition with the given ID. The supplied JSON structure must be a valid JSON-LD object
-pub async fn update_contract_definition(configuration: &configuration::Configuration, contract_definition_input: Option<edc_api::ContractDefinitionInput>) -> Result<(), Error<UpdateContractDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/contractdefinitions", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&contract_definition_input);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<UpdateContractDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn update_contract_definition(configuration: &configuration::Configuration, contract_definition_input: Option<edc_api::ContractDefinitionInput>) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::PUT, "/v2/contractdefinitions")
+        .json(&contract_definition_input)
+        .execute_empty().await
 }
 
@@ -11,126 +11,39 @@
 use reqwest;
 
 use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
-
-
-/// struct for typed errors of method [`add_entry`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum AddEntryError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`find`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum FindError {
-    Status204(Vec<edc_api::ApiErrorDetail>),
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_all`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetAllError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Adds one dataplane instance to the internal database of the selector. DEPRECATED: dataplanes should register themselves through control-api
 #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT; Dataplanes should register themselves through control-api")]
-pub async fn add_entry(configuration: &configuration::Configuration, data_plane_instance_schema: Option<edc_api::DataPlaneInstanceSchema>) -> Result<(), Error<AddEntryError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/dataplanes", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&data_plane_instance_schema);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<AddEntryError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn add_entry(configuration: &configuration::Configuration, data_plane_instance_schema: Option<edc_api::DataPlaneInstanceSchema>) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/dataplanes")
+        .json(&data_plane_instance_schema)
+        .execute_empty().await
 }
 
 /// Finds the best fitting data plane instance for a particular query
 #[deprecated(note="Deprecated since management api version 0.6.5-SNAPSHOT")]
-pub async fn find(configuration: &configuration::Configuration, selection_request_schema: Option<edc_api::SelectionRequestSchema>) -> Result<edc_api::DataPlaneInstanceSchema, Error<FindError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/dataplanes/select", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&selection_request_schema);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if local_var_status == reqwest::StatusCode::NO_CONTENT {
-        Err(Error::ResponseError(ResponseContent { status: local_var_status, content: "No suitable DataPlane instance was found".to_string(), entity: None }))
-    } else if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<FindError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn find(configuration: &configuration::Configuration, selection_request_schema: Option<edc_api::SelectionRequestSchema>) -> Result<edc_api::DataPlaneInstanceSchema, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/dataplanes/select")
+        .idempotent()
+        .no_content_is_error()
+        .json(&selection_request_schema)
+        .execute().await
+        .map_err(|local_var_error| match local_var_error {
+            Error::ResponseError(local_var_response) if local_var_response.status == reqwest::StatusCode::NO_CONTENT => {
+                let local_var_message = "No suitable DataPlane instance was found".to_string();
+                let local_var_detail = edc_api::ApiErrorDetail { message: Some(local_var_message.clone()), ..edc_api::ApiErrorDetail::new() };
+                let local_var_entity = EdcError::new(local_var_response.status, vec![local_var_detail]);
+                Error::ResponseError(ResponseContent { content: local_var_message, entity: Some(local_var_entity), ..local_var_response })
+            }
+            local_var_error => local_var_error,
+        })
 }
 
 /// Returns a list of all currently registered data plane instances
-pub async fn get_all(configuration: &configuration::Configuration) -> Result<Vec<edc_api::DataPlaneInstanceSchema>, Error<GetAllError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/dataplanes", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetAllError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_all(configuration: &configuration::Configuration) -> Result<Vec<edc_api::DataPlaneInstanceSchema>, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, "/v2/dataplanes")
+        .execute().await
 }
\ No newline at end of file
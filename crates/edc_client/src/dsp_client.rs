@@ -0,0 +1,131 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+//! Consumer side of the Dataspace Protocol. The functions talk directly to the protocol endpoint of a provider, e.g.
+//! `http://provider-connector:9194/protocol`, which is taken from the `base_path` of the configuration.
+//!
+//! The `bearer_access_token` of the configuration is sent as identity token in the `Authorization` header. Credentials
+//! of the management api are not sent, but note that default headers of the configured `reqwest::Client` always are.
+
+
+use crate::error::ErrorEntity;
+use crate::request::Request;
+use super::{Error, configuration};
+
+
+/// struct for typed errors of the dataspace protocol functions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DspError {
+    ProtocolError(edc_api::dsp::ProtocolError),
+    UnknownValue(serde_json::Value),
+}
+
+impl ErrorEntity for DspError {
+    fn from_response(_status: reqwest::StatusCode, _headers: &reqwest::header::HeaderMap, content: &str, _retryable: bool) -> Option<Self> {
+        serde_json::from_str(content).ok()
+    }
+}
+
+
+/// Requests the catalog of the provider
+pub async fn request_catalog(configuration: &configuration::Configuration, catalog_request_message: &edc_api::dsp::CatalogRequestMessage) -> Result<edc_api::Catalog, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, "/catalog/request")
+        .idempotent()
+        .json(catalog_request_message)
+        .execute().await
+}
+
+/// Gets a single dataset from the catalog of the provider
+pub async fn get_dataset(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::Dataset, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::GET, format!("/catalog/datasets/{id}", id = crate::urlencode(id)))
+        .execute().await
+}
+
+/// Starts a new contract negotiation with the provider
+pub async fn request_contract(configuration: &configuration::Configuration, contract_request_message: &edc_api::dsp::ContractRequestMessage) -> Result<edc_api::dsp::ContractNegotiation, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, "/negotiations/request")
+        .json(contract_request_message)
+        .execute().await
+}
+
+/// Sends a counter request within the negotiation with the given provider process id
+pub async fn counter_request(configuration: &configuration::Configuration, provider_pid: &str, contract_request_message: &edc_api::dsp::ContractRequestMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/negotiations/{providerPid}/request", providerPid = crate::urlencode(provider_pid)))
+        .json(contract_request_message)
+        .execute_empty().await
+}
+
+/// Gets the negotiation with the given provider process id
+pub async fn get_negotiation(configuration: &configuration::Configuration, provider_pid: &str) -> Result<edc_api::dsp::ContractNegotiation, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::GET, format!("/negotiations/{providerPid}", providerPid = crate::urlencode(provider_pid)))
+        .execute().await
+}
+
+/// Sends a negotiation event, e.g. to accept the last offer of the provider
+pub async fn send_negotiation_event(configuration: &configuration::Configuration, contract_negotiation_event_message: &edc_api::dsp::ContractNegotiationEventMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/negotiations/{providerPid}/events", providerPid = crate::urlencode(&contract_negotiation_event_message.provider_pid)))
+        .json(contract_negotiation_event_message)
+        .execute_empty().await
+}
+
+/// Verifies the agreement the provider sent for the negotiation
+pub async fn verify_agreement(configuration: &configuration::Configuration, contract_agreement_verification_message: &edc_api::dsp::ContractAgreementVerificationMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/negotiations/{providerPid}/agreement/verification", providerPid = crate::urlencode(&contract_agreement_verification_message.provider_pid)))
+        .json(contract_agreement_verification_message)
+        .execute_empty().await
+}
+
+/// Terminates the negotiation
+pub async fn terminate_negotiation(configuration: &configuration::Configuration, contract_negotiation_termination_message: &edc_api::dsp::ContractNegotiationTerminationMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/negotiations/{providerPid}/termination", providerPid = crate::urlencode(&contract_negotiation_termination_message.provider_pid)))
+        .json(contract_negotiation_termination_message)
+        .execute_empty().await
+}
+
+/// Requests a transfer of the data covered by an agreement
+pub async fn request_transfer(configuration: &configuration::Configuration, transfer_request_message: &edc_api::dsp::TransferRequestMessage) -> Result<edc_api::dsp::TransferProcess, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, "/transfers/request")
+        .json(transfer_request_message)
+        .execute().await
+}
+
+/// Gets the transfer process with the given provider process id
+pub async fn get_transfer_process(configuration: &configuration::Configuration, provider_pid: &str) -> Result<edc_api::dsp::TransferProcess, Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::GET, format!("/transfers/{providerPid}", providerPid = crate::urlencode(provider_pid)))
+        .execute().await
+}
+
+/// Resumes a suspended transfer process
+pub async fn start_transfer(configuration: &configuration::Configuration, transfer_start_message: &edc_api::dsp::TransferStartMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/transfers/{providerPid}/start", providerPid = crate::urlencode(&transfer_start_message.provider_pid)))
+        .json(transfer_start_message)
+        .execute_empty().await
+}
+
+/// Completes the transfer process
+pub async fn complete_transfer(configuration: &configuration::Configuration, transfer_completion_message: &edc_api::dsp::TransferCompletionMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/transfers/{providerPid}/completion", providerPid = crate::urlencode(&transfer_completion_message.provider_pid)))
+        .json(transfer_completion_message)
+        .execute_empty().await
+}
+
+/// Suspends the transfer process
+pub async fn suspend_transfer(configuration: &configuration::Configuration, transfer_suspension_message: &edc_api::dsp::TransferSuspensionMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/transfers/{providerPid}/suspension", providerPid = crate::urlencode(&transfer_suspension_message.provider_pid)))
+        .json(transfer_suspension_message)
+        .execute_empty().await
+}
+
+/// Terminates the transfer process
+pub async fn terminate_transfer(configuration: &configuration::Configuration, transfer_termination_message: &edc_api::dsp::TransferTerminationMessage) -> Result<(), Error<DspError>> {
+    Request::protocol(configuration, reqwest::Method::POST, format!("/transfers/{providerPid}/termination", providerPid = crate::urlencode(&transfer_termination_message.provider_pid)))
+        .json(transfer_termination_message)
+        .execute_empty().await
+}
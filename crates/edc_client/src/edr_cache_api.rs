@@ -10,121 +10,34 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
+use futures::Stream;
 
-
-/// struct for typed errors of method [`query_edrs`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum QueryEDRsError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`delete_edr`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum DeleteEDRError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_edr_data_address`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetEDRDataAddressError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
+use crate::pagination::paginate;
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Request all Edr entries according to a particular query
-pub async fn query_edrs(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::EndpointDataReferenceEntry>, Error<QueryEDRsError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/edrs/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&query_spec);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+pub async fn query_edrs(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::EndpointDataReferenceEntry>, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v1/edrs/request")
+        .idempotent()
+        .json(&query_spec)
+        .execute().await
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<QueryEDRsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// Streams all Edr entries according to a query, requesting `page_size` of them at a time. `limit` and `offset` of the query are ignored.
+pub fn query_edrs_stream(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::EndpointDataReferenceEntry, Error<EdcError>>> + '_ {
+    paginate(query_spec, page_size, move |query_spec| query_edrs(configuration, Some(query_spec)))
 }
 
 /// Removes an EDR entry given the transfer process ID
-pub async fn delete_edr(configuration: &configuration::Configuration, transfer_process_id: &str) -> Result<(), Error<DeleteEDRError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/edrs/{transferProcessId}", local_var_configuration.base_path, transferProcessId= crate::urlencode(transfer_process_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeleteEDRError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn delete_edr(configuration: &configuration::Configuration, transfer_process_id: &str) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::DELETE, format!("/v1/edrs/{transferProcessId}", transferProcessId = crate::urlencode(transfer_process_id)))
+        .execute_empty().await
 }
 
 /// Gets the EDR data address with the given transfer process ID
-pub async fn get_edr_data_address(configuration: &configuration::Configuration, transfer_process_id: &str) -> Result<edc_api::DataAddress, Error<GetEDRDataAddressError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v1/edrs/{transferProcessId}/dataaddress", local_var_configuration.base_path, transferProcessId= crate::urlencode(transfer_process_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetEDRDataAddressError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_edr_data_address(configuration: &configuration::Configuration, transfer_process_id: &str) -> Result<edc_api::DataAddress, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, format!("/v1/edrs/{transferProcessId}/dataaddress", transferProcessId = crate::urlencode(transfer_process_id)))
+        .execute().await
 }
\ No newline at end of file
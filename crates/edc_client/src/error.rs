@@ -0,0 +1,144 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use std::fmt;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+use crate::ApiErrorDetail;
+
+
+/// Headers that connectors and gateways in front of them use to correlate a request with their logs
+const REQUEST_ID_HEADERS: [&str; 3] = ["x-request-id", "x-correlation-id", "x-amzn-requestid"];
+
+/// Error response of the management api. All api functions share this type, the status tells what went wrong and the
+/// details carry the messages of the connector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdcError {
+    pub status: StatusCode,
+    /// Details of the response body, empty if the connector sent none or something else than a list of details
+    pub details: Vec<ApiErrorDetail>,
+    /// Id the connector or a gateway in front of it assigned to the request, if it sent one back
+    pub request_id: Option<String>,
+    /// The retry policy considers the status transient, so sending the request again later may succeed
+    pub retryable: bool,
+}
+
+impl EdcError {
+
+    pub fn new(status: StatusCode, details: Vec<ApiErrorDetail>) -> EdcError {
+        EdcError {
+            status,
+            details,
+            request_id: None,
+            retryable: false,
+        }
+    }
+
+    pub fn is_bad_request(&self) -> bool {
+        self.status == StatusCode::BAD_REQUEST
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.status == StatusCode::UNAUTHORIZED || self.status == StatusCode::FORBIDDEN
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status == StatusCode::CONFLICT
+    }
+
+    pub fn is_server_error(&self) -> bool {
+        self.status.is_server_error()
+    }
+
+    /// Messages of all details, in the order the connector sent them
+    pub fn messages(&self) -> impl Iterator<Item = &str> {
+        self.details.iter().filter_map(|detail| detail.message.as_deref())
+    }
+
+}
+
+impl fmt::Display for EdcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status code {}", self.status)?;
+        let messages = self.messages().collect::<Vec<_>>();
+        if !messages.is_empty() {
+            write!(f, ": {}", messages.join("; "))?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for EdcError {}
+
+/// Typed content of error responses, decoded by the request pipeline before it hands out [`crate::ResponseContent`]
+pub trait ErrorEntity: Sized {
+    fn from_response(status: StatusCode, headers: &HeaderMap, content: &str, retryable: bool) -> Option<Self>;
+}
+
+impl ErrorEntity for EdcError {
+    fn from_response(status: StatusCode, headers: &HeaderMap, content: &str, retryable: bool) -> Option<Self> {
+        // The EDC answers with a list of details, some gateways and older versions with a single one
+        let details = match serde_json::from_str::<serde_json::Value>(content) {
+            Ok(serde_json::Value::Array(details)) => details.into_iter().filter_map(|detail| serde_json::from_value(detail).ok()).collect(),
+            Ok(detail @ serde_json::Value::Object(_)) => serde_json::from_value(detail).into_iter().collect(),
+            _ => Vec::new(),
+        };
+        let request_id = REQUEST_ID_HEADERS.iter()
+            .find_map(|name| headers.get(*name))
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Some(EdcError { status, details, request_id, retryable })
+    }
+}
+
+impl <T> crate::Error<T> {
+    /// Status of the error response, `None` if the request failed without one
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            crate::Error::ResponseError(e) => Some(e.status),
+            _ => None,
+        }
+    }
+}
+
+impl crate::Error<EdcError> {
+    /// Decoded error response of the management api, `None` if the request failed without one
+    pub fn edc_error(&self) -> Option<&EdcError> {
+        match self {
+            crate::Error::ResponseError(e) => e.entity.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.edc_error().is_some_and(EdcError::is_not_found)
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.edc_error().is_some_and(EdcError::is_conflict)
+    }
+
+    /// Sending the request again later may succeed: the connector was unreachable or answered with a transient status
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            crate::Error::Transport { .. } => true,
+            _ => self.edc_error().is_some_and(|e| e.retryable),
+        }
+    }
+}
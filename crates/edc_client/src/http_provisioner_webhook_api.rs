@@ -10,80 +10,19 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use super::{Error, configuration};
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
-/// struct for typed errors of method [`call_deprovision_webhook`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CallDeprovisionWebhookError {
-    DefaultResponse(),
-    UnknownValue(serde_json::Value),
+pub async fn call_deprovision_webhook(configuration: &configuration::Configuration, process_id: &str, deprovisioned_resource: Option<edc_api::DeprovisionedResource>) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, format!("/callback/{processId}/deprovision", processId = crate::urlencode(process_id)))
+        .json(&deprovisioned_resource)
+        .execute_empty().await
 }
 
-/// struct for typed errors of method [`call_provision_webhook`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CallProvisionWebhookError {
-    DefaultResponse(),
-    UnknownValue(serde_json::Value),
-}
-
-
-pub async fn call_deprovision_webhook(configuration: &configuration::Configuration, process_id: &str, deprovisioned_resource: Option<edc_api::DeprovisionedResource>) -> Result<(), Error<CallDeprovisionWebhookError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/callback/{processId}/deprovision", local_var_configuration.base_path, processId= crate::urlencode(process_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&deprovisioned_resource);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<CallDeprovisionWebhookError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
-}
-
-pub async fn call_provision_webhook(configuration: &configuration::Configuration, process_id: &str, provisioner_webhook_request: Option<edc_api::ProvisionerWebhookRequest>) -> Result<(), Error<CallProvisionWebhookError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/callback/{processId}/provision", local_var_configuration.base_path, processId= crate::urlencode(process_id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&provisioner_webhook_request);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<CallProvisionWebhookError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn call_provision_webhook(configuration: &configuration::Configuration, process_id: &str, provisioner_webhook_request: Option<edc_api::ProvisionerWebhookRequest>) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, format!("/callback/{processId}/provision", processId = crate::urlencode(process_id)))
+        .json(&provisioner_webhook_request)
+        .execute_empty().await
 }
 
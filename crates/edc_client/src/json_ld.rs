@@ -0,0 +1,313 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! JSON-LD handling of responses. The connector is free to choose its context, so the same property may arrive as
+//! `assetId`, `edc:assetId` or `https://w3id.org/edc/v0.0.1/ns/assetId`. Every response is therefore [`expand`]ed
+//! against its own `@context` and [`compact`]ed into the fixed [`client_context`] before it is deserialized:
+//!
+//! * EDC terms are the vocabulary and lose their namespace, e.g. `assetId`
+//! * terms of the `odrl`, `dcat`, `dct` and `dspace` namespaces keep their prefix, e.g. `dcat:dataset`
+//! * within policies (`policy` and `hasPolicy`) ODRL is the vocabulary instead, e.g. `permission` and `edc:inForceDate`
+//! * all other IRIs are kept as they are
+//!
+//! Only the keys and the `@type` of nodes are rewritten, the structure of the document stays the same.
+
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+
+pub const EDC_NS: &str = "https://w3id.org/edc/v0.0.1/ns/";
+pub const ODRL_NS: &str = "http://www.w3.org/ns/odrl/2/";
+pub const DCAT_NS: &str = "http://www.w3.org/ns/dcat#";
+pub const DCT_NS: &str = "http://purl.org/dc/terms/";
+pub const DSPACE_NS: &str = "https://w3id.org/dspace/v0.8/";
+
+/// Remote context of the ODRL vocabulary, it is the only remote context that changes how keys expand
+pub const ODRL_CONTEXT: &str = "http://www.w3.org/ns/odrl.jsonld";
+
+/// Terms of the ODRL context that policies use without prefix
+const ODRL_TERMS: [&str; 32] = [
+    "Agreement", "Offer", "Policy", "Set", "action", "and", "andSequence", "assignee", "assigner", "conflict",
+    "consequence", "constraint", "dataType", "duty", "failure", "function", "hasPolicy", "inheritFrom", "leftOperand",
+    "obligation", "operator", "or", "partOf", "permission", "profile", "prohibition", "refinement", "relation",
+    "remedy", "rightOperand", "target", "xone",
+];
+
+/// Context that every response is compacted into
+pub fn client_context() -> Value {
+    let policy_scope = serde_json::json!({ "@vocab": ODRL_NS });
+    serde_json::json!({
+        "@vocab": EDC_NS,
+        "edc": EDC_NS,
+        "odrl": ODRL_NS,
+        "dcat": DCAT_NS,
+        "dct": DCT_NS,
+        "dspace": DSPACE_NS,
+        "policy": { "@id": "edc:policy", "@context": policy_scope },
+        "hasPolicy": { "@id": "odrl:hasPolicy", "@context": policy_scope },
+    })
+}
+
+/// Expands all keys and types of the document to IRIs and removes its contexts
+pub fn expand(document: &Value) -> Value {
+    expand_value(document, &Context::client())
+}
+
+/// Compacts an [`expand`]ed document into the [`client_context`], which is set as `@context` of the top level nodes
+pub fn compact(document: &Value) -> Value {
+    let context = Context::client();
+    let with_context = |node: Value| match node {
+        Value::Object(mut node) => {
+            node.insert("@context".to_string(), client_context());
+            Value::Object(node)
+        },
+        other => other,
+    };
+    match compact_value(document, &context) {
+        Value::Array(nodes) => Value::Array(nodes.into_iter().map(with_context).collect()),
+        node => with_context(node),
+    }
+}
+
+/// Brings a document of any context into the form of the [`client_context`]
+pub fn normalize(document: &Value) -> Value {
+    compact(&expand(document))
+}
+
+
+#[derive(Debug, Clone)]
+struct Term {
+    iri: String,
+    /// Property scoped context that applies to the values of the term
+    context: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Context {
+    vocab: Option<String>,
+    terms: HashMap<String, Term>,
+}
+
+impl Context {
+
+    fn client() -> Context {
+        Context::default().with(&client_context())
+    }
+
+    /// Context after processing the local context `local`
+    fn with(&self, local: &Value) -> Context {
+        match local {
+            Value::Null => Context::client(),
+            Value::Array(contexts) => contexts.iter().fold(self.clone(), |context, local| context.with(local)),
+            Value::String(url) if url == ODRL_CONTEXT => {
+                let mut context = self.clone();
+                context.define("odrl", ODRL_NS.to_string(), None);
+                context.define("uid", "@id".to_string(), None);
+                for term in ODRL_TERMS {
+                    context.define(term, format!("{}{}", ODRL_NS, term), None);
+                }
+                context
+            },
+            Value::String(url) => {
+                // Remote contexts are not fetched, the prefixes of the client context are known anyway
+                tracing::debug!(context = %url, "Ignoring remote JSON-LD context");
+                self.clone()
+            },
+            Value::Object(definitions) => {
+                let mut context = self.clone();
+                // Prefixes first, so that the other definitions can use them no matter their order
+                for (term, definition) in definitions {
+                    if let Value::String(iri) = definition {
+                        if !term.starts_with('@') && is_absolute(iri) {
+                            context.define(term, iri.clone(), None);
+                        }
+                    }
+                }
+                if let Some(vocab) = definitions.get("@vocab") {
+                    context.vocab = vocab.as_str().map(|vocab| context.expand_iri(vocab, false));
+                }
+                for (term, definition) in definitions {
+                    if term.starts_with('@') {
+                        continue;
+                    }
+                    match definition {
+                        Value::Null => {
+                            context.terms.remove(term);
+                        },
+                        Value::String(iri) => {
+                            let iri = context.expand_iri(iri, true);
+                            context.define(term, iri, None);
+                        },
+                        Value::Object(definition) => {
+                            let iri = match definition.get("@id").and_then(Value::as_str) {
+                                Some(iri) => context.expand_iri(iri, true),
+                                None => context.expand_iri(term, true),
+                            };
+                            context.define(term, iri, definition.get("@context").cloned());
+                        },
+                        _ => {},
+                    }
+                }
+                context
+            },
+            _ => self.clone(),
+        }
+    }
+
+    fn define(&mut self, term: &str, iri: String, context: Option<Value>) {
+        self.terms.insert(term.to_string(), Term { iri, context });
+    }
+
+    /// Expands a key or a type, `vocab` tells whether terms without prefix are relative to the vocabulary
+    fn expand_iri(&self, value: &str, vocab: bool) -> String {
+        if value.starts_with('@') {
+            return value.to_string();
+        }
+        if let Some(term) = self.terms.get(value) {
+            return term.iri.clone();
+        }
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if suffix.starts_with("//") {
+                return value.to_string();
+            }
+            return match self.terms.get(prefix) {
+                Some(term) => format!("{}{}", term.iri, suffix),
+                None => value.to_string(),
+            };
+        }
+        match &self.vocab {
+            Some(namespace) if vocab => format!("{}{}", namespace, value),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Shortest form of the IRI: a term, relative to the vocabulary or with a prefix
+    fn compact_iri(&self, iri: &str) -> String {
+        if iri.starts_with('@') {
+            return iri.to_string();
+        }
+        if let Some((term, _)) = self.terms.iter().filter(|(_, term)| term.iri == iri).min_by_key(|(term, _)| (term.len(), term.to_string())) {
+            return term.clone();
+        }
+        if let Some(term) = self.vocab.as_deref().and_then(|vocab| iri.strip_prefix(vocab)) {
+            // A term of the context would expand to a different IRI
+            if !term.is_empty() && !term.contains(':') && !self.terms.contains_key(term) {
+                return term.to_string();
+            }
+        }
+        self.terms.iter()
+            .filter(|(_, term)| term.iri.ends_with(['/', '#']))
+            .filter_map(|(prefix, term)| iri.strip_prefix(term.iri.as_str()).filter(|suffix| !suffix.is_empty()).map(|suffix| format!("{}:{}", prefix, suffix)))
+            .min_by_key(|compacted| (compacted.len(), compacted.clone()))
+            .unwrap_or_else(|| iri.to_string())
+    }
+
+}
+
+fn expand_value(value: &Value, context: &Context) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.iter().map(|value| expand_value(value, context)).collect()),
+        Value::Object(node) => {
+            let context = match node.get("@context") {
+                Some(local) => context.with(local),
+                None => context.clone(),
+            };
+            let mut expanded = Map::new();
+            for (key, value) in node {
+                if key == "@context" {
+                    continue;
+                }
+                let iri = context.expand_iri(key, true);
+                let value = match iri.as_str() {
+                    "@type" => map_strings(value, |r#type| context.expand_iri(r#type, true)),
+                    "@id" | "@value" | "@language" | "@list" | "@json" => value.clone(),
+                    _ => match context.terms.get(key).and_then(|term| term.context.as_ref()) {
+                        Some(scoped) => expand_value(value, &context.with(scoped)),
+                        None => expand_value(value, &context),
+                    },
+                };
+                merge(&mut expanded, iri, value);
+            }
+            Value::Object(expanded)
+        },
+        _ => value.clone(),
+    }
+}
+
+fn compact_value(value: &Value, context: &Context) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.iter().map(|value| compact_value(value, context)).collect()),
+        Value::Object(node) => {
+            let mut compacted = Map::new();
+            for (iri, value) in node {
+                let key = context.compact_iri(iri);
+                let value = match iri.as_str() {
+                    "@type" => map_strings(value, |r#type| context.compact_iri(r#type)),
+                    "@id" | "@value" | "@language" | "@list" | "@json" => value.clone(),
+                    _ => match context.terms.get(&key).and_then(|term| term.context.as_ref()) {
+                        Some(scoped) => with_scope(compact_value(value, &context.with(scoped)), scoped),
+                        None => compact_value(value, context),
+                    },
+                };
+                merge(&mut compacted, key, value);
+            }
+            Value::Object(compacted)
+        },
+        _ => value.clone(),
+    }
+}
+
+/// Keeps the scoped context on the nodes it applies to, so they can be sent on their own
+fn with_scope(value: Value, scoped: &Value) -> Value {
+    match value {
+        Value::Object(mut node) if !node.contains_key("@value") => {
+            node.insert("@context".to_string(), scoped.clone());
+            Value::Object(node)
+        },
+        Value::Array(values) => Value::Array(values.into_iter().map(|value| with_scope(value, scoped)).collect()),
+        other => other,
+    }
+}
+
+fn map_strings(value: &Value, f: impl Fn(&str) -> String) -> Value {
+    match value {
+        Value::String(value) => Value::String(f(value)),
+        Value::Array(values) => Value::Array(values.iter().map(|value| match value {
+            Value::String(value) => Value::String(f(value)),
+            other => other.clone(),
+        }).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Keys that end up the same, e.g. `permission` and `odrl:permission`, have their values combined
+fn merge(node: &mut Map<String, Value>, key: String, value: Value) {
+    match node.remove(&key) {
+        None => {
+            node.insert(key, value);
+        },
+        Some(existing) => {
+            let mut values = match existing {
+                Value::Array(values) => values,
+                existing => vec![existing],
+            };
+            match value {
+                Value::Array(more) => values.extend(more),
+                value => values.push(value),
+            }
+            node.insert(key, Value::Array(values));
+        },
+    }
+}
+
+fn is_absolute(iri: &str) -> bool {
+    iri.split_once(':').is_some_and(|(scheme, _)| !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c)))
+}
@@ -4,18 +4,32 @@ extern crate edc_api as api_models;
 
 pub mod application_observability_api;
 pub mod asset_api;
+pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod catalog_api;
+pub mod client;
 pub mod configuration;
 pub mod contract_agreement_api;
 pub mod contract_definition_api;
 pub mod contract_negotiation_api;
 pub mod dataplane_selector_api;
+pub mod dsp_client;
 pub mod edr_cache_api;
+pub mod error;
 pub mod http_provisioner_webhook_api;
+pub mod json_ld;
+pub mod pagination;
 pub mod policy_definition_api;
+pub mod reconcile;
 pub mod secret_api;
 pub mod transfer_process_api;
-mod utils;
+pub mod watch;
+pub mod workflow;
+mod request;
+
+pub use client::EdcClient;
+pub use error::EdcError;
 
 pub use api_models::ApiErrorDetail;
 pub use api_models::AssetInput;
@@ -53,7 +67,6 @@ pub use api_models::TransferProcess;
 pub use api_models::TransferRequest;
 pub use api_models::TransferState;
 
-use std::error;
 use std::fmt;
 
 #[derive(Debug, Clone)]
@@ -61,11 +74,18 @@ pub struct ResponseContent<T> {
     pub status: reqwest::StatusCode,
     pub content: String,
     pub entity: Option<T>,
+    /// Number of attempts that were made, including the one that produced this response
+    pub attempts: u32,
 }
 
 #[derive(Debug)]
 pub enum Error<T> {
+    /// The request could not be built or the response could not be read
     Reqwest(reqwest::Error),
+    /// The connector could not be reached, `attempts` counts all failed attempts
+    Transport { source: reqwest::Error, attempts: u32 },
+    /// No access token could be obtained from the token provider of the configuration
+    Token(auth::TokenError),
     Serde(serde_json::Error),
     Io(std::io::Error),
     ResponseError(ResponseContent<T>),
@@ -75,6 +95,8 @@ impl <T> fmt::Display for Error<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (module, e) = match self {
             Error::Reqwest(e) => ("reqwest", e.to_string()),
+            Error::Transport { source, attempts } => ("transport", format!("{} after {} attempt(s)", source, attempts)),
+            Error::Token(e) => ("token provider", e.to_string()),
             Error::Serde(e) => ("serde", e.to_string()),
             Error::Io(e) => ("IO", e.to_string()),
             Error::ResponseError(e) => ("response", format!("status code {}", e.status)),
@@ -83,10 +105,12 @@ impl <T> fmt::Display for Error<T> {
     }
 }
 
-impl <T: fmt::Debug> error::Error for Error<T> {
-    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+impl <T: fmt::Debug> std::error::Error for Error<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         Some(match self {
             Error::Reqwest(e) => e,
+            Error::Transport { source, .. } => source,
+            Error::Token(e) => e,
             Error::Serde(e) => e,
             Error::Io(e) => e,
             Error::ResponseError(_) => return None,
@@ -94,12 +118,29 @@ impl <T: fmt::Debug> error::Error for Error<T> {
     }
 }
 
+impl <T> Error<T> {
+    /// Number of attempts made before the request failed, `None` if it failed before or after sending
+    pub fn attempts(&self) -> Option<u32> {
+        match self {
+            Error::Transport { attempts, .. } => Some(*attempts),
+            Error::ResponseError(e) => Some(e.attempts),
+            _ => None,
+        }
+    }
+}
+
 impl <T> From<reqwest::Error> for Error<T> {
     fn from(e: reqwest::Error) -> Self {
         Error::Reqwest(e)
     }
 }
 
+impl <T> From<auth::TokenError> for Error<T> {
+    fn from(e: auth::TokenError) -> Self {
+        Error::Token(e)
+    }
+}
+
 impl <T> From<serde_json::Error> for Error<T> {
     fn from(e: serde_json::Error) -> Self {
         Error::Serde(e)
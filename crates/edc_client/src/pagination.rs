@@ -0,0 +1,66 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Paging through the query endpoints. The `*_stream` functions of the api modules return a `futures::Stream` that fetches
+//! one page per request and ends after the first page that is not full.
+
+
+use std::future::Future;
+
+use futures::{stream, Stream, TryStreamExt};
+
+use super::Error;
+
+
+/// Page size that keeps the number of requests low without making single responses too large
+pub const DEFAULT_PAGE_SIZE: i32 = 50;
+
+/// Query spec of the first page. Filter and sort order are taken from the given query spec, `limit` and `offset` are replaced.
+pub(crate) fn first_page(query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> edc_api::QuerySpec {
+    let query_spec = query_spec.unwrap_or_else(|| edc_api::QuerySpec {
+        sort_field: None,
+        sort_order: None,
+        ..edc_api::QuerySpec::default()
+    });
+    edc_api::QuerySpec {
+        limit: Some(page_size),
+        offset: Some(0),
+        ..query_spec
+    }
+}
+
+/// Calls `fetch` with consecutive pages of the query spec and streams the items of all pages
+pub(crate) fn paginate<'a, T, E, F, Fut>(query_spec: Option<edc_api::QuerySpec>, page_size: i32, fetch: F) -> impl Stream<Item = Result<T, Error<E>>> + 'a
+where
+    T: 'a,
+    E: 'a,
+    F: FnMut(edc_api::QuerySpec) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error<E>>> + 'a,
+{
+    let page_size = page_size.max(1);
+    let query_spec = first_page(query_spec, page_size);
+
+    stream::try_unfold((fetch, Some(query_spec)), move |(mut fetch, next)| async move {
+        let Some(query_spec) = next else {
+            return Ok::<_, Error<E>>(None);
+        };
+
+        let offset = query_spec.offset.unwrap_or_default();
+        let page = fetch(query_spec.clone()).await?;
+        tracing::debug!(offset, items = page.len(), "Fetched page");
+
+        let next = if page.len() < page_size as usize {
+            None
+        } else {
+            Some(edc_api::QuerySpec { offset: Some(offset + page_size), ..query_spec })
+        };
+        Ok(Some((stream::iter(page.into_iter().map(Ok)), (fetch, next))))
+    })
+    .try_flatten()
+}
@@ -10,203 +10,49 @@
 
 use reqwest;
 
-use crate::ResponseContent;
-use crate::utils::remove_prefixes_from_value;
-use super::{Error, configuration};
+use futures::Stream;
 
-
-/// struct for typed errors of method [`create_policy_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CreatePolicyDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status409(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`delete_policy_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum DeletePolicyDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    Status409(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`get_policy_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum GetPolicyDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`query_policy_definitions`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum QueryPolicyDefinitionsError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    UnknownValue(serde_json::Value),
-}
-
-/// struct for typed errors of method [`update_policy_definition`]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum UpdatePolicyDefinitionError {
-    Status400(Vec<edc_api::ApiErrorDetail>),
-    Status404(edc_api::ApiErrorDetail),
-    UnknownValue(serde_json::Value),
-}
+use crate::pagination::paginate;
+use crate::request::Request;
+use super::{EdcError, Error, configuration};
 
 
 /// Creates a new policy definition
-pub async fn create_policy_definition(configuration: &configuration::Configuration, policy_definition_input: Option<edc_api::PolicyDefinitionInput>) -> Result<edc_api::IdResponse, Error<CreatePolicyDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/policydefinitions", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&policy_definition_input);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<CreatePolicyDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn create_policy_definition(configuration: &configuration::Configuration, policy_definition_input: Option<edc_api::PolicyDefinitionInput>) -> Result<edc_api::IdResponse, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/policydefinitions")
+        .json(&policy_definition_input)
+        .execute().await
 }
 
 /// Removes a policy definition with the given ID if possible. Deleting a policy definition is only possible if that policy definition is not yet referenced by a contract definition, in which case an error is returned. DANGER ZONE: Note that deleting policy definitions can have unexpected results, do this at your own risk!
-pub async fn delete_policy_definition(configuration: &configuration::Configuration, id: &str) -> Result<(), Error<DeletePolicyDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/policydefinitions/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::DELETE, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<DeletePolicyDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn delete_policy_definition(configuration: &configuration::Configuration, id: &str) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::DELETE, format!("/v2/policydefinitions/{id}", id = crate::urlencode(id)))
+        .execute_empty().await
 }
 
 /// Gets a policy definition with the given ID
-pub async fn get_policy_definition(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::PolicyDefinitionOutput, Error<GetPolicyDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/policydefinitions/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::GET, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<GetPolicyDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn get_policy_definition(configuration: &configuration::Configuration, id: &str) -> Result<edc_api::PolicyDefinitionOutput, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::GET, format!("/v2/policydefinitions/{id}", id = crate::urlencode(id)))
+        .execute().await
 }
 
 /// Returns all policy definitions according to a query
-pub async fn query_policy_definitions(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::PolicyDefinitionOutput>, Error<QueryPolicyDefinitionsError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/policydefinitions/request", local_var_configuration.base_path);
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::POST, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&query_spec);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
+pub async fn query_policy_definitions(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>) -> Result<Vec<edc_api::PolicyDefinitionOutput>, Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::POST, "/v2/policydefinitions/request")
+        .idempotent()
+        .json(&query_spec)
+        .execute().await
+}
 
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        let mut val = serde_json::from_str(&local_var_content).map_err(Error::from)?;
-        val = remove_prefixes_from_value(val);
-        serde_json::from_value(val).map_err(Error::from)
-    } else {
-        let local_var_entity: Option<QueryPolicyDefinitionsError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+/// Streams all policy definitions according to a query, requesting `page_size` of them at a time. `limit` and `offset` of the query are ignored.
+pub fn query_policy_definitions_stream(configuration: &configuration::Configuration, query_spec: Option<edc_api::QuerySpec>, page_size: i32) -> impl Stream<Item = Result<edc_api::PolicyDefinitionOutput, Error<EdcError>>> + '_ {
+    paginate(query_spec, page_size, move |query_spec| query_policy_definitions(configuration, Some(query_spec)))
 }
 
 /// Updates an existing Policy, If the Policy is not found, an error is reported
-pub async fn update_policy_definition(configuration: &configuration::Configuration, id: &str, policy_definition_input: Option<edc_api::PolicyDefinitionInput>) -> Result<(), Error<UpdatePolicyDefinitionError>> {
-    let local_var_configuration = configuration;
-
-    let local_var_client = &local_var_configuration.client;
-
-    let local_var_uri_str = format!("{}/v2/policydefinitions/{id}", local_var_configuration.base_path, id= crate::urlencode(id));
-    let mut local_var_req_builder = local_var_client.request(reqwest::Method::PUT, local_var_uri_str.as_str());
-
-    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
-        local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
-    }
-    local_var_req_builder = local_var_req_builder.json(&policy_definition_input);
-
-    let local_var_req = local_var_req_builder.build()?;
-    let local_var_resp = local_var_client.execute(local_var_req).await?;
-
-    let local_var_status = local_var_resp.status();
-    let local_var_content = local_var_resp.text().await?;
-
-    if !local_var_status.is_client_error() && !local_var_status.is_server_error() {
-        Ok(())
-    } else {
-        let local_var_entity: Option<UpdatePolicyDefinitionError> = serde_json::from_str(&local_var_content).ok();
-        let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity };
-        Err(Error::ResponseError(local_var_error))
-    }
+pub async fn update_policy_definition(configuration: &configuration::Configuration, id: &str, policy_definition_input: Option<edc_api::PolicyDefinitionInput>) -> Result<(), Error<EdcError>> {
+    Request::new(configuration, reqwest::Method::PUT, format!("/v2/policydefinitions/{id}", id = crate::urlencode(id)))
+        .json(&policy_definition_input)
+        .execute_empty().await
 }
 
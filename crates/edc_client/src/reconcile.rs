@@ -0,0 +1,464 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Declarative provisioning of assets, policy definitions and contract definitions. A [`Manifest`] lists the
+//! definitions a connector should have; [`Reconcile`] queries the definitions the connector has, plans what to create,
+//! update or delete, and applies the plan:
+//!
+//! ```no_run
+//! # async fn run(client: edc_client::EdcClient) -> Result<(), Box<dyn std::error::Error>> {
+//! use edc_client::reconcile::Manifest;
+//!
+//! let manifest = Manifest::from_json(&std::fs::read_to_string("manifest.json")?)?;
+//! let plan = client.reconcile(manifest).plan().await?;
+//! for step in plan.changes() {
+//!     println!("{}", step);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Definitions are matched by their `@id`. They are compared after normalizing their JSON-LD, so a manifest with
+//! `edc:` prefixes matches a connector that answers without them.
+
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use futures::{Stream, TryStreamExt};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::configuration::Configuration;
+use super::pagination::DEFAULT_PAGE_SIZE;
+use super::{asset_api, contract_definition_api, json_ld, policy_definition_api, EdcError, Error};
+
+
+/// Definitions a connector should have
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawManifest")]
+pub struct Manifest {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<edc_api::AssetInput>,
+    #[serde(rename = "policyDefinitions", skip_serializing_if = "Vec::is_empty")]
+    pub policy_definitions: Vec<edc_api::PolicyDefinitionInput>,
+    #[serde(rename = "contractDefinitions", skip_serializing_if = "Vec::is_empty")]
+    pub contract_definitions: Vec<edc_api::ContractDefinitionInput>,
+}
+
+/// Manifest as it is written, the definitions may leave out their `@context`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawManifest {
+    #[serde(default)]
+    assets: Vec<Value>,
+    #[serde(default, rename = "policyDefinitions")]
+    policy_definitions: Vec<Value>,
+    #[serde(default, rename = "contractDefinitions")]
+    contract_definitions: Vec<Value>,
+}
+
+impl TryFrom<RawManifest> for Manifest {
+    type Error = String;
+
+    fn try_from(raw: RawManifest) -> Result<Self, Self::Error> {
+        Ok(Manifest {
+            assets: definitions(raw.assets, Kind::Asset)?,
+            policy_definitions: definitions(raw.policy_definitions, Kind::PolicyDefinition)?,
+            contract_definitions: definitions(raw.contract_definitions, Kind::ContractDefinition)?,
+        })
+    }
+}
+
+/// Deserializes the definitions of one kind in the form of the client context, so they may use prefixes of their own.
+/// Definitions without `@context` use the EDC namespace as vocabulary.
+fn definitions<T: serde::de::DeserializeOwned>(values: Vec<Value>, kind: Kind) -> Result<Vec<T>, String> {
+    values.into_iter().enumerate().map(|(i, mut value)| {
+        if let Value::Object(definition) = &mut value {
+            definition.entry("@context").or_insert_with(|| serde_json::json!({ "@vocab": json_ld::EDC_NS }));
+        }
+        serde_json::from_value(json_ld::normalize(&value)).map_err(|e| format!("invalid {} {} of the manifest: {}", kind, i + 1, e))
+    }).collect()
+}
+
+impl Manifest {
+
+    pub fn from_json(content: &str) -> Result<Manifest, serde_json::Error> {
+        serde_json::from_str(content)
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(content: &str) -> Result<Manifest, serde_yaml::Error> {
+        serde_yaml::from_str(content)
+    }
+
+}
+
+/// Kinds of definitions, in the order they are created in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Kind {
+    PolicyDefinition,
+    Asset,
+    ContractDefinition,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::PolicyDefinition => write!(f, "policy definition"),
+            Kind::Asset => write!(f, "asset"),
+            Kind::ContractDefinition => write!(f, "contract definition"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+    /// The definition of the connector already matches the manifest
+    Unchanged,
+}
+
+/// What happens to one definition
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    pub kind: Kind,
+    #[serde(rename = "@id")]
+    pub id: String,
+    pub action: Action,
+    /// Properties that differ between the manifest and the connector, only set for updates
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = match self.action {
+            Action::Create => '+',
+            Action::Update => '~',
+            Action::Delete => '-',
+            Action::Unchanged => ' ',
+        };
+        write!(f, "{} {} {}", sign, self.kind, self.id)?;
+        if !self.changes.is_empty() {
+            write!(f, " ({})", self.changes.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Steps that bring the connector in line with the manifest, in the order they are applied: contract definitions are
+/// deleted first, then policy definitions, assets and contract definitions are created or updated, and finally assets
+/// and policy definitions are deleted. That way no contract definition refers to a missing policy or asset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Plan {
+    pub steps: Vec<Step>,
+}
+
+impl Plan {
+
+    /// Steps that change the connector
+    pub fn changes(&self) -> impl Iterator<Item = &Step> {
+        self.steps.iter().filter(|step| step.action != Action::Unchanged)
+    }
+
+    /// Whether the connector already matches the manifest
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+
+}
+
+#[derive(Debug)]
+pub enum ReconcileError {
+    /// A definition of the manifest has no `@id`, so it can't be matched with the definitions of the connector
+    MissingId { kind: Kind },
+    DuplicateId { kind: Kind, id: String },
+    /// The definitions of the connector could not be queried
+    Query { kind: Kind, source: Box<Error<EdcError>> },
+    /// A step of the plan failed, the steps before it have been applied
+    Step { step: Step, source: Box<Error<EdcError>> },
+}
+
+impl fmt::Display for ReconcileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The details of the connector say more than the status alone
+        let describe = |e: &Error<EdcError>| e.edc_error().map(ToString::to_string).unwrap_or_else(|| e.to_string());
+        match self {
+            ReconcileError::MissingId { kind } => write!(f, "{} without @id in the manifest", kind),
+            ReconcileError::DuplicateId { kind, id } => write!(f, "{} {} appears more than once in the manifest", kind, id),
+            ReconcileError::Query { kind, source } => write!(f, "querying the {}s failed: {}", kind, describe(source)),
+            ReconcileError::Step { step, source } => {
+                let action = match step.action {
+                    Action::Create => "creating",
+                    Action::Update => "updating",
+                    Action::Delete => "deleting",
+                    Action::Unchanged => "checking",
+                };
+                write!(f, "{} {} {} failed: {}", action, step.kind, step.id, describe(source))
+            },
+        }
+    }
+}
+
+impl std::error::Error for ReconcileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReconcileError::Query { source, .. } | ReconcileError::Step { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Definitions of the manifest as JSON with their ids
+type Definitions = Vec<(String, Value)>;
+
+/// Builder for reconciling a connector with a manifest
+pub struct Reconcile<'a> {
+    configuration: &'a Configuration,
+    manifest: Manifest,
+    prune: bool,
+    page_size: i32,
+}
+
+impl<'a> Reconcile<'a> {
+
+    /// Reconciles the connector with the manifest. Definitions that are not in the manifest are kept unless
+    /// [`Reconcile::with_prune`] is set.
+    pub fn new(configuration: &'a Configuration, manifest: Manifest) -> Reconcile<'a> {
+        Reconcile {
+            configuration,
+            manifest,
+            prune: false,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Also deletes the definitions of the connector that are not in the manifest
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Page size of the queries for the definitions of the connector
+    pub fn with_page_size(mut self, page_size: i32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Compares the manifest with the connector without changing anything
+    #[tracing::instrument(name = "reconcile_plan", skip_all)]
+    pub async fn plan(&self) -> Result<Plan, ReconcileError> {
+        let desired = self.desired()?;
+        let policy_definitions = query(Kind::PolicyDefinition, policy_definition_api::query_policy_definitions_stream(self.configuration, None, self.page_size), |policy_definition| policy_definition.at_id.clone()).await?;
+        let assets = query(Kind::Asset, asset_api::request_assets_stream(self.configuration, None, self.page_size), |asset| asset.at_id.clone()).await?;
+        let contract_definitions = query(Kind::ContractDefinition, contract_definition_api::query_all_contract_definitions_stream(self.configuration, None, self.page_size), |contract_definition| contract_definition.at_id.clone()).await?;
+        let current = [
+            (Kind::PolicyDefinition, policy_definitions),
+            (Kind::Asset, assets),
+            (Kind::ContractDefinition, contract_definitions),
+        ];
+
+        let mut upserts = Vec::new();
+        let mut deletes = Vec::new();
+        for ((kind, desired), (_, current)) in desired.iter().zip(current) {
+            for (id, definition) in desired {
+                let step = match current.get(id) {
+                    None => Step { kind: *kind, id: id.clone(), action: Action::Create, changes: Vec::new() },
+                    Some(existing) => match differences(*kind, definition, existing) {
+                        changes if changes.is_empty() => Step { kind: *kind, id: id.clone(), action: Action::Unchanged, changes },
+                        changes => Step { kind: *kind, id: id.clone(), action: Action::Update, changes },
+                    },
+                };
+                upserts.push(step);
+            }
+            if self.prune {
+                let wanted = desired.iter().map(|(id, _)| id).collect::<HashSet<_>>();
+                let mut extra = current.into_keys().filter(|id| !wanted.contains(id)).collect::<Vec<_>>();
+                extra.sort();
+                deletes.extend(extra.into_iter().map(|id| Step { kind: *kind, id, action: Action::Delete, changes: Vec::new() }));
+            }
+        }
+
+        // Contract definitions are deleted before anything they may refer to changes
+        let (first, mut last): (Vec<_>, Vec<_>) = deletes.into_iter().partition(|step| step.kind == Kind::ContractDefinition);
+        last.sort_by_key(|step| std::cmp::Reverse(step.kind));
+        let steps = first.into_iter()
+            .chain(upserts)
+            .chain(last)
+            .collect::<Vec<_>>();
+        tracing::debug!(steps = steps.len(), "Planned reconciliation");
+        Ok(Plan { steps })
+    }
+
+    /// Plans and applies the changes, returns the plan that was applied
+    #[tracing::instrument(name = "reconcile_apply", skip_all)]
+    pub async fn apply(self) -> Result<Plan, ReconcileError> {
+        let plan = self.plan().await?;
+        for step in plan.changes() {
+            self.execute(step).await.map_err(|source| ReconcileError::Step { step: step.clone(), source: Box::new(source) })?;
+            tracing::debug!(kind = %step.kind, id = step.id, action = ?step.action, "Applied step");
+        }
+        Ok(plan)
+    }
+
+    /// The definitions of the manifest by kind as JSON, in the order of the manifest
+    fn desired(&self) -> Result<[(Kind, Definitions); 3], ReconcileError> {
+        Ok([
+            (Kind::PolicyDefinition, by_id(&self.manifest.policy_definitions, Kind::PolicyDefinition, |policy_definition| policy_definition.at_id.as_deref())?),
+            (Kind::Asset, by_id(&self.manifest.assets, Kind::Asset, |asset| asset.at_id.as_deref())?),
+            (Kind::ContractDefinition, by_id(&self.manifest.contract_definitions, Kind::ContractDefinition, |contract_definition| contract_definition.at_id.as_deref())?),
+        ])
+    }
+
+    async fn execute(&self, step: &Step) -> Result<(), Error<EdcError>> {
+        let id = step.id.as_str();
+        match (step.kind, step.action) {
+            (_, Action::Unchanged) => Ok(()),
+            (Kind::PolicyDefinition, Action::Delete) => policy_definition_api::delete_policy_definition(self.configuration, id).await,
+            (Kind::Asset, Action::Delete) => asset_api::remove_asset(self.configuration, id).await,
+            (Kind::ContractDefinition, Action::Delete) => contract_definition_api::delete_contract_definition(self.configuration, id).await,
+            (Kind::PolicyDefinition, action) => {
+                let policy_definition = find(&self.manifest.policy_definitions, id, |policy_definition| policy_definition.at_id.as_deref());
+                match action {
+                    Action::Create => policy_definition_api::create_policy_definition(self.configuration, Some(policy_definition)).await.map(drop),
+                    _ => policy_definition_api::update_policy_definition(self.configuration, id, Some(policy_definition)).await,
+                }
+            },
+            (Kind::Asset, action) => {
+                let asset = find(&self.manifest.assets, id, |asset| asset.at_id.as_deref());
+                match action {
+                    Action::Create => asset_api::create_asset(self.configuration, Some(asset)).await.map(drop),
+                    _ => asset_api::update_asset(self.configuration, Some(asset)).await,
+                }
+            },
+            (Kind::ContractDefinition, action) => {
+                let contract_definition = find(&self.manifest.contract_definitions, id, |contract_definition| contract_definition.at_id.as_deref());
+                match action {
+                    Action::Create => contract_definition_api::create_contract_definition(self.configuration, Some(contract_definition)).await.map(drop),
+                    _ => contract_definition_api::update_contract_definition(self.configuration, Some(contract_definition)).await,
+                }
+            },
+        }
+    }
+
+}
+
+/// Checks that every definition has a unique id and pairs them with their JSON
+fn by_id<T: Serialize>(definitions: &[T], kind: Kind, id: impl Fn(&T) -> Option<&str>) -> Result<Definitions, ReconcileError> {
+    let mut seen = HashSet::new();
+    definitions.iter().map(|definition| {
+        let id = id(definition).ok_or(ReconcileError::MissingId { kind })?.to_string();
+        if !seen.insert(id.clone()) {
+            return Err(ReconcileError::DuplicateId { kind, id });
+        }
+        Ok((id, serde_json::to_value(definition).unwrap_or_default()))
+    }).collect()
+}
+
+/// The definition of the plan, plans are only made from the manifest so it is always there
+fn find<T: Clone>(definitions: &[T], id: &str, at_id: impl Fn(&T) -> Option<&str>) -> T {
+    definitions.iter()
+        .find(|definition| at_id(definition) == Some(id))
+        .cloned()
+        .expect("step of the plan refers to a definition of the manifest")
+}
+
+/// All definitions of one kind on the connector as JSON by id
+async fn query<T: Serialize>(kind: Kind, stream: impl Stream<Item = Result<T, Error<EdcError>>>, id: impl Fn(&T) -> Option<String>) -> Result<HashMap<String, Value>, ReconcileError> {
+    let definitions = stream.try_collect::<Vec<_>>().await.map_err(|source| ReconcileError::Query { kind, source: Box::new(source) })?;
+    Ok(definitions.into_iter()
+        .filter_map(|definition| Some((id(&definition)?, serde_json::to_value(&definition).ok()?)))
+        .collect())
+}
+
+/// Names of the properties of the definition that differ from the existing one. Properties the connector manages
+/// itself, such as `createdAt` or the generated `@id` of a policy, are not compared.
+fn differences(kind: Kind, desired: &Value, existing: &Value) -> Vec<String> {
+    let (desired, existing) = (canonical(&json_ld::normalize(desired)), canonical(&json_ld::normalize(existing)));
+    let fields: &[&str] = match kind {
+        Kind::PolicyDefinition => &["policy"],
+        Kind::Asset => &["properties", "privateProperties", "dataAddress"],
+        Kind::ContractDefinition => &["accessPolicyId", "contractPolicyId", "assetsSelector"],
+    };
+    fields.iter()
+        .filter(|field| comparable(kind, field, desired.get(**field)) != comparable(kind, field, existing.get(**field)))
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// The value of a property without what the connector adds on its own
+fn comparable(kind: Kind, field: &str, value: Option<&Value>) -> Value {
+    let mut value = value.cloned().unwrap_or(Value::Null);
+    match (kind, field, &mut value) {
+        // The connector repeats the id of the asset as property
+        (Kind::Asset, "properties", Value::Object(properties)) => {
+            properties.remove("id");
+        },
+        (Kind::Asset, "dataAddress", Value::Object(data_address)) => {
+            data_address.remove("@type");
+        },
+        // Policies get an id of their own, and are sets if nothing else is said
+        (Kind::PolicyDefinition, "policy", Value::Object(policy)) => {
+            policy.remove("@id");
+            if policy.get("@type").is_none_or(|policy_type| *policy_type == "Set") {
+                policy.remove("@type");
+            }
+        },
+        (Kind::ContractDefinition, "assetsSelector", criteria) => {
+            let criteria = match criteria {
+                Value::Array(criteria) => criteria.iter_mut().collect(),
+                criterion => vec![criterion],
+            };
+            for criterion in criteria {
+                if let Value::Object(criterion) = criterion {
+                    criterion.remove("@type");
+                }
+            }
+        },
+        _ => {},
+    }
+    canonical(&value)
+}
+
+/// JSON-LD form without `@context`, null values and empty collections, with single values unwrapped from their
+/// arrays, since a connector is free to answer either way
+fn canonical(value: &Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let object = object.iter()
+                .filter(|(key, _)| *key != "@context")
+                .map(|(key, value)| (key.clone(), canonical(value)))
+                .filter(|(_, value)| !is_empty(value))
+                .collect::<Map<_, _>>();
+            Value::Object(object)
+        },
+        Value::Array(values) => {
+            let mut values = values.iter().map(canonical).filter(|value| !is_empty(value)).collect::<Vec<_>>();
+            match values.len() {
+                1 => values.remove(0),
+                _ => Value::Array(values),
+            }
+        },
+        value => value.clone(),
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(values) => values.is_empty(),
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
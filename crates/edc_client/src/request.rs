@@ -0,0 +1,222 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::Instrument;
+
+use crate::ResponseContent;
+use crate::error::ErrorEntity;
+use crate::json_ld;
+use super::{Error, configuration};
+
+
+/// A request against the management api. Every api function goes through this pipeline, which authenticates the request,
+/// sets the user agent, retries failed attempts according to the retry policy, decodes error responses into the typed
+/// error of the function and brings successful responses into the client JSON-LD context.
+pub(crate) struct Request<'a> {
+    configuration: &'a configuration::Configuration,
+    method: reqwest::Method,
+    path: String,
+    body: Option<serde_json::Result<Vec<u8>>>,
+    protocol: bool,
+    idempotent: bool,
+    no_content_is_error: bool,
+}
+
+impl<'a> Request<'a> {
+
+    /// Creates a request for the given path relative to the base path of the configuration
+    pub(crate) fn new(configuration: &'a configuration::Configuration, method: reqwest::Method, path: impl Into<String>) -> Request<'a> {
+        Request {
+            configuration,
+            idempotent: method != reqwest::Method::POST && method != reqwest::Method::PATCH,
+            method,
+            path: path.into(),
+            body: None,
+            protocol: false,
+            no_content_is_error: false,
+        }
+    }
+
+    /// Creates a request against the dataspace protocol endpoint of a counter party. Management credentials are never
+    /// sent to the counter party, the bearer token of the configuration is sent as identity token instead.
+    pub(crate) fn protocol(configuration: &'a configuration::Configuration, method: reqwest::Method, path: impl Into<String>) -> Request<'a> {
+        Request {
+            protocol: true,
+            ..Request::new(configuration, method, path)
+        }
+    }
+
+    /// Marks a POST request as safe to send more than once, e.g. because it only queries
+    pub(crate) fn idempotent(mut self) -> Request<'a> {
+        self.idempotent = true;
+        self
+    }
+
+    /// Treats an empty `204 No Content` answer as error response, for endpoints that signal a missing result with it
+    pub(crate) fn no_content_is_error(mut self) -> Request<'a> {
+        self.no_content_is_error = true;
+        self
+    }
+
+    pub(crate) fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Request<'a> {
+        self.body = Some(serde_json::to_vec(body));
+        self
+    }
+
+    fn build(&self, body: Option<Vec<u8>>, token: Option<&str>) -> Result<reqwest::Request, reqwest::Error> {
+        let local_var_configuration = self.configuration;
+        let local_var_uri_str = format!("{}{}", local_var_configuration.base_path, self.path);
+        let mut local_var_req_builder = local_var_configuration.client.request(self.method.clone(), local_var_uri_str.as_str());
+
+        if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+            local_var_req_builder = local_var_req_builder.header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+        }
+        if self.protocol {
+            if let Some(ref local_var_token) = local_var_configuration.bearer_access_token {
+                local_var_req_builder = local_var_req_builder.header(reqwest::header::AUTHORIZATION, local_var_token.as_str());
+            }
+        } else {
+            // Only one scheme can use the authorization header, tokens win over basic auth
+            let local_var_basic_auth = local_var_configuration.basic_auth.as_ref().filter(|(local_var_username, _)| !local_var_username.is_empty());
+            if let Some(local_var_token) = token.or(local_var_configuration.bearer_access_token.as_deref()).or(local_var_configuration.oauth_access_token.as_deref()) {
+                local_var_req_builder = local_var_req_builder.bearer_auth(local_var_token);
+            } else if let Some((local_var_username, local_var_password)) = local_var_basic_auth {
+                local_var_req_builder = local_var_req_builder.basic_auth(local_var_username, local_var_password.as_ref());
+            }
+            if let Some(ref local_var_api_key) = local_var_configuration.api_key {
+                local_var_req_builder = local_var_req_builder.header(configuration::API_KEY_HEADER, local_var_api_key.value());
+            }
+        }
+        if let Some(local_var_body) = body {
+            local_var_req_builder = local_var_req_builder
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(local_var_body);
+        }
+
+        local_var_req_builder.build()
+    }
+
+    /// Sends the request and returns status and content of a successful response. Error responses are decoded into `E`.
+    ///
+    /// Every request is recorded in a `management_api` span with method, path, status, latency and the number of attempts.
+    /// Headers and bodies are never recorded, so credentials don't end up in the logs.
+    pub(crate) async fn send<E: ErrorEntity>(self) -> Result<(reqwest::StatusCode, String), Error<E>> {
+        let local_var_span = tracing::info_span!(
+            "management_api",
+            method = %self.method,
+            path = %self.path,
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            attempts = tracing::field::Empty,
+        );
+        self.dispatch().instrument(local_var_span).await
+    }
+
+    async fn dispatch<E: ErrorEntity>(mut self) -> Result<(reqwest::StatusCode, String), Error<E>> {
+        let local_var_body = self.body.take().transpose()?;
+        let local_var_policy = &self.configuration.retry;
+        let local_var_retryable = self.idempotent || local_var_policy.retry_non_idempotent;
+        let local_var_span = tracing::Span::current();
+
+        // The token provider only authenticates management requests, counter parties get the identity token
+        let local_var_token_provider = self.configuration.token_provider.as_ref().filter(|_| !self.protocol);
+        let mut local_var_token_refreshed = false;
+
+        let mut local_var_attempt = 1;
+        loop {
+            let local_var_token = match local_var_token_provider {
+                Some(local_var_token_provider) => Some(local_var_token_provider.token().await?),
+                None => None,
+            };
+            let local_var_req = self.build(local_var_body.clone(), local_var_token.as_deref())?;
+            let local_var_can_retry = local_var_attempt < local_var_policy.max_attempts;
+            local_var_span.record("attempts", local_var_attempt);
+
+            let local_var_start = Instant::now();
+            let local_var_resp = match self.configuration.client.execute(local_var_req).await {
+                Ok(local_var_resp) => local_var_resp,
+                Err(e) if local_var_can_retry && (local_var_retryable || e.is_connect()) => {
+                    let local_var_delay = local_var_policy.backoff(local_var_attempt);
+                    tracing::debug!(error = %e, attempt = local_var_attempt, delay_ms = local_var_delay.as_millis() as u64, "Retrying management api request");
+                    tokio::time::sleep(local_var_delay).await;
+                    local_var_attempt += 1;
+                    continue;
+                }
+                Err(e) => {
+                    tracing::debug!(error = %e, "Management api request failed");
+                    return Err(Error::Transport { source: e, attempts: local_var_attempt });
+                }
+            };
+
+            let local_var_status = local_var_resp.status();
+            if let (Some(local_var_token_provider), Some(local_var_token)) = (local_var_token_provider, local_var_token.as_deref()) {
+                if local_var_status == reqwest::StatusCode::UNAUTHORIZED && !local_var_token_refreshed {
+                    // The token may have been revoked before it expired, a rejected request is safe to send once more
+                    tracing::debug!(attempt = local_var_attempt, "Retrying management api request with a fresh token");
+                    local_var_token_provider.invalidate(local_var_token);
+                    local_var_token_refreshed = true;
+                    local_var_attempt += 1;
+                    continue;
+                }
+            }
+            if local_var_can_retry && local_var_retryable && local_var_policy.is_retryable_status(local_var_status) {
+                let local_var_delay = local_var_policy.backoff(local_var_attempt).max(retry_after(&local_var_resp).unwrap_or_default());
+                tracing::debug!(status = local_var_status.as_u16(), attempt = local_var_attempt, delay_ms = local_var_delay.as_millis() as u64, "Retrying management api request");
+                tokio::time::sleep(local_var_delay).await;
+                local_var_attempt += 1;
+                continue;
+            }
+            let local_var_headers = local_var_resp.headers().clone();
+            let local_var_content = local_var_resp.text().await?;
+
+            local_var_span.record("status", local_var_status.as_u16());
+            local_var_span.record("latency_ms", local_var_start.elapsed().as_millis() as u64);
+            tracing::debug!("Management api request completed");
+
+            let local_var_is_error = local_var_status.is_client_error() || local_var_status.is_server_error()
+                || (self.no_content_is_error && local_var_status == reqwest::StatusCode::NO_CONTENT);
+            return if !local_var_is_error {
+                Ok((local_var_status, local_var_content))
+            } else {
+                let local_var_entity = E::from_response(local_var_status, &local_var_headers, &local_var_content, local_var_policy.is_retryable_status(local_var_status));
+                let local_var_error = ResponseContent { status: local_var_status, content: local_var_content, entity: local_var_entity, attempts: local_var_attempt };
+                Err(Error::ResponseError(local_var_error))
+            };
+        }
+    }
+
+    /// Sends the request and deserializes the response
+    pub(crate) async fn execute<T: DeserializeOwned, E: ErrorEntity>(self) -> Result<T, Error<E>> {
+        let (_, local_var_content) = self.send().await?;
+        decode(&local_var_content)
+    }
+
+    /// Sends the request and discards the content of the response
+    pub(crate) async fn execute_empty<E: ErrorEntity>(self) -> Result<(), Error<E>> {
+        self.send().await.map(|_| ())
+    }
+
+}
+
+/// Delay the server asked for in the `Retry-After` header. Only the number of seconds is supported, not the http date.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let seconds = response.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Deserializes the content of a successful response after compacting it into the client context, see [`crate::json_ld`]
+pub(crate) fn decode<T: DeserializeOwned, E>(content: &str) -> Result<T, Error<E>> {
+    let val: serde_json::Value = serde_json::from_str(content).map_err(Error::from)?;
+    serde_json::from_value(json_ld::normalize(&val)).map_err(Error::from)
+}
@@ -0,0 +1,217 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! Following contract negotiations and transfer processes as they change their state. The `watch_*` functions of the
+//! api modules poll the connector and stream every state change until a terminal state is reached, the `wait_*`
+//! functions wait for a state that matches a predicate.
+//!
+//! Polling starts with a short interval that grows while the state stays the same, see [`PollInterval`].
+
+
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use edc_api::transfer_state::TransferProcessState;
+use edc_api::{ContractNegotiation, ContractNegotiationState, TransferProcess};
+use futures::{stream, Stream, TryStreamExt};
+
+use super::Error;
+
+
+/// Process with a state machine on the connector
+pub trait Stateful {
+
+    type State: Copy + PartialEq + fmt::Debug;
+
+    fn current_state(&self) -> Self::State;
+
+    /// Whether the process has ended and won't change its state anymore
+    fn is_terminal(&self) -> bool;
+
+}
+
+impl Stateful for ContractNegotiation {
+
+    type State = ContractNegotiationState;
+
+    fn current_state(&self) -> ContractNegotiationState {
+        self.state
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.state.is_terminal()
+    }
+
+}
+
+impl Stateful for TransferProcess {
+
+    type State = TransferProcessState;
+
+    fn current_state(&self) -> TransferProcessState {
+        self.state.unwrap_or(TransferProcessState::Initial)
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.current_state().is_terminal()
+    }
+
+}
+
+/// Time between two polls. It starts at `initial` and is multiplied by `multiplier` after every poll that didn't see a
+/// new state, up to `max`. A state change resets it to `initial`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollInterval {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+}
+
+impl PollInterval {
+
+    pub fn new(initial: Duration, max: Duration) -> PollInterval {
+        PollInterval {
+            initial,
+            max: max.max(initial),
+            multiplier: 1.5,
+        }
+    }
+
+    /// Polls at a constant interval
+    pub fn fixed(interval: Duration) -> PollInterval {
+        PollInterval::new(interval, interval)
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Interval after `unchanged` polls in a row that saw the same state
+    pub fn after(&self, unchanged: u32) -> Duration {
+        let interval = self.initial.as_secs_f64() * self.multiplier.powi(unchanged.min(64) as i32);
+        Duration::from_secs_f64(interval.min(self.max.as_secs_f64()))
+    }
+
+}
+
+impl Default for PollInterval {
+    fn default() -> Self {
+        PollInterval::new(Duration::from_millis(100), Duration::from_secs(2))
+    }
+}
+
+impl From<Duration> for PollInterval {
+    fn from(interval: Duration) -> Self {
+        PollInterval::fixed(interval)
+    }
+}
+
+/// Why waiting for a state did not succeed
+#[derive(Debug)]
+pub enum WaitError<T, E> {
+    /// No matching state was seen in time, `last` is the process as it was seen last
+    Timeout { last: Option<T> },
+    /// The process reached a terminal state that does not match
+    Unreachable(T),
+    Api(Error<E>),
+}
+
+impl <T: Stateful, E> fmt::Display for WaitError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitError::Timeout { last: Some(last) } => write!(f, "timed out in state {:?}", last.current_state()),
+            WaitError::Timeout { last: None } => write!(f, "timed out before the state was known"),
+            WaitError::Unreachable(process) => write!(f, "ended in state {:?}", process.current_state()),
+            WaitError::Api(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl <T: Stateful + fmt::Debug, E: fmt::Debug> std::error::Error for WaitError<T, E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WaitError::Api(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+impl <T, E> From<Error<E>> for WaitError<T, E> {
+    fn from(e: Error<E>) -> Self {
+        WaitError::Api(e)
+    }
+}
+
+struct Watcher<F, S> {
+    fetch: F,
+    last: Option<S>,
+    unchanged: u32,
+    ended: bool,
+}
+
+/// Calls `fetch` until the process reaches a terminal state and streams the process whenever its state changed. The
+/// stream ends after the first error.
+pub(crate) fn watch<'a, T, E, F, Fut>(poll_interval: PollInterval, fetch: F) -> impl Stream<Item = Result<T, Error<E>>> + 'a
+where
+    T: Stateful + 'a,
+    E: 'a,
+    F: FnMut() -> Fut + 'a,
+    Fut: Future<Output = Result<T, Error<E>>> + 'a,
+{
+    let watcher = Watcher { fetch, last: None, unchanged: 0, ended: false };
+
+    stream::try_unfold(watcher, move |mut watcher| async move {
+        if watcher.ended {
+            return Ok(None);
+        }
+        loop {
+            if watcher.last.is_some() {
+                tokio::time::sleep(poll_interval.after(watcher.unchanged)).await;
+            }
+            let process = (watcher.fetch)().await?;
+            let state = process.current_state();
+            if watcher.last == Some(state) {
+                watcher.unchanged += 1;
+                continue;
+            }
+
+            tracing::debug!(?state, "Observed state change");
+            watcher.last = Some(state);
+            watcher.unchanged = 0;
+            watcher.ended = process.is_terminal();
+            return Ok(Some((process, watcher)));
+        }
+    })
+}
+
+/// Follows the stream of [`watch`] until the state of the process matches `predicate`
+pub(crate) async fn wait_until<T, E>(states: impl Stream<Item = Result<T, Error<E>>>, predicate: impl Fn(&T::State) -> bool, timeout: Duration) -> Result<T, WaitError<T, E>>
+where
+    T: Stateful,
+{
+    let mut states = std::pin::pin!(states);
+    let mut last = None;
+    let waited = tokio::time::timeout(timeout, async {
+        while let Some(process) = states.try_next().await? {
+            if predicate(&process.current_state()) {
+                return Ok(process);
+            }
+            if process.is_terminal() {
+                return Err(WaitError::Unreachable(process));
+            }
+            last = Some(process);
+        }
+        // The stream only ends after a terminal state, which has been handled above
+        Err(WaitError::Timeout { last: last.take() })
+    }).await;
+
+    waited.unwrap_or_else(|_| Err(WaitError::Timeout { last }))
+}
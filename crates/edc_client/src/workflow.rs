@@ -0,0 +1,371 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+//! The usual consumer workflow in one call: look up the dataset in the catalog of the provider, pick an offer, negotiate
+//! a contract, wait until the negotiation is `FINALIZED`, start a transfer and wait until it is `STARTED`.
+//!
+//! ```no_run
+//! # async fn run(configuration: edc_client::configuration::Configuration) -> Result<(), edc_client::workflow::WorkflowError> {
+//! use edc_client::workflow::NegotiateAndTransfer;
+//!
+//! let transfer = NegotiateAndTransfer::new(&configuration, "http://provider:9194/protocol", "provider", "asset-1")
+//!     .with_timeout(std::time::Duration::from_secs(60))
+//!     .run()
+//!     .await?;
+//! println!("Transfer {} started", transfer.transfer_process_id);
+//! # Ok(())
+//! # }
+//! ```
+
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Mutex;
+use std::task::Poll;
+use std::time::Duration;
+
+use edc_api::transfer_state::TransferProcessState;
+use edc_api::{ContractNegotiationState, ContractOffer};
+
+use super::configuration::Configuration;
+use super::watch::{wait_until, PollInterval, WaitError};
+use super::{catalog_api, contract_negotiation_api, transfer_process_api};
+
+
+pub const DATASPACE_PROTOCOL: &str = "dataspace-protocol-http";
+
+/// Why a workflow did not end with a started transfer
+#[derive(Debug)]
+pub enum WorkflowError {
+    /// The dataset has no offer, or none that the offer filter accepts
+    NoOffer { asset_id: String },
+    NegotiationTerminated { negotiation_id: String, error_detail: Option<String> },
+    /// The negotiation was not finalized in time, `state` is the last state that was seen
+    NegotiationTimeout { negotiation_id: String, state: ContractNegotiationState },
+    TransferTerminated { transfer_process_id: String, error_detail: Option<String> },
+    /// The transfer was not started in time, `state` is the last state that was seen
+    TransferTimeout { transfer_process_id: String, state: Option<TransferProcessState> },
+    /// The workflow was cancelled, the negotiation or transfer that was in progress has been terminated
+    Cancelled { negotiation_id: Option<String>, transfer_process_id: Option<String> },
+    /// The connector answered the given step without the id of the negotiation, agreement or transfer process
+    MissingId { step: &'static str },
+    /// A request of the given step failed
+    Api { step: &'static str, source: Box<dyn std::error::Error + Send + Sync> },
+}
+
+impl fmt::Display for WorkflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkflowError::NoOffer { asset_id } => write!(f, "no suitable offer for asset {}", asset_id),
+            WorkflowError::NegotiationTerminated { negotiation_id, error_detail } =>
+                write!(f, "negotiation {} was terminated: {}", negotiation_id, error_detail.as_deref().unwrap_or("no details")),
+            WorkflowError::NegotiationTimeout { negotiation_id, state } => write!(f, "negotiation {} timed out in state {:?}", negotiation_id, state),
+            WorkflowError::TransferTerminated { transfer_process_id, error_detail } =>
+                write!(f, "transfer process {} was terminated: {}", transfer_process_id, error_detail.as_deref().unwrap_or("no details")),
+            WorkflowError::TransferTimeout { transfer_process_id, state } => write!(f, "transfer process {} timed out in state {:?}", transfer_process_id, state),
+            WorkflowError::Cancelled { .. } => write!(f, "workflow was cancelled"),
+            WorkflowError::MissingId { step } => write!(f, "connector returned no id for the {}", step),
+            WorkflowError::Api { step, source } => write!(f, "{} failed: {}", step, source),
+        }
+    }
+}
+
+impl std::error::Error for WorkflowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WorkflowError::Api { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+fn api_error<E: std::error::Error + Send + Sync + 'static>(step: &'static str) -> impl FnOnce(E) -> WorkflowError {
+    move |e| WorkflowError::Api { step, source: Box::new(e) }
+}
+
+/// Result of a successful workflow
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    pub negotiation_id: String,
+    pub contract_agreement_id: String,
+    pub transfer_process_id: String,
+    pub transfer_process: edc_api::TransferProcess,
+}
+
+/// Ids of the processes the workflow started so far, so they can be terminated on cancellation
+#[derive(Debug, Default)]
+struct Progress {
+    negotiation_id: Option<String>,
+    transfer_process_id: Option<String>,
+    /// A request that starts a negotiation or transfer is in flight, its process would be left behind if it was dropped
+    initiating: bool,
+}
+
+type OfferFilter<'a> = Box<dyn Fn(&ContractOffer) -> bool + Send + Sync + 'a>;
+
+/// Builder for the negotiate and transfer workflow of a consumer
+pub struct NegotiateAndTransfer<'a> {
+    configuration: &'a Configuration,
+    counter_party_address: String,
+    counter_party_id: String,
+    asset_id: String,
+    protocol: String,
+    offer: Option<ContractOffer>,
+    offer_filter: Option<OfferFilter<'a>>,
+    transfer_type: String,
+    data_destination: edc_api::DataAddress,
+    callback_addresses: Option<Vec<edc_api::CallbackAddress>>,
+    timeout: Duration,
+    poll_interval: PollInterval,
+}
+
+impl<'a> NegotiateAndTransfer<'a> {
+
+    /// Workflow for the asset of the provider at `counter_party_address`. Transfers are `HttpData-PULL` into an
+    /// `HttpProxy` destination unless configured otherwise.
+    pub fn new(configuration: &'a Configuration, counter_party_address: impl Into<String>, counter_party_id: impl Into<String>, asset_id: impl Into<String>) -> NegotiateAndTransfer<'a> {
+        NegotiateAndTransfer {
+            configuration,
+            counter_party_address: counter_party_address.into(),
+            counter_party_id: counter_party_id.into(),
+            asset_id: asset_id.into(),
+            protocol: DATASPACE_PROTOCOL.to_string(),
+            offer: None,
+            offer_filter: None,
+            transfer_type: "HttpData-PULL".to_string(),
+            data_destination: edc_api::DataAddress {
+                at_type: None,
+                r#type: Some("HttpProxy".to_string()),
+                base_url: None,
+                additional_properties: std::collections::HashMap::new(),
+            },
+            callback_addresses: None,
+            timeout: Duration::from_secs(30),
+            poll_interval: PollInterval::default(),
+        }
+    }
+
+    /// Negotiates the given offer instead of looking it up in the catalog
+    pub fn with_offer(mut self, offer: ContractOffer) -> Self {
+        self.offer = Some(offer);
+        self
+    }
+
+    /// Picks the first offer of the dataset that the filter accepts, instead of the first offer
+    pub fn with_offer_filter(mut self, offer_filter: impl Fn(&ContractOffer) -> bool + Send + Sync + 'a) -> Self {
+        self.offer_filter = Some(Box::new(offer_filter));
+        self
+    }
+
+    pub fn with_protocol(mut self, protocol: impl Into<String>) -> Self {
+        self.protocol = protocol.into();
+        self
+    }
+
+    pub fn with_transfer_type(mut self, transfer_type: impl Into<String>) -> Self {
+        self.transfer_type = transfer_type.into();
+        self
+    }
+
+    pub fn with_data_destination(mut self, data_destination: edc_api::DataAddress) -> Self {
+        self.data_destination = data_destination;
+        self
+    }
+
+    /// Callback addresses for both the negotiation and the transfer
+    pub fn with_callback_addresses(mut self, callback_addresses: Vec<edc_api::CallbackAddress>) -> Self {
+        self.callback_addresses = Some(callback_addresses);
+        self
+    }
+
+    /// How long to wait for the negotiation to be finalized, and again for the transfer to be started
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// A [`Duration`] polls at a fixed interval
+    pub fn with_poll_interval(mut self, poll_interval: impl Into<PollInterval>) -> Self {
+        self.poll_interval = poll_interval.into();
+        self
+    }
+
+    /// Runs the whole workflow
+    pub async fn run(self) -> Result<Transfer, WorkflowError> {
+        self.run_until(std::future::pending()).await
+    }
+
+    /// Runs the workflow until `cancel` completes. A negotiation or transfer that is in progress at that point is
+    /// terminated, and the workflow fails with [`WorkflowError::Cancelled`].
+    pub async fn run_until(self, cancel: impl Future<Output = ()>) -> Result<Transfer, WorkflowError> {
+        let progress = Mutex::new(Progress::default());
+        let execute = self.execute(&progress);
+        tokio::pin!(execute);
+        tokio::select! {
+            result = &mut execute => return result,
+            _ = cancel => {},
+        }
+
+        // Finish a request that starts a process, so that the process is known and can be terminated
+        std::future::poll_fn(|cx| {
+            if !progress.lock().unwrap().initiating {
+                return Poll::Ready(());
+            }
+            match execute.as_mut().poll(cx) {
+                Poll::Ready(_) => Poll::Ready(()),
+                Poll::Pending if progress.lock().unwrap().initiating => Poll::Pending,
+                Poll::Pending => Poll::Ready(()),
+            }
+        }).await;
+
+        let progress = std::mem::take(&mut *progress.lock().unwrap());
+        self.terminate(&progress).await;
+        Err(WorkflowError::Cancelled { negotiation_id: progress.negotiation_id, transfer_process_id: progress.transfer_process_id })
+    }
+
+    #[tracing::instrument(name = "negotiate_and_transfer", skip_all, fields(asset_id = %self.asset_id))]
+    async fn execute(&self, progress: &Mutex<Progress>) -> Result<Transfer, WorkflowError> {
+        let offer = match &self.offer {
+            Some(offer) => offer.clone(),
+            None => self.select_offer().await?,
+        };
+
+        let negotiation_id = self.negotiate(offer, progress).await?;
+        let contract_agreement_id = self.wait_for_agreement(&negotiation_id).await?;
+
+        let transfer_process_id = self.transfer(&contract_agreement_id, progress).await?;
+        let transfer_process = self.wait_for_start(&transfer_process_id).await?;
+
+        Ok(Transfer { negotiation_id, contract_agreement_id, transfer_process_id, transfer_process })
+    }
+
+    async fn select_offer(&self) -> Result<ContractOffer, WorkflowError> {
+        let dataset_request = edc_api::DatasetRequest {
+            at_id: Some(self.asset_id.clone()),
+            counter_party_address: Some(self.counter_party_address.clone()),
+            counter_party_id: Some(self.counter_party_id.clone()),
+            protocol: Some(self.protocol.clone()),
+            query_spec: None,
+            ..edc_api::DatasetRequest::default()
+        };
+        let dataset = catalog_api::get_dataset(self.configuration, Some(dataset_request)).await.map_err(api_error("catalog request"))?;
+
+        dataset.has_policy.into_iter()
+            .find(|offer| self.offer_filter.as_ref().is_none_or(|offer_filter| offer_filter(offer)))
+            .ok_or_else(|| WorkflowError::NoOffer { asset_id: self.asset_id.clone() })
+    }
+
+    async fn negotiate(&self, offer: ContractOffer, progress: &Mutex<Progress>) -> Result<String, WorkflowError> {
+        let policy = edc_api::Offer {
+            context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String("http://www.w3.org/ns/odrl/2/".to_string()))]),
+            at_type: Some("Offer".to_string()),
+            at_id: offer.at_id,
+            assigner: offer.assigner.unwrap_or_else(|| self.counter_party_id.clone()),
+            target: offer.target.unwrap_or_else(|| self.asset_id.clone()),
+        };
+        let contract_request = edc_api::ContractRequest {
+            callback_addresses: self.callback_addresses.clone(),
+            counter_party_address: self.counter_party_address.clone(),
+            policy: Some(policy),
+            protocol: self.protocol.clone(),
+            ..edc_api::ContractRequest::default()
+        };
+
+        progress.lock().unwrap().initiating = true;
+        let response = contract_negotiation_api::initiate_contract_negotiation(self.configuration, Some(contract_request)).await;
+        // The id is recorded before anything else is awaited, so a cancellation from now on terminates the negotiation
+        let mut progress = progress.lock().unwrap();
+        progress.initiating = false;
+        let negotiation_id = response.map_err(api_error("contract negotiation"))?
+            .at_id.ok_or(WorkflowError::MissingId { step: "contract negotiation" })?;
+        progress.negotiation_id = Some(negotiation_id.clone());
+        tracing::debug!(negotiation_id, "Initiated contract negotiation");
+        Ok(negotiation_id)
+    }
+
+    async fn wait_for_agreement(&self, negotiation_id: &str) -> Result<String, WorkflowError> {
+        let states = contract_negotiation_api::watch_negotiation(self.configuration, negotiation_id, self.poll_interval);
+        match wait_until(states, |state| *state == ContractNegotiationState::Finalized, self.timeout).await {
+            Ok(negotiation) => negotiation.contract_agreement_id.ok_or(WorkflowError::MissingId { step: "contract agreement" }),
+            Err(WaitError::Unreachable(negotiation)) => Err(WorkflowError::NegotiationTerminated {
+                negotiation_id: negotiation_id.to_string(),
+                error_detail: negotiation.error_detail,
+            }),
+            Err(WaitError::Timeout { last }) => Err(WorkflowError::NegotiationTimeout {
+                negotiation_id: negotiation_id.to_string(),
+                state: last.map(|negotiation| negotiation.state).unwrap_or_default(),
+            }),
+            Err(WaitError::Api(e)) => Err(api_error("contract negotiation")(e)),
+        }
+    }
+
+    async fn transfer(&self, contract_agreement_id: &str, progress: &Mutex<Progress>) -> Result<String, WorkflowError> {
+        let transfer_request = edc_api::TransferRequest {
+            asset_id: self.asset_id.clone(),
+            callback_addresses: self.callback_addresses.clone(),
+            contract_id: contract_agreement_id.to_string(),
+            counter_party_address: self.counter_party_address.clone(),
+            data_destination: Box::new(self.data_destination.clone()),
+            protocol: self.protocol.clone(),
+            transfer_type: self.transfer_type.clone(),
+            ..edc_api::TransferRequest::default()
+        };
+
+        progress.lock().unwrap().initiating = true;
+        let response = transfer_process_api::initiate_transfer_process(self.configuration, Some(transfer_request)).await;
+        let mut progress = progress.lock().unwrap();
+        progress.initiating = false;
+        let transfer_process_id = response.map_err(api_error("transfer process"))?
+            .at_id.ok_or(WorkflowError::MissingId { step: "transfer process" })?;
+        progress.transfer_process_id = Some(transfer_process_id.clone());
+        tracing::debug!(transfer_process_id, "Initiated transfer process");
+        Ok(transfer_process_id)
+    }
+
+    async fn wait_for_start(&self, transfer_process_id: &str) -> Result<edc_api::TransferProcess, WorkflowError> {
+        let states = transfer_process_api::watch_transfer_process(self.configuration, transfer_process_id, self.poll_interval);
+        // Push transfers of small data may already be completed when they are polled
+        let started = |state: &TransferProcessState| matches!(state, TransferProcessState::Started | TransferProcessState::Completed);
+        match wait_until(states, started, self.timeout).await {
+            Ok(transfer_process) => Ok(transfer_process),
+            Err(WaitError::Unreachable(transfer_process)) => Err(WorkflowError::TransferTerminated {
+                transfer_process_id: transfer_process_id.to_string(),
+                error_detail: transfer_process.error_detail,
+            }),
+            Err(WaitError::Timeout { last }) => Err(WorkflowError::TransferTimeout {
+                transfer_process_id: transfer_process_id.to_string(),
+                state: last.and_then(|transfer_process| transfer_process.state),
+            }),
+            Err(WaitError::Api(e)) => Err(api_error("transfer process")(e)),
+        }
+    }
+
+    /// Terminates what was started last, failures are only logged since the workflow is given up anyway
+    async fn terminate(&self, progress: &Progress) {
+        if let Some(transfer_process_id) = &progress.transfer_process_id {
+            let terminate = edc_api::TerminateTransfer {
+                reason: Some("Cancelled by the consumer".to_string()),
+                ..edc_api::TerminateTransfer::default()
+            };
+            if let Err(e) = transfer_process_api::terminate_transfer_process(self.configuration, transfer_process_id, Some(terminate)).await {
+                tracing::warn!(transfer_process_id, error = %e, "Could not terminate transfer process");
+            }
+        } else if let Some(negotiation_id) = &progress.negotiation_id {
+            let terminate = edc_api::TerminateNegotiationSchema {
+                at_id: negotiation_id.clone(),
+                reason: Some("Cancelled by the consumer".to_string()),
+                ..edc_api::TerminateNegotiationSchema::default()
+            };
+            if let Err(e) = contract_negotiation_api::terminate_negotiation(self.configuration, negotiation_id, Some(terminate)).await {
+                tracing::warn!(negotiation_id, error = %e, "Could not terminate contract negotiation");
+            }
+        }
+    }
+
+}
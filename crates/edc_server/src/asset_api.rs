@@ -0,0 +1,94 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{AssetInput, AssetOutput, IdResponse, QuerySpec};
+use crate::error::{parse_body, require_body, ApiError};
+use crate::{default_context, now_millis, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v3/assets", post(create_asset).put(update_asset))
+        .route("/v3/assets/request", post(request_assets))
+        .route("/v3/assets/:id", get(get_asset).delete(remove_asset))
+}
+
+fn validate(asset: &AssetInput) -> Result<(), ApiError> {
+    if asset.data_address.r#type.as_deref().is_none_or(str::is_empty) {
+        return Err(ApiError::BadRequest("dataAddress.type is mandatory".to_string()));
+    }
+    Ok(())
+}
+
+/// Creates a new asset together with a data address
+pub async fn create_asset(State(store): State<SharedStore>, body: Bytes) -> Result<Json<IdResponse>, ApiError> {
+    let asset: AssetInput = require_body(&body)?;
+    validate(&asset)?;
+
+    let id = asset.at_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let created_at = now_millis();
+    let output = AssetOutput {
+        context: default_context(),
+        at_id: Some(id.clone()),
+        at_type: Some("Asset".to_string()),
+        created_at: Some(created_at),
+        data_address: Some(asset.data_address),
+        private_properties: asset.private_properties,
+        properties: Some(asset.properties),
+    };
+
+    if !store.assets.insert(output) {
+        return Err(ApiError::already_exists("Asset", &id));
+    }
+    Ok(Json(IdResponse::new(Some(id), Some(created_at))))
+}
+
+/// Gets an asset with the given ID
+pub async fn get_asset(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<AssetOutput>, ApiError> {
+    store.assets.get(&id).map(Json).ok_or_else(|| ApiError::not_found("Asset", &id))
+}
+
+/// Removes an asset with the given ID if possible. Deleting an asset is only possible if that asset is not yet referenced by a contract agreement.
+pub async fn remove_asset(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    if !store.assets.contains(&id) {
+        return Err(ApiError::not_found("Asset", &id));
+    }
+    if store.contract_agreements.any(|agreement| agreement.asset_id.as_deref() == Some(id.as_str())) {
+        return Err(ApiError::Conflict(format!("Asset {} cannot be deleted as it is referenced by at least one contract agreement", id)));
+    }
+    store.assets.remove(&id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Request all assets according to a particular query
+pub async fn request_assets(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<AssetOutput>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.assets.all(), query_spec.as_ref()).map(Json)
+}
+
+/// Updates an asset with the given ID if it exists
+pub async fn update_asset(State(store): State<SharedStore>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let asset: AssetInput = require_body(&body)?;
+    validate(&asset)?;
+    let id = asset.at_id.clone().ok_or_else(|| ApiError::BadRequest("@id is mandatory when updating an asset".to_string()))?;
+
+    store.assets.update(&id, |existing| {
+        existing.data_address = Some(asset.data_address);
+        existing.private_properties = asset.private_properties;
+        existing.properties = Some(asset.properties);
+    }).ok_or_else(|| ApiError::not_found("Asset", &id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
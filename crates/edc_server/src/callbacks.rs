@@ -0,0 +1,118 @@
+//! Receiver for the events that connectors post to callback addresses, so negotiations and transfers don't have to be
+//! polled. Every accepted event is sent through a channel, e.g.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use edc_server::callbacks::CallbackReceiver;
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//! let mut events = CallbackReceiver::new()
+//!     .with_auth("X-Callback-Key", "secret")
+//!     .spawn(listener, "/callbacks");
+//!
+//! while let Some(envelope) = events.recv().await {
+//!     println!("{}", envelope.event.name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use tokio::sync::mpsc;
+
+use crate::api_models::EventEnvelope;
+use crate::error::ApiError;
+
+
+#[derive(Debug)]
+struct ReceiverState {
+    auth: Option<(String, String)>,
+    events: mpsc::Sender<EventEnvelope>,
+}
+
+/// Accepts events with `POST` on the root of its router
+#[derive(Debug, Clone)]
+pub struct CallbackReceiver {
+    auth: Option<(String, String)>,
+    capacity: usize,
+}
+
+impl CallbackReceiver {
+
+    pub fn new() -> CallbackReceiver {
+        CallbackReceiver {
+            auth: None,
+            capacity: 64,
+        }
+    }
+
+    /// Only accepts events that carry `secret` in the header `auth_key`. That is how the connector authenticates for
+    /// a callback address with an `authKey`: it sends the secret that its vault stores under the `authCodeId`.
+    pub fn with_auth(mut self, auth_key: impl Into<String>, secret: impl Into<String>) -> Self {
+        self.auth = Some((auth_key.into(), secret.into()));
+        self
+    }
+
+    /// Number of events that are buffered before callbacks wait for the receiving end of the channel
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Builds the router and the receiving end of the events. Once the receiving end is dropped, callbacks are
+    /// answered with `503` so the connector retries them later.
+    pub fn router(self) -> (Router, mpsc::Receiver<EventEnvelope>) {
+        let (sender, receiver) = mpsc::channel(self.capacity);
+        let state = Arc::new(ReceiverState { auth: self.auth, events: sender });
+        let router = Router::new()
+            .route("/", post(receive_event))
+            .with_state(state);
+        (router, receiver)
+    }
+
+    /// Serves the receiver below `path` on an already bound listener in a background task
+    pub fn spawn(self, listener: tokio::net::TcpListener, path: &str) -> mpsc::Receiver<EventEnvelope> {
+        let (router, receiver) = self.router();
+        let app = if path.is_empty() || path == "/" {
+            router
+        } else {
+            Router::new().nest(path, router)
+        };
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        receiver
+    }
+
+}
+
+impl Default for CallbackReceiver {
+    fn default() -> Self {
+        CallbackReceiver::new()
+    }
+}
+
+async fn receive_event(State(state): State<Arc<ReceiverState>>, headers: HeaderMap, body: Bytes) -> Response {
+    if let Some((auth_key, secret)) = &state.auth {
+        let authorized = headers.get(auth_key.as_str()).is_some_and(|value| value.as_bytes() == secret.as_bytes());
+        if !authorized {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+    }
+
+    let envelope: EventEnvelope = match serde_json::from_slice(&body) {
+        Ok(envelope) => envelope,
+        Err(e) => return ApiError::BadRequest(format!("Invalid event: {}", e)).into_response(),
+    };
+
+    match state.events.send(envelope).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::api_models::{AssetOutput, Catalog, CatalogRequest, ContractDefinitionOutput, ContractOffer, DataService, Dataset, DatasetRequest};
+use crate::error::{require_body, ApiError};
+use crate::{default_context, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/catalog/request", post(request_catalog))
+        .route("/v2/catalog/dataset/request", post(get_dataset))
+}
+
+fn offers_asset(definition: &ContractDefinitionOutput, asset: &Value) -> bool {
+    definition.assets_selector.iter().flatten()
+        .all(|criterion| query::evaluate(asset, criterion).unwrap_or(false))
+}
+
+/// Builds the dcat dataset of an asset with one offer per contract definition that selects the asset
+pub(crate) fn dataset(store: &SharedStore, asset: &AssetOutput) -> Option<Dataset> {
+    let asset_id = asset.at_id.clone()?;
+    let asset_value = serde_json::to_value(asset).ok()?;
+
+    let offers: Vec<ContractOffer> = store.contract_definitions.all().iter()
+        .filter(|definition| offers_asset(definition, &asset_value))
+        .map(|definition| {
+            let definition_id = definition.at_id.clone().unwrap_or_default();
+            let permissions = definition.contract_policy_id.as_deref()
+                .and_then(|policy_id| store.policy_definitions.get(policy_id))
+                .and_then(|policy_definition| policy_definition.policy)
+                .and_then(|policy| policy.get("permission").cloned())
+                .map(|permission| match permission {
+                    Value::Array(permissions) => permissions,
+                    permission => vec![permission],
+                })
+                .unwrap_or_default();
+            ContractOffer {
+                at_id: format!("{}:{}:{}", definition_id, asset_id, uuid::Uuid::new_v4()),
+                assigner: Some(store.participant_id.clone()),
+                target: Some(asset_id.clone()),
+                permission: permissions,
+                ..ContractOffer::default()
+            }
+        })
+        .collect();
+
+    if offers.is_empty() {
+        return None;
+    }
+
+    let mut properties = asset.properties.clone().unwrap_or_default();
+    properties.entry("id".to_string()).or_insert_with(|| Value::String(asset_id.clone()));
+    Some(Dataset {
+        at_id: Some(asset_id),
+        has_policy: offers,
+        properties,
+        ..Dataset::default()
+    })
+}
+
+pub(crate) fn context() -> HashMap<String, Value> {
+    let mut context = default_context();
+    context.insert("dcat".to_string(), Value::String("http://www.w3.org/ns/dcat#".to_string()));
+    context.insert("dct".to_string(), Value::String("http://purl.org/dc/terms/".to_string()));
+    context.insert("dspace".to_string(), Value::String("https://w3id.org/dspace/v0.8/".to_string()));
+    context
+}
+
+/// Obtains the catalog of the counter party. The in-memory server is its own counter party, so the catalog contains the
+/// assets of this store that are selected by a contract definition.
+pub async fn request_catalog(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Catalog>, ApiError> {
+    let catalog_request: CatalogRequest = require_body(&body)?;
+
+    let assets = query::apply(store.assets.all(), catalog_request.query_spec.as_ref())?;
+    let datasets: Vec<Dataset> = assets.iter().filter_map(|asset| dataset(&store, asset)).collect();
+
+    let service = DataService {
+        at_id: Some(uuid::Uuid::new_v4().to_string()),
+        terms: Some("connector".to_string()),
+        endpoint_url: Some(catalog_request.counter_party_address),
+        ..DataService::default()
+    };
+    Ok(Json(Catalog {
+        context: context(),
+        at_id: Some(uuid::Uuid::new_v4().to_string()),
+        dataset: datasets,
+        service: vec![service],
+        participant_id: Some(store.participant_id.clone()),
+        ..Catalog::default()
+    }))
+}
+
+/// Gets a single dataset from the catalog of the counter party
+pub async fn get_dataset(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Dataset>, ApiError> {
+    let dataset_request: DatasetRequest = require_body(&body)?;
+    let id = dataset_request.at_id.ok_or_else(|| ApiError::BadRequest("@id is mandatory".to_string()))?;
+
+    let dataset = store.assets.get(&id)
+        .and_then(|asset| dataset(&store, &asset))
+        .ok_or_else(|| ApiError::not_found("Dataset", &id))?;
+    Ok(Json(Dataset { context: context(), ..dataset }))
+}
@@ -0,0 +1,36 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use std::net::SocketAddr;
+
+
+#[derive(Debug, Clone)]
+pub struct Configuration {
+    pub address: SocketAddr,
+    pub base_path: String,
+}
+
+impl Configuration {
+    pub fn new(address: SocketAddr, base_path: String) -> Configuration {
+        Configuration {
+            address,
+            base_path,
+        }
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            address: SocketAddr::from(([127, 0, 0, 1], 29193)),
+            base_path: "/management".to_owned(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{ContractAgreement, ContractNegotiation, QuerySpec};
+use crate::error::{parse_body, ApiError};
+use crate::{query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/contractagreements/request", post(query_all_agreements))
+        .route("/v2/contractagreements/:id", get(get_agreement_by_id))
+        .route("/v2/contractagreements/:id/negotiation", get(get_negotiation_by_agreement_id))
+}
+
+/// Gets an contract agreement with the given ID
+pub async fn get_agreement_by_id(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<ContractAgreement>, ApiError> {
+    store.contract_agreements.get(&id).map(Json).ok_or_else(|| ApiError::not_found("ContractAgreement", &id))
+}
+
+/// Gets a contract negotiation with the given contract agreement ID
+pub async fn get_negotiation_by_agreement_id(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<ContractNegotiation>, ApiError> {
+    store.contract_negotiations.find(|negotiation| negotiation.contract_agreement_id.as_deref() == Some(id.as_str()))
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found("ContractAgreement", &id))
+}
+
+/// Gets all contract agreements according to a particular query
+pub async fn query_all_agreements(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<ContractAgreement>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.contract_agreements.all(), query_spec.as_ref()).map(Json)
+}
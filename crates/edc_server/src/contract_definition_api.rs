@@ -0,0 +1,92 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{ContractDefinitionInput, ContractDefinitionOutput, IdResponse, QuerySpec};
+use crate::error::{parse_body, require_body, ApiError};
+use crate::{default_context, now_millis, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/contractdefinitions", post(create_contract_definition).put(update_contract_definition))
+        .route("/v2/contractdefinitions/request", post(query_all_contract_definitions))
+        .route("/v2/contractdefinitions/:id", get(get_contract_definition).delete(delete_contract_definition))
+}
+
+fn validate(contract_definition: &ContractDefinitionInput) -> Result<(), ApiError> {
+    if contract_definition.access_policy_id.is_empty() {
+        return Err(ApiError::BadRequest("accessPolicyId is mandatory".to_string()));
+    }
+    if contract_definition.contract_policy_id.is_empty() {
+        return Err(ApiError::BadRequest("contractPolicyId is mandatory".to_string()));
+    }
+    Ok(())
+}
+
+/// Creates a new contract definition
+pub async fn create_contract_definition(State(store): State<SharedStore>, body: Bytes) -> Result<Json<IdResponse>, ApiError> {
+    let contract_definition: ContractDefinitionInput = require_body(&body)?;
+    validate(&contract_definition)?;
+
+    let id = contract_definition.at_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let created_at = now_millis();
+    let output = ContractDefinitionOutput {
+        context: default_context(),
+        at_id: Some(id.clone()),
+        at_type: Some("ContractDefinition".to_string()),
+        access_policy_id: Some(contract_definition.access_policy_id),
+        assets_selector: Some(contract_definition.assets_selector),
+        contract_policy_id: Some(contract_definition.contract_policy_id),
+        created_at: Some(created_at),
+    };
+
+    if !store.contract_definitions.insert(output) {
+        return Err(ApiError::already_exists("ContractDefinition", &id));
+    }
+    Ok(Json(IdResponse::new(Some(id), Some(created_at))))
+}
+
+/// Removes a contract definition with the given ID if possible
+pub async fn delete_contract_definition(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    store.contract_definitions.remove(&id).ok_or_else(|| ApiError::not_found("ContractDefinition", &id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Gets an contract definition with the given ID
+pub async fn get_contract_definition(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<ContractDefinitionOutput>, ApiError> {
+    store.contract_definitions.get(&id).map(Json).ok_or_else(|| ApiError::not_found("ContractDefinition", &id))
+}
+
+/// Returns all contract definitions according to a query
+pub async fn query_all_contract_definitions(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<ContractDefinitionOutput>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.contract_definitions.all(), query_spec.as_ref()).map(Json)
+}
+
+/// Updated a contract definition with the given ID. The supplied JSON structure must be a valid JSON-LD object
+pub async fn update_contract_definition(State(store): State<SharedStore>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let contract_definition: ContractDefinitionInput = require_body(&body)?;
+    validate(&contract_definition)?;
+    let id = contract_definition.at_id.clone()
+        .ok_or_else(|| ApiError::BadRequest("@id is mandatory when updating a contract definition".to_string()))?;
+
+    store.contract_definitions.update(&id, |existing| {
+        existing.access_policy_id = Some(contract_definition.access_policy_id);
+        existing.assets_selector = Some(contract_definition.assets_selector);
+        existing.contract_policy_id = Some(contract_definition.contract_policy_id);
+    }).ok_or_else(|| ApiError::not_found("ContractDefinition", &id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
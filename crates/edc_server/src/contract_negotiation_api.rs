@@ -0,0 +1,154 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::contract_negotiation::EnumType;
+use crate::api_models::{ContractAgreement, ContractNegotiation, ContractNegotiationState, ContractRequest, IdResponse, NegotiationState, QuerySpec, TerminateNegotiationSchema};
+use crate::error::{parse_body, require_body, ApiError};
+use crate::{default_context, now_millis, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/contractnegotiations", post(initiate_contract_negotiation))
+        .route("/v2/contractnegotiations/request", post(query_negotiations))
+        .route("/v2/contractnegotiations/:id", get(get_negotiation))
+        .route("/v2/contractnegotiations/:id/agreement", get(get_agreement_for_negotiation))
+        .route("/v2/contractnegotiations/:id/state", get(get_negotiation_state))
+        .route("/v2/contractnegotiations/:id/terminate", post(terminate_negotiation))
+}
+
+/// Initiates a contract negotiation for a given offer and with the given counter part.
+/// The negotiation is carried out against the assets of this store. After the processing delay of the store it either finalizes
+/// with a contract agreement or terminates if the requested asset is unknown.
+pub async fn initiate_contract_negotiation(State(store): State<SharedStore>, body: Bytes) -> Result<Json<IdResponse>, ApiError> {
+    let contract_request: ContractRequest = require_body(&body)?;
+
+    let (asset_id, assigner, policy) = match (&contract_request.policy, &contract_request.offer) {
+        (Some(offer), _) => {
+            let policy = serde_json::to_value(offer).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+            (offer.target.clone(), Some(offer.assigner.clone()), Some(policy))
+        }
+        (None, Some(description)) => {
+            let asset_id = description.asset_id.clone()
+                .ok_or_else(|| ApiError::BadRequest("offer.assetId is mandatory".to_string()))?;
+            (asset_id, None, description.policy.clone())
+        }
+        (None, None) => return Err(ApiError::BadRequest("policy is mandatory".to_string())),
+    };
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = now_millis();
+    let negotiation = ContractNegotiation {
+        context: default_context(),
+        at_id: Some(id.clone()),
+        at_type: Some("ContractNegotiation".to_string()),
+        callback_addresses: contract_request.callback_addresses,
+        contract_agreement_id: None,
+        counter_party_address: Some(contract_request.counter_party_address),
+        counter_party_id: assigner.clone(),
+        error_detail: None,
+        protocol: Some(contract_request.protocol),
+        state: ContractNegotiationState::Requested,
+        r#type: Some(EnumType::Consumer),
+    };
+    store.contract_negotiations.insert(negotiation);
+
+    let negotiation_id = id.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(store.processing_delay).await;
+        conclude_negotiation(&store, &negotiation_id, asset_id, assigner, policy);
+    });
+
+    Ok(Json(IdResponse::new(Some(id), Some(created_at))))
+}
+
+/// Finalizes a requested negotiation with a new contract agreement, unless it was terminated in the meantime
+fn conclude_negotiation(store: &SharedStore, id: &str, asset_id: String, assigner: Option<String>, policy: Option<serde_json::Value>) {
+    if !store.assets.contains(&asset_id) {
+        store.contract_negotiations.try_update(id, |negotiation| {
+            if negotiation.state != ContractNegotiationState::Requested {
+                return Err(());
+            }
+            negotiation.state = ContractNegotiationState::Terminated;
+            negotiation.error_detail = Some(format!("Asset {} is not offered by the counter party", asset_id));
+            Ok(())
+        });
+        return;
+    }
+
+    let agreement_id = uuid::Uuid::new_v4().to_string();
+    let agreement = ContractAgreement {
+        context: default_context(),
+        at_id: Some(agreement_id.clone()),
+        at_type: Some("ContractAgreement".to_string()),
+        asset_id: Some(asset_id),
+        consumer_id: Some(store.participant_id.clone()),
+        contract_signing_date: Some(now_millis() / 1000),
+        policy,
+        provider_id: Some(assigner.unwrap_or_else(|| store.participant_id.clone())),
+    };
+    // The agreement is stored while the negotiation is locked, nobody sees a finalized negotiation without agreement
+    store.contract_negotiations.try_update(id, |negotiation| {
+        if negotiation.state != ContractNegotiationState::Requested {
+            return Err(());
+        }
+        store.contract_agreements.insert(agreement);
+        negotiation.contract_agreement_id = Some(agreement_id);
+        negotiation.state = ContractNegotiationState::Finalized;
+        Ok(())
+    });
+}
+
+/// Gets a contract negotiation with the given ID
+pub async fn get_negotiation(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<ContractNegotiation>, ApiError> {
+    store.contract_negotiations.get(&id).map(Json).ok_or_else(|| ApiError::not_found("ContractNegotiation", &id))
+}
+
+/// Gets a contract agreement for a contract negotiation with the given ID
+pub async fn get_agreement_for_negotiation(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<ContractAgreement>, ApiError> {
+    let negotiation = store.contract_negotiations.get(&id).ok_or_else(|| ApiError::not_found("ContractNegotiation", &id))?;
+    negotiation.contract_agreement_id
+        .and_then(|agreement_id| store.contract_agreements.get(&agreement_id))
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("ContractNegotiation {} has no contract agreement", id)))
+}
+
+/// Gets the state of a contract negotiation with the given ID
+pub async fn get_negotiation_state(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<NegotiationState>, ApiError> {
+    store.contract_negotiations.get(&id)
+        .map(|negotiation| Json(NegotiationState { state: negotiation.state }))
+        .ok_or_else(|| ApiError::not_found("ContractNegotiation", &id))
+}
+
+/// Returns all contract negotiations according to a query
+pub async fn query_negotiations(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<ContractNegotiation>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.contract_negotiations.all(), query_spec.as_ref()).map(Json)
+}
+
+/// Terminates the contract negotiation
+pub async fn terminate_negotiation(State(store): State<SharedStore>, Path(id): Path<String>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let terminate: Option<TerminateNegotiationSchema> = parse_body(&body)?;
+    store.contract_negotiations.try_update(&id, |negotiation| {
+        if matches!(negotiation.state, ContractNegotiationState::Finalized | ContractNegotiationState::Terminated) {
+            return Err(ApiError::Conflict(format!("ContractNegotiation {} cannot be terminated in state {:?}", id, negotiation.state)));
+        }
+        negotiation.state = ContractNegotiationState::Terminated;
+        negotiation.error_detail = terminate.and_then(|terminate| terminate.reason);
+        Ok(())
+    }).ok_or_else(|| ApiError::not_found("ContractNegotiation", &id))??;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,68 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{DataPlaneInstanceSchema, SelectionRequestSchema};
+use crate::error::{require_body, ApiError};
+use crate::{default_context, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/dataplanes", get(get_all).post(add_entry))
+        .route("/v2/dataplanes/select", post(find))
+}
+
+/// Adds one dataplane instance, an existing instance with the same ID is replaced
+pub async fn add_entry(State(store): State<SharedStore>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let mut dataplane: DataPlaneInstanceSchema = require_body(&body)?;
+    if dataplane.url.is_empty() {
+        return Err(ApiError::BadRequest("url is mandatory".to_string()));
+    }
+
+    let id = dataplane.at_id.get_or_insert_with(|| uuid::Uuid::new_v4().to_string()).clone();
+    dataplane.context = default_context();
+    dataplane.at_type = Some("DataPlaneInstance".to_string());
+    store.dataplanes.remove(&id);
+    store.dataplanes.insert(dataplane);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Finds the best fitting data plane instance for the given source, destination and transfer type. Responds with 204 if there is none.
+pub async fn find(State(store): State<SharedStore>, body: Bytes) -> Result<Response, ApiError> {
+    let selection: SelectionRequestSchema = require_body(&body)?;
+
+    let accepts = |allowed: &[String], address_type: Option<&str>| address_type.is_none_or(|t| allowed.iter().any(|a| a == t));
+    let found = store.dataplanes.find(|dataplane| {
+        accepts(&dataplane.allowed_source_types, selection.source.as_ref().and_then(|source| source.r#type.as_deref()))
+            && accepts(&dataplane.allowed_dest_types, selection.destination.as_ref().and_then(|destination| destination.r#type.as_deref()))
+            && match (&dataplane.allowed_transfer_types, selection.transfer_type.as_deref()) {
+                (Some(allowed), Some(transfer_type)) => allowed.iter().any(|a| a == transfer_type),
+                (None, Some(_)) => false,
+                (_, None) => true,
+            }
+    });
+
+    Ok(match found {
+        Some(dataplane) => Json(dataplane).into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    })
+}
+
+/// Returns a list of all currently registered data plane instances
+pub async fn get_all(State(store): State<SharedStore>) -> Json<Vec<DataPlaneInstanceSchema>> {
+    Json(store.dataplanes.all())
+}
@@ -0,0 +1,358 @@
+/*
+ * dataspace-protocol
+ *
+ * Messages of the Dataspace Protocol as used by the protocol endpoints of the Eclipse EDC.
+ * https://docs.internationaldataspaces.org/ids-knowledgebase/v/dataspace-protocol
+ * Version: 0.8
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::contract_negotiation::EnumType;
+use crate::api_models::dsp::{CatalogRequestMessage, ContractAgreementVerificationMessage, ContractNegotiation, ContractNegotiationEventMessage,
+                             ContractNegotiationTerminationMessage, ContractRequestMessage, NegotiationState, ProtocolError, TransferCompletionMessage,
+                             TransferProcess, TransferRequestMessage, TransferStartMessage, TransferState, TransferSuspensionMessage, TransferTerminationMessage};
+use crate::api_models::transfer_process::RHashType;
+use crate::api_models::transfer_state::TransferProcessState;
+use crate::api_models::{Catalog, ContractAgreement, ContractNegotiationState, DataAddress, DataService, Dataset};
+use crate::{catalog_api, default_context, now_millis, SharedStore};
+
+
+pub const DATASPACE_PROTOCOL: &str = "dataspace-protocol-http";
+
+/// Provider side of the dataspace protocol. Requests are answered from the same store as the management api, negotiations
+/// and transfer processes started over the protocol show up there as provider processes identified by their provider pid.
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/catalog/request", post(request_catalog))
+        .route("/catalog/datasets/:id", get(get_dataset))
+        .route("/negotiations/request", post(request_contract))
+        .route("/negotiations/:pid", get(get_negotiation))
+        .route("/negotiations/:pid/request", post(counter_request))
+        .route("/negotiations/:pid/events", post(negotiation_event))
+        .route("/negotiations/:pid/agreement/verification", post(verify_agreement))
+        .route("/negotiations/:pid/termination", post(terminate_negotiation))
+        .route("/transfers/request", post(request_transfer))
+        .route("/transfers/:pid", get(get_transfer_process))
+        .route("/transfers/:pid/start", post(start_transfer))
+        .route("/transfers/:pid/completion", post(complete_transfer))
+        .route("/transfers/:pid/suspension", post(suspend_transfer))
+        .route("/transfers/:pid/termination", post(terminate_transfer))
+}
+
+/// Errors of the protocol endpoints, rendered as `dspace:*Error` message
+#[derive(Debug, Clone, PartialEq)]
+pub struct DspApiError {
+    pub status: StatusCode,
+    pub error: Box<ProtocolError>,
+}
+
+impl DspApiError {
+
+    fn new(status: StatusCode, kind: &str, reason: String) -> DspApiError {
+        let mut error = ProtocolError::new(format!("dspace:{}", kind));
+        error.code = Some(status.as_u16().to_string());
+        error.reason = vec![serde_json::Value::String(reason)];
+        DspApiError { status, error: Box::new(error) }
+    }
+
+    fn with_pids(mut self, provider_pid: Option<&str>, consumer_pid: Option<&str>) -> DspApiError {
+        self.error.provider_pid = provider_pid.map(|pid| pid.to_string());
+        self.error.consumer_pid = consumer_pid.map(|pid| pid.to_string());
+        self
+    }
+
+}
+
+impl IntoResponse for DspApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.error)).into_response()
+    }
+}
+
+fn negotiation_error(status: StatusCode, reason: String) -> DspApiError {
+    DspApiError::new(status, "ContractNegotiationError", reason)
+}
+
+fn transfer_error(status: StatusCode, reason: String) -> DspApiError {
+    DspApiError::new(status, "TransferError", reason)
+}
+
+/// Deserializes a message, the keys of the protocol are used as is
+fn message<T: serde::de::DeserializeOwned>(body: &[u8], error: fn(StatusCode, String) -> DspApiError) -> Result<T, DspApiError> {
+    serde_json::from_slice(body).map_err(|e| error(StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+/// Returns the catalog of the assets of this store that are selected by a contract definition
+pub async fn request_catalog(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Catalog>, DspApiError> {
+    let _: CatalogRequestMessage = message(&body, |status, reason| DspApiError::new(status, "CatalogError", reason))?;
+
+    let datasets: Vec<Dataset> = store.assets.all().iter().filter_map(|asset| catalog_api::dataset(&store, asset)).collect();
+    Ok(Json(Catalog {
+        context: catalog_api::context(),
+        at_id: Some(uuid::Uuid::new_v4().to_string()),
+        dataset: datasets,
+        service: vec![DataService { at_id: Some(uuid::Uuid::new_v4().to_string()), terms: Some("connector".to_string()), ..DataService::default() }],
+        participant_id: Some(store.participant_id.clone()),
+        ..Catalog::default()
+    }))
+}
+
+/// Returns the dataset of the asset with the given id
+pub async fn get_dataset(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<Dataset>, DspApiError> {
+    let dataset = store.assets.get(&id)
+        .and_then(|asset| catalog_api::dataset(&store, &asset))
+        .ok_or_else(|| DspApiError::new(StatusCode::NOT_FOUND, "CatalogError", format!("Dataset {} was not found", id)))?;
+    Ok(Json(Dataset { context: catalog_api::context(), ..dataset }))
+}
+
+/// Looks up the negotiation and checks that the message belongs to it
+fn negotiation(store: &SharedStore, provider_pid: &str, consumer_pid: Option<&str>) -> Result<ContractNegotiation, DspApiError> {
+    let negotiation = store.dsp_negotiations.get(provider_pid)
+        .ok_or_else(|| negotiation_error(StatusCode::NOT_FOUND, format!("ContractNegotiation {} was not found", provider_pid)).with_pids(Some(provider_pid), consumer_pid))?;
+    if consumer_pid.is_some_and(|consumer_pid| consumer_pid != negotiation.consumer_pid) {
+        return Err(negotiation_error(StatusCode::BAD_REQUEST, "consumerPid does not match the negotiation".to_string()).with_pids(Some(provider_pid), consumer_pid));
+    }
+    Ok(negotiation)
+}
+
+/// Moves the negotiation into the target state if its current state is one of the allowed ones. The provider process of
+/// the management api follows along.
+fn transition_negotiation(store: &SharedStore, provider_pid: &str, consumer_pid: &str, allowed: &[NegotiationState], target: NegotiationState) -> Result<(), DspApiError> {
+    let negotiation = negotiation(store, provider_pid, Some(consumer_pid))?;
+    if !allowed.contains(&negotiation.state) {
+        return Err(negotiation_error(StatusCode::BAD_REQUEST, format!("ContractNegotiation {} cannot be moved from {:?} to {:?}", provider_pid, negotiation.state, target))
+            .with_pids(Some(provider_pid), Some(consumer_pid)));
+    }
+    set_negotiation_state(store, provider_pid, target);
+    Ok(())
+}
+
+fn set_negotiation_state(store: &SharedStore, provider_pid: &str, state: NegotiationState) {
+    store.dsp_negotiations.update(provider_pid, |negotiation| negotiation.state = state);
+    store.contract_negotiations.update(provider_pid, |negotiation| {
+        negotiation.state = match state {
+            NegotiationState::Requested => ContractNegotiationState::Requested,
+            NegotiationState::Offered => ContractNegotiationState::Offered,
+            NegotiationState::Accepted => ContractNegotiationState::Accepted,
+            NegotiationState::Agreed => ContractNegotiationState::Agreed,
+            NegotiationState::Verified => ContractNegotiationState::Verified,
+            NegotiationState::Finalized => ContractNegotiationState::Finalized,
+            NegotiationState::Terminated => ContractNegotiationState::Terminated,
+        };
+    });
+}
+
+/// Starts a negotiation for an offer of this store. After the processing delay the provider agrees to the offer.
+pub async fn request_contract(State(store): State<SharedStore>, body: Bytes) -> Result<(StatusCode, Json<ContractNegotiation>), DspApiError> {
+    let contract_request: ContractRequestMessage = message(&body, negotiation_error)?;
+    let consumer_pid = contract_request.consumer_pid.clone();
+
+    let asset_id = contract_request.offer.target.clone().unwrap_or_default();
+    let offered = store.assets.get(&asset_id).and_then(|asset| catalog_api::dataset(&store, &asset)).is_some();
+    if !offered {
+        return Err(negotiation_error(StatusCode::BAD_REQUEST, format!("Asset {} is not offered", asset_id)).with_pids(None, Some(&consumer_pid)));
+    }
+
+    let provider_pid = uuid::Uuid::new_v4().to_string();
+    let negotiation = ContractNegotiation::new(provider_pid.clone(), consumer_pid, NegotiationState::Requested);
+    store.dsp_negotiations.insert(negotiation.clone());
+    store.contract_negotiations.insert(crate::api_models::ContractNegotiation {
+        context: default_context(),
+        at_id: Some(provider_pid.clone()),
+        at_type: Some("ContractNegotiation".to_string()),
+        counter_party_address: Some(contract_request.callback_address),
+        protocol: Some(DATASPACE_PROTOCOL.to_string()),
+        state: ContractNegotiationState::Requested,
+        r#type: Some(EnumType::Provider),
+        ..crate::api_models::ContractNegotiation::default()
+    });
+
+    let policy = serde_json::to_value(&contract_request.offer).ok();
+    tokio::spawn(async move {
+        tokio::time::sleep(store.processing_delay).await;
+        agree(&store, &provider_pid, asset_id, policy);
+    });
+
+    Ok((StatusCode::CREATED, Json(negotiation)))
+}
+
+/// Agrees to a requested negotiation with a new contract agreement, unless it was terminated in the meantime
+fn agree(store: &SharedStore, provider_pid: &str, asset_id: String, policy: Option<serde_json::Value>) {
+    if store.dsp_negotiations.get(provider_pid).map(|negotiation| negotiation.state) != Some(NegotiationState::Requested) {
+        return;
+    }
+
+    let agreement_id = uuid::Uuid::new_v4().to_string();
+    store.contract_agreements.insert(ContractAgreement {
+        context: default_context(),
+        at_id: Some(agreement_id.clone()),
+        at_type: Some("ContractAgreement".to_string()),
+        asset_id: Some(asset_id),
+        consumer_id: None,
+        contract_signing_date: Some(now_millis() / 1000),
+        policy,
+        provider_id: Some(store.participant_id.clone()),
+    });
+    store.contract_negotiations.update(provider_pid, |negotiation| negotiation.contract_agreement_id = Some(agreement_id));
+    set_negotiation_state(store, provider_pid, NegotiationState::Agreed);
+}
+
+/// Returns the negotiation with the given provider pid
+pub async fn get_negotiation(State(store): State<SharedStore>, Path(pid): Path<String>) -> Result<Json<ContractNegotiation>, DspApiError> {
+    negotiation(&store, &pid, None).map(Json)
+}
+
+/// Counter requests are only accepted after an offer of the provider, which this store never makes
+pub async fn counter_request(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let contract_request: ContractRequestMessage = message(&body, negotiation_error)?;
+    transition_negotiation(&store, &pid, &contract_request.consumer_pid, &[NegotiationState::Offered], NegotiationState::Requested)?;
+    Ok(StatusCode::OK)
+}
+
+/// Accepts an offer of the provider. Consumers cannot finalize a negotiation.
+pub async fn negotiation_event(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let event: ContractNegotiationEventMessage = message(&body, negotiation_error)?;
+    transition_negotiation(&store, &pid, &event.consumer_pid, &[NegotiationState::Offered], NegotiationState::Accepted)?;
+    Ok(StatusCode::OK)
+}
+
+/// Verifies the agreement, after the processing delay the provider finalizes the negotiation
+pub async fn verify_agreement(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let verification: ContractAgreementVerificationMessage = message(&body, negotiation_error)?;
+    transition_negotiation(&store, &pid, &verification.consumer_pid, &[NegotiationState::Agreed], NegotiationState::Verified)?;
+
+    tokio::spawn(async move {
+        tokio::time::sleep(store.processing_delay).await;
+        if store.dsp_negotiations.get(&pid).map(|negotiation| negotiation.state) == Some(NegotiationState::Verified) {
+            set_negotiation_state(&store, &pid, NegotiationState::Finalized);
+        }
+    });
+    Ok(StatusCode::OK)
+}
+
+/// Terminates a negotiation that is not yet finalized
+pub async fn terminate_negotiation(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let termination: ContractNegotiationTerminationMessage = message(&body, negotiation_error)?;
+    let allowed = [NegotiationState::Requested, NegotiationState::Offered, NegotiationState::Accepted, NegotiationState::Agreed, NegotiationState::Verified];
+    transition_negotiation(&store, &pid, &termination.consumer_pid, &allowed, NegotiationState::Terminated)?;
+    Ok(StatusCode::OK)
+}
+
+/// Looks up the transfer process and checks that the message belongs to it
+fn transfer_process(store: &SharedStore, provider_pid: &str, consumer_pid: Option<&str>) -> Result<TransferProcess, DspApiError> {
+    let transfer_process = store.dsp_transfer_processes.get(provider_pid)
+        .ok_or_else(|| transfer_error(StatusCode::NOT_FOUND, format!("TransferProcess {} was not found", provider_pid)).with_pids(Some(provider_pid), consumer_pid))?;
+    if consumer_pid.is_some_and(|consumer_pid| consumer_pid != transfer_process.consumer_pid) {
+        return Err(transfer_error(StatusCode::BAD_REQUEST, "consumerPid does not match the transfer process".to_string()).with_pids(Some(provider_pid), consumer_pid));
+    }
+    Ok(transfer_process)
+}
+
+/// Moves the transfer process into the target state if its current state is one of the allowed ones. The provider process
+/// of the management api follows along.
+fn transition_transfer(store: &SharedStore, provider_pid: &str, consumer_pid: &str, allowed: &[TransferState], target: TransferState) -> Result<(), DspApiError> {
+    let transfer_process = transfer_process(store, provider_pid, Some(consumer_pid))?;
+    if !allowed.contains(&transfer_process.state) {
+        return Err(transfer_error(StatusCode::BAD_REQUEST, format!("TransferProcess {} cannot be moved from {:?} to {:?}", provider_pid, transfer_process.state, target))
+            .with_pids(Some(provider_pid), Some(consumer_pid)));
+    }
+    set_transfer_state(store, provider_pid, target);
+    Ok(())
+}
+
+fn set_transfer_state(store: &SharedStore, provider_pid: &str, state: TransferState) {
+    store.dsp_transfer_processes.update(provider_pid, |transfer_process| transfer_process.state = state);
+    store.transfer_processes.update(provider_pid, |transfer_process| {
+        transfer_process.state = Some(match state {
+            TransferState::Requested => TransferProcessState::Requested,
+            TransferState::Started => TransferProcessState::Started,
+            TransferState::Suspended => TransferProcessState::Suspended,
+            TransferState::Completed => TransferProcessState::Completed,
+            TransferState::Terminated => TransferProcessState::Terminated,
+        });
+        transfer_process.state_timestamp = Some(now_millis());
+    });
+}
+
+/// Requests a transfer for an agreement of this store. After the processing delay the provider starts the transfer.
+pub async fn request_transfer(State(store): State<SharedStore>, body: Bytes) -> Result<(StatusCode, Json<TransferProcess>), DspApiError> {
+    let transfer_request: TransferRequestMessage = message(&body, transfer_error)?;
+    let consumer_pid = transfer_request.consumer_pid.clone();
+
+    let agreement = store.contract_agreements.get(&transfer_request.agreement_id)
+        .ok_or_else(|| transfer_error(StatusCode::BAD_REQUEST, format!("Contract agreement {} was not found", transfer_request.agreement_id)).with_pids(None, Some(&consumer_pid)))?;
+
+    let provider_pid = uuid::Uuid::new_v4().to_string();
+    let transfer_process = TransferProcess::new(provider_pid.clone(), consumer_pid.clone(), TransferState::Requested);
+    store.dsp_transfer_processes.insert(transfer_process.clone());
+    store.transfer_processes.insert(crate::api_models::TransferProcess {
+        context: default_context(),
+        at_type: Some("TransferProcess".to_string()),
+        at_id: Some(provider_pid.clone()),
+        correlation_id: Some(consumer_pid),
+        callback_addresses: Vec::new(),
+        asset_id: agreement.asset_id,
+        contract_agreement_id: agreement.at_id,
+        counter_party_address: Some(transfer_request.callback_address),
+        counter_party_id: None,
+        data_destination: transfer_request.data_address.map(|data_address| Box::new(DataAddress::new(None, Some(data_address.endpoint_type), data_address.endpoint))),
+        error_detail: None,
+        private_properties: None,
+        protocol: Some(DATASPACE_PROTOCOL.to_string()),
+        state: Some(TransferProcessState::Requested),
+        state_timestamp: Some(now_millis()),
+        transfer_type: transfer_request.format,
+        r#type: Some(RHashType::Provider),
+    });
+
+    tokio::spawn(async move {
+        tokio::time::sleep(store.processing_delay).await;
+        if store.dsp_transfer_processes.get(&provider_pid).map(|transfer_process| transfer_process.state) == Some(TransferState::Requested) {
+            set_transfer_state(&store, &provider_pid, TransferState::Started);
+        }
+    });
+
+    Ok((StatusCode::CREATED, Json(transfer_process)))
+}
+
+/// Returns the transfer process with the given provider pid
+pub async fn get_transfer_process(State(store): State<SharedStore>, Path(pid): Path<String>) -> Result<Json<TransferProcess>, DspApiError> {
+    transfer_process(&store, &pid, None).map(Json)
+}
+
+/// Resumes a suspended transfer process
+pub async fn start_transfer(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let start: TransferStartMessage = message(&body, transfer_error)?;
+    transition_transfer(&store, &pid, &start.consumer_pid, &[TransferState::Suspended], TransferState::Started)?;
+    Ok(StatusCode::OK)
+}
+
+/// Completes a started transfer process
+pub async fn complete_transfer(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let completion: TransferCompletionMessage = message(&body, transfer_error)?;
+    transition_transfer(&store, &pid, &completion.consumer_pid, &[TransferState::Started], TransferState::Completed)?;
+    Ok(StatusCode::OK)
+}
+
+/// Suspends a started transfer process
+pub async fn suspend_transfer(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let suspension: TransferSuspensionMessage = message(&body, transfer_error)?;
+    transition_transfer(&store, &pid, &suspension.consumer_pid, &[TransferState::Started], TransferState::Suspended)?;
+    Ok(StatusCode::OK)
+}
+
+/// Terminates a transfer process that is not yet completed
+pub async fn terminate_transfer(State(store): State<SharedStore>, Path(pid): Path<String>, body: Bytes) -> Result<StatusCode, DspApiError> {
+    let termination: TransferTerminationMessage = message(&body, transfer_error)?;
+    let allowed = [TransferState::Requested, TransferState::Started, TransferState::Suspended];
+    transition_transfer(&store, &pid, &termination.consumer_pid, &allowed, TransferState::Terminated)?;
+    Ok(StatusCode::OK)
+}
@@ -0,0 +1,54 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{DataAddress, EndpointDataReferenceEntry, QuerySpec};
+use crate::error::{parse_body, ApiError};
+use crate::{query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v1/edrs/request", post(query_edrs))
+        .route("/v1/edrs/:transfer_process_id", delete(delete_edr))
+        .route("/v1/edrs/:transfer_process_id/dataaddress", get(get_edr_data_address))
+}
+
+/// Request all Edr entries according to a particular query. The query is evaluated against the transfer processes the entries belong to.
+pub async fn query_edrs(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<EndpointDataReferenceEntry>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    let transfer_processes = store.transfer_processes.all().into_iter()
+        .filter(|transfer_process| transfer_process.at_id.as_deref().is_some_and(|id| store.edrs.contains(id)))
+        .collect();
+
+    let entries = query::apply(transfer_processes, query_spec.as_ref())?.into_iter()
+        .filter_map(|transfer_process| store.edrs.get(transfer_process.at_id.as_deref()?))
+        .collect();
+    Ok(Json(entries))
+}
+
+/// Removes an EDR entry given the transfer process ID
+pub async fn delete_edr(State(store): State<SharedStore>, Path(transfer_process_id): Path<String>) -> Result<StatusCode, ApiError> {
+    store.edrs.remove(&transfer_process_id).ok_or_else(|| ApiError::not_found("EndpointDataReferenceEntry", &transfer_process_id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Gets the EDR data address with the given transfer process ID
+pub async fn get_edr_data_address(State(store): State<SharedStore>, Path(transfer_process_id): Path<String>) -> Result<Json<DataAddress>, ApiError> {
+    if !store.edrs.contains(&transfer_process_id) {
+        return Err(ApiError::not_found("EndpointDataReferenceEntry", &transfer_process_id));
+    }
+    Ok(Json(DataAddress::new(Some("DataAddress".to_string()), Some("https://w3id.org/idsa/v4.1/HTTP".to_string()), Some(store.public_endpoint.clone()))))
+}
@@ -0,0 +1,69 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+use crate::api_models::ApiErrorDetail;
+
+
+/// Errors returned by the management api, rendered as a list of [`ApiErrorDetail`] like the EDC does
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    BadRequest(String),
+    NotFound(String),
+    Conflict(String),
+}
+
+impl ApiError {
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+        }
+    }
+
+    pub fn details(&self) -> Vec<ApiErrorDetail> {
+        let (r#type, message) = match self {
+            ApiError::BadRequest(message) => ("InvalidRequest", message),
+            ApiError::NotFound(message) => ("ObjectNotFound", message),
+            ApiError::Conflict(message) => ("ObjectConflict", message),
+        };
+        vec![ApiErrorDetail {
+            invalid_value: None,
+            message: Some(message.clone()),
+            path: None,
+            r#type: Some(r#type.to_string()),
+        }]
+    }
+
+    pub fn not_found(kind: &str, id: &str) -> ApiError {
+        ApiError::NotFound(format!("Object of type {} with ID={} was not found", kind, id))
+    }
+
+    pub fn already_exists(kind: &str, id: &str) -> ApiError {
+        ApiError::Conflict(format!("Object of type {} with ID={} already exists", kind, id))
+    }
+
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status(), Json(self.details())).into_response()
+    }
+}
+
+/// Deserializes a request body, mapping malformed json to a 400 instead of axum's default rejection.
+/// Namespace prefixes of the EDC and ODRL vocabularies are removed from all keys beforehand.
+pub(crate) fn parse_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<Option<T>, ApiError> {
+    if body.iter().all(|b| b.is_ascii_whitespace()) {
+        return Ok(None);
+    }
+    let value: serde_json::Value = serde_json::from_slice(body).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    serde_json::from_value::<Option<T>>(crate::query::remove_prefixes(value)).map_err(|e| ApiError::BadRequest(e.to_string()))
+}
+
+/// Like [`parse_body`], but a missing body is an error as well
+pub(crate) fn require_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, ApiError> {
+    parse_body(body)?.ok_or_else(|| ApiError::BadRequest("Request body was missing".to_string()))
+}
@@ -0,0 +1,90 @@
+extern crate edc_api as api_models;
+
+pub mod asset_api;
+pub mod callbacks;
+pub mod catalog_api;
+pub mod configuration;
+pub mod contract_agreement_api;
+pub mod contract_definition_api;
+pub mod contract_negotiation_api;
+pub mod dataplane_selector_api;
+pub mod dsp_api;
+pub mod edr_cache_api;
+pub mod error;
+pub mod mock;
+pub mod policy_definition_api;
+pub mod query;
+pub mod store;
+pub mod transfer_process_api;
+
+pub use configuration::Configuration;
+pub use error::ApiError;
+pub use store::InMemoryStore;
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::Router;
+
+pub const EDC_NS: &str = "https://w3id.org/edc/v0.0.1/ns/";
+pub const ODRL_NS: &str = "http://www.w3.org/ns/odrl/2/";
+
+pub type SharedStore = Arc<InMemoryStore>;
+
+/// Builds the management api router for the given store. Routes are relative to the root, nest the router to serve it below a base path.
+pub fn router(store: SharedStore) -> Router {
+    Router::new()
+        .merge(asset_api::routes())
+        .merge(catalog_api::routes())
+        .merge(contract_agreement_api::routes())
+        .merge(contract_definition_api::routes())
+        .merge(contract_negotiation_api::routes())
+        .merge(dataplane_selector_api::routes())
+        .merge(edr_cache_api::routes())
+        .merge(policy_definition_api::routes())
+        .merge(transfer_process_api::routes())
+        .with_state(store)
+}
+
+/// Serves the management api on an already bound listener until the future is dropped
+pub async fn serve(listener: tokio::net::TcpListener, base_path: &str, store: SharedStore) -> std::io::Result<()> {
+    let app = if base_path.is_empty() || base_path == "/" {
+        router(store)
+    } else {
+        Router::new().nest(base_path, router(store))
+    };
+    axum::serve(listener, app).await
+}
+
+/// Builds the dataspace protocol router for the given store, the counterpart of [`router`] that counter parties talk to
+pub fn protocol_router(store: SharedStore) -> Router {
+    dsp_api::routes().with_state(store)
+}
+
+/// Serves the dataspace protocol endpoints on an already bound listener until the future is dropped
+pub async fn serve_protocol(listener: tokio::net::TcpListener, base_path: &str, store: SharedStore) -> std::io::Result<()> {
+    let app = if base_path.is_empty() || base_path == "/" {
+        protocol_router(store)
+    } else {
+        Router::new().nest(base_path, protocol_router(store))
+    };
+    axum::serve(listener, app).await
+}
+
+/// Binds the address of the configuration and serves the management api with an empty in-memory store
+pub async fn run(configuration: Configuration) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(configuration.address).await?;
+    serve(listener, &configuration.base_path, Arc::new(InMemoryStore::new())).await
+}
+
+pub(crate) fn default_context() -> std::collections::HashMap<String, serde_json::Value> {
+    std::collections::HashMap::from([
+        ("@vocab".to_string(), serde_json::Value::String(EDC_NS.to_string())),
+        ("edc".to_string(), serde_json::Value::String(EDC_NS.to_string())),
+        ("odrl".to_string(), serde_json::Value::String(ODRL_NS.to_string())),
+    ])
+}
+
+pub(crate) fn now_millis() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or_default()
+}
@@ -0,0 +1,304 @@
+//! Mock of the management api for tests of clients. [`MockServer`] serves an [`InMemoryStore`] like [`crate::serve`]
+//! does, records every request, and answers requests with scripted responses or injected errors instead of the store
+//! where a rule says so, e.g.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use edc_server::mock::{Method, MockResponse, MockServer};
+//!
+//! let server = MockServer::start().await?;
+//! server.respond(Method::GET, "/v3/assets/*", MockResponse::not_found("No such asset").times(1));
+//!
+//! // Point the client at `server.url()`, the first asset request fails with a 404 and the next ones reach the store
+//!
+//! let requests = server.requests();
+//! assert_eq!("/v3/assets/asset-1", requests[0].path);
+//! # Ok(())
+//! # }
+//! ```
+
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::body::{Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+
+use crate::api_models::ApiErrorDetail;
+use crate::error::ApiError;
+use crate::{InMemoryStore, SharedStore};
+
+pub use axum::http::{Method, StatusCode};
+
+
+/// Request bodies larger than this are rejected, management requests are far smaller
+const BODY_LIMIT: usize = 16 * 1024 * 1024;
+
+/// A request as the mock received it
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    /// Path below the base path, e.g. `/v3/assets/request`
+    pub path: String,
+    pub query: Option<String>,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl RecordedRequest {
+
+    /// The body as JSON, `None` if it is empty or no JSON
+    pub fn json(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+}
+
+/// Response of a rule. The error constructors render their message as list of [`ApiErrorDetail`] like the EDC does.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    body: Bytes,
+    delay: Option<Duration>,
+    times: Option<usize>,
+}
+
+impl MockResponse {
+
+    /// Response without body
+    pub fn new(status: StatusCode) -> MockResponse {
+        MockResponse {
+            status,
+            headers: Vec::new(),
+            body: Bytes::new(),
+            delay: None,
+            times: None,
+        }
+    }
+
+    pub fn json<T: serde::Serialize>(status: StatusCode, body: &T) -> MockResponse {
+        let body = serde_json::to_vec(body).expect("mock response body can be serialized");
+        MockResponse::new(status)
+            .with_header(axum::http::header::CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .with_body(body)
+    }
+
+    /// Error response with one detail of the given type and message
+    pub fn error(status: StatusCode, r#type: &str, message: &str) -> MockResponse {
+        let details = vec![ApiErrorDetail {
+            invalid_value: None,
+            message: Some(message.to_string()),
+            path: None,
+            r#type: Some(r#type.to_string()),
+        }];
+        MockResponse::json(status, &details)
+    }
+
+    pub fn bad_request(message: &str) -> MockResponse {
+        ApiError::BadRequest(message.to_string()).into()
+    }
+
+    pub fn not_found(message: &str) -> MockResponse {
+        ApiError::NotFound(message.to_string()).into()
+    }
+
+    pub fn conflict(message: &str) -> MockResponse {
+        ApiError::Conflict(message.to_string()).into()
+    }
+
+    pub fn server_error(message: &str) -> MockResponse {
+        MockResponse::error(StatusCode::INTERNAL_SERVER_ERROR, "InternalError", message)
+    }
+
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Waits before answering, e.g. to run into the timeout of a client
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Answers only the next `times` matching requests, the requests after them reach the store again
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    fn to_response(&self) -> Response {
+        let mut response = (self.status, self.body.clone()).into_response();
+        for (name, value) in &self.headers {
+            response.headers_mut().insert(name.clone(), value.clone());
+        }
+        response
+    }
+
+}
+
+impl From<ApiError> for MockResponse {
+    fn from(e: ApiError) -> Self {
+        MockResponse::json(e.status(), &e.details())
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    method: Method,
+    path: String,
+    response: MockResponse,
+}
+
+impl Rule {
+
+    /// `*` in the path of the rule matches exactly one segment
+    fn matches(&self, method: &Method, path: &str) -> bool {
+        let pattern = self.path.trim_end_matches('/').split('/');
+        let segments = path.trim_end_matches('/').split('/');
+        self.method == method
+            && pattern.clone().count() == segments.clone().count()
+            && pattern.zip(segments).all(|(expected, segment)| expected == "*" || expected == segment)
+    }
+
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    base_path: String,
+    rules: Mutex<Vec<Rule>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+/// Management api on a random local port that is served until the mock is dropped
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    store: SharedStore,
+    state: Arc<MockState>,
+    server: tokio::task::JoinHandle<std::io::Result<()>>,
+}
+
+impl MockServer {
+
+    /// Serves an empty store below `/management`
+    pub async fn start() -> std::io::Result<MockServer> {
+        MockServer::with_store(Arc::new(InMemoryStore::new()), "/management").await
+    }
+
+    /// Serves the given store below `base_path`, e.g. to seed it with assets beforehand
+    pub async fn with_store(store: SharedStore, base_path: &str) -> std::io::Result<MockServer> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let base_path = base_path.trim_end_matches('/').to_string();
+        let state = Arc::new(MockState { base_path: base_path.clone(), ..MockState::default() });
+
+        let app = if base_path.is_empty() {
+            crate::router(store.clone())
+        } else {
+            Router::new().nest(&base_path, crate::router(store.clone()))
+        };
+        let app = app.layer(middleware::from_fn_with_state(state.clone(), intercept));
+        let server = tokio::spawn(async move { axum::serve(listener, app).await });
+
+        Ok(MockServer { address, store, state, server })
+    }
+
+    /// Base url of the management api, e.g. `http://127.0.0.1:41234/management`
+    pub fn url(&self) -> String {
+        format!("http://{}{}", self.address, self.state.base_path)
+    }
+
+    pub fn store(&self) -> &SharedStore {
+        &self.store
+    }
+
+    /// Answers requests with the method and path with the response instead of the store. Rules are matched in the
+    /// order they were added, a rule whose [`MockResponse::times`] are used up no longer matches.
+    pub fn respond(&self, method: Method, path: &str, response: MockResponse) {
+        let rule = Rule { method, path: path.to_string(), response };
+        self.state.rules.lock().unwrap().push(rule);
+    }
+
+    /// All requests in the order they were received, including those the store answered
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Requests with the method and path, `*` matches one segment like it does for rules
+    pub fn requests_to(&self, method: Method, path: &str) -> Vec<RecordedRequest> {
+        let rule = Rule { method, path: path.to_string(), response: MockResponse::new(StatusCode::OK) };
+        self.state.requests.lock().unwrap().iter()
+            .filter(|request| rule.matches(&request.method, &request.path))
+            .cloned()
+            .collect()
+    }
+
+    /// Removes all rules and recorded requests, the store is kept
+    pub fn reset(&self) {
+        self.state.rules.lock().unwrap().clear();
+        self.state.requests.lock().unwrap().clear();
+    }
+
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Records the request and answers it with the first matching rule, or passes it on to the store
+async fn intercept(State(state): State<Arc<MockState>>, request: Request, next: Next) -> Response {
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, BODY_LIMIT).await {
+        Ok(body) => body,
+        Err(e) => return ApiError::BadRequest(format!("Could not read the request body: {}", e)).into_response(),
+    };
+    let path = parts.uri.path().strip_prefix(state.base_path.as_str()).unwrap_or(parts.uri.path());
+    let path = if path.is_empty() { "/" } else { path }.to_string();
+
+    state.requests.lock().unwrap().push(RecordedRequest {
+        method: parts.method.clone(),
+        path: path.clone(),
+        query: parts.uri.query().map(str::to_string),
+        headers: parts.headers.clone(),
+        body: body.clone(),
+    });
+
+    let scripted = {
+        let mut rules = state.rules.lock().unwrap();
+        let rule = rules.iter_mut().find(|rule| rule.response.times != Some(0) && rule.matches(&parts.method, &path));
+        rule.map(|rule| {
+            if let Some(times) = &mut rule.response.times {
+                *times -= 1;
+            }
+            rule.response.clone()
+        })
+    };
+
+    match scripted {
+        Some(response) => {
+            if let Some(delay) = response.delay {
+                tokio::time::sleep(delay).await;
+            }
+            response.to_response()
+        },
+        None => next.run(Request::from_parts(parts, Body::from(body))).await,
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::{IdResponse, PolicyDefinitionInput, PolicyDefinitionOutput, QuerySpec};
+use crate::error::{parse_body, require_body, ApiError};
+use crate::{default_context, now_millis, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/policydefinitions", post(create_policy_definition))
+        .route("/v2/policydefinitions/request", post(query_policy_definitions))
+        .route("/v2/policydefinitions/:id", get(get_policy_definition).put(update_policy_definition).delete(delete_policy_definition))
+}
+
+fn validate(policy_definition: &PolicyDefinitionInput) -> Result<(), ApiError> {
+    if !policy_definition.policy.is_object() {
+        return Err(ApiError::BadRequest("policy must be a JSON object".to_string()));
+    }
+    Ok(())
+}
+
+/// Creates a new policy definition
+pub async fn create_policy_definition(State(store): State<SharedStore>, body: Bytes) -> Result<Json<IdResponse>, ApiError> {
+    let policy_definition: PolicyDefinitionInput = require_body(&body)?;
+    validate(&policy_definition)?;
+
+    let id = policy_definition.at_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let output = PolicyDefinitionOutput::new(default_context(), Some(id.clone()), Some("PolicyDefinition".to_string()), Some(policy_definition.policy));
+
+    if !store.policy_definitions.insert(output) {
+        return Err(ApiError::already_exists("PolicyDefinition", &id));
+    }
+    Ok(Json(IdResponse::new(Some(id), Some(now_millis()))))
+}
+
+/// Removes a policy definition with the given ID if possible. Deleting a policy definition is only possible if that policy definition is not yet referenced by a contract definition.
+pub async fn delete_policy_definition(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    if !store.policy_definitions.contains(&id) {
+        return Err(ApiError::not_found("PolicyDefinition", &id));
+    }
+    let referenced = store.contract_definitions.any(|definition| {
+        definition.access_policy_id.as_deref() == Some(id.as_str()) || definition.contract_policy_id.as_deref() == Some(id.as_str())
+    });
+    if referenced {
+        return Err(ApiError::Conflict(format!("PolicyDefinition {} cannot be deleted as it is referenced by at least one contract definition", id)));
+    }
+    store.policy_definitions.remove(&id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Gets a policy definition with the given ID
+pub async fn get_policy_definition(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<PolicyDefinitionOutput>, ApiError> {
+    store.policy_definitions.get(&id).map(Json).ok_or_else(|| ApiError::not_found("PolicyDefinition", &id))
+}
+
+/// Returns all policy definitions according to a query
+pub async fn query_policy_definitions(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<PolicyDefinitionOutput>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.policy_definitions.all(), query_spec.as_ref()).map(Json)
+}
+
+/// Updates an existing Policy, If the Policy is not found, an error is reported
+pub async fn update_policy_definition(State(store): State<SharedStore>, Path(id): Path<String>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let policy_definition: PolicyDefinitionInput = require_body(&body)?;
+    validate(&policy_definition)?;
+
+    store.policy_definitions.update(&id, |existing| existing.policy = Some(policy_definition.policy))
+        .ok_or_else(|| ApiError::not_found("PolicyDefinition", &id))?;
+    Ok(StatusCode::NO_CONTENT)
+}
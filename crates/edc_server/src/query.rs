@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api_models::query::PropertyPath;
+use crate::api_models::query_spec::SortOrder;
+use crate::api_models::{Criterion, CriterionOperator, QuerySpec};
+use crate::error::ApiError;
+use crate::{EDC_NS, ODRL_NS};
+
+
+const DEFAULT_LIMIT: usize = 50;
+
+/// Removes the `edc:`/`odrl:` prefixes and the expanded EDC and ODRL namespaces from all keys of the value
+pub fn remove_prefixes(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map.into_iter()
+            .map(|(key, v)| (strip_namespace(&key).to_string(), remove_prefixes(v)))
+            .collect()),
+        Value::Array(arr) => Value::Array(arr.into_iter().map(remove_prefixes).collect()),
+        other => other,
+    }
+}
+
+fn strip_namespace(key: &str) -> &str {
+    key.trim_start_matches(EDC_NS).trim_start_matches(ODRL_NS).trim_start_matches("edc:").trim_start_matches("odrl:")
+}
+
+/// Filters, sorts and pages the items according to the query spec like the EDC does for its `/request` endpoints
+pub fn apply<T: Serialize>(items: Vec<T>, query_spec: Option<&QuerySpec>) -> Result<Vec<T>, ApiError> {
+    let default_spec = QuerySpec {
+        at_context: None,
+        at_type: None,
+        filter_expression: vec![],
+        limit: None,
+        offset: None,
+        sort_field: None,
+        sort_order: None,
+    };
+    let spec = query_spec.unwrap_or(&default_spec);
+
+    let offset = usize::try_from(spec.offset.unwrap_or(0))
+        .map_err(|_| ApiError::BadRequest("offset must be greater than or equal to 0".to_string()))?;
+    let limit = match spec.limit {
+        Some(limit) if limit <= 0 => return Err(ApiError::BadRequest("limit must be greater than 0".to_string())),
+        Some(limit) => limit as usize,
+        None => DEFAULT_LIMIT,
+    };
+    // Unsupported operators are rejected even if there is nothing to filter
+    for criterion in &spec.filter_expression {
+        if let CriterionOperator::Other(operator) = &criterion.operator {
+            return Err(unsupported_operator(operator));
+        }
+    }
+
+    let mut selected = Vec::new();
+    for item in items {
+        let value = serde_json::to_value(&item).map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        let mut matches = true;
+        for criterion in &spec.filter_expression {
+            if !evaluate(&value, criterion)? {
+                matches = false;
+                break;
+            }
+        }
+        if matches {
+            selected.push((value, item));
+        }
+    }
+
+    if let Some(sort_field) = spec.sort_field.as_deref() {
+        let sort_field = PropertyPath::parse(sort_field);
+        selected.sort_by(|(a, _), (b, _)| {
+            let ordering = compare_optional(lookup(a, &sort_field), lookup(b, &sort_field));
+            match spec.sort_order.unwrap_or_default() {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+    }
+
+    Ok(selected.into_iter().skip(offset).take(limit).map(|(_, item)| item).collect())
+}
+
+/// Evaluates a single criterion against the json representation of an object
+pub fn evaluate(value: &Value, criterion: &Criterion) -> Result<bool, ApiError> {
+    let path = criterion.operand_left.as_str()
+        .ok_or_else(|| ApiError::BadRequest(format!("operandLeft must be a string, got {}", criterion.operand_left)))?;
+    let operator = &criterion.operator;
+    let right = &criterion.operand_right;
+    let left = match (lookup(value, &PropertyPath::parse(path)), operator) {
+        (_, CriterionOperator::Other(operator)) => return Err(unsupported_operator(operator)),
+        (Some(left), _) => left,
+        (None, _) => return Ok(*operator == CriterionOperator::Ne),
+    };
+
+    match operator {
+        CriterionOperator::Eq => Ok(loose_eq(left, right)),
+        CriterionOperator::Ne => Ok(!loose_eq(left, right)),
+        CriterionOperator::In => match right {
+            Value::Array(candidates) => Ok(candidates.iter().any(|candidate| loose_eq(left, candidate))),
+            _ => Err(ApiError::BadRequest("operandRight of the 'in' operator must be a list".to_string())),
+        },
+        CriterionOperator::Like => Ok(like(left, right, false)),
+        CriterionOperator::ILike => Ok(like(left, right, true)),
+        CriterionOperator::Lt => Ok(compare(left, right) == Some(Ordering::Less)),
+        CriterionOperator::Le => Ok(matches!(compare(left, right), Some(Ordering::Less | Ordering::Equal))),
+        CriterionOperator::Gt => Ok(compare(left, right) == Some(Ordering::Greater)),
+        CriterionOperator::Ge => Ok(matches!(compare(left, right), Some(Ordering::Greater | Ordering::Equal))),
+        CriterionOperator::Contains => match left {
+            Value::Array(elements) => Ok(elements.iter().any(|element| loose_eq(element, right))),
+            Value::String(text) => Ok(right.as_str().map(|needle| text.contains(needle)).unwrap_or(false)),
+            _ => Ok(false),
+        },
+        CriterionOperator::Other(operator) => Err(unsupported_operator(operator)),
+    }
+}
+
+fn unsupported_operator(operator: &str) -> ApiError {
+    ApiError::BadRequest(format!("Operator '{operator}' is not supported"))
+}
+
+/// Resolves a property path with the namespaces of the segments removed. `id` refers to the `@id` of the object and
+/// unknown top level names are looked up in the `properties` of the object, so asset properties can be queried without
+/// a prefix.
+fn lookup<'a>(value: &'a Value, path: &PropertyPath) -> Option<&'a Value> {
+    let mut current = value;
+    for (index, segment) in path.segments().iter().enumerate() {
+        let segment = match strip_namespace(segment) {
+            "id" if index == 0 => "@id",
+            segment => segment,
+        };
+        current = match current.get(segment) {
+            Some(next) => next,
+            None if index == 0 => current.get("properties")?.get(segment)?,
+            None => return None,
+        };
+    }
+    Some(current)
+}
+
+fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        _ => None,
+    }
+}
+
+fn loose_eq(left: &Value, right: &Value) -> bool {
+    if let (Some(l), Some(r)) = (left.as_f64(), right.as_f64()) {
+        return l == r;
+    }
+    match (as_text(left), as_text(right)) {
+        (Some(l), Some(r)) => l == r,
+        _ => left == right,
+    }
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    if let (Some(l), Some(r)) = (left.as_f64(), right.as_f64()) {
+        return l.partial_cmp(&r);
+    }
+    let l = as_text(left)?;
+    let r = as_text(right)?;
+    match (l.parse::<f64>(), r.parse::<f64>()) {
+        (Ok(l), Ok(r)) => l.partial_cmp(&r),
+        _ => Some(l.cmp(&r)),
+    }
+}
+
+fn compare_optional(left: Option<&Value>, right: Option<&Value>) -> Ordering {
+    match (left, right) {
+        (Some(l), Some(r)) => compare(l, r).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// SQL like matching, `%` matches any sequence and `_` any single character
+fn like(left: &Value, right: &Value, case_insensitive: bool) -> bool {
+    let (Some(mut text), Some(mut pattern)) = (as_text(left), as_text(right)) else {
+        return false;
+    };
+    if case_insensitive {
+        text = text.to_lowercase();
+        pattern = pattern.to_lowercase();
+    }
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    // matched[j] is true if the first i characters of the text match the first j characters of the pattern
+    let mut matched = vec![false; pattern.len() + 1];
+    matched[0] = true;
+    for j in 1..=pattern.len() {
+        matched[j] = matched[j - 1] && pattern[j - 1] == '%';
+    }
+    for c in text {
+        let mut next = vec![false; pattern.len() + 1];
+        for j in 1..=pattern.len() {
+            next[j] = match pattern[j - 1] {
+                '%' => next[j - 1] || matched[j],
+                '_' => matched[j - 1],
+                p => matched[j - 1] && p == c,
+            };
+        }
+        matched = next;
+    }
+    matched[pattern.len()]
+}
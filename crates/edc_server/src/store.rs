@@ -0,0 +1,209 @@
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::Duration;
+
+use crate::api_models::{dsp, AssetOutput, ContractAgreement, ContractDefinitionOutput, ContractNegotiation, DataPlaneInstanceSchema, EndpointDataReferenceEntry, PolicyDefinitionOutput, TransferProcess};
+
+
+/// Objects that can be kept in a [`Collection`], identified by their `@id`
+pub trait Entity: Clone {
+    fn id(&self) -> Option<&str>;
+}
+
+impl Entity for AssetOutput {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for PolicyDefinitionOutput {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for ContractDefinitionOutput {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for ContractNegotiation {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for ContractAgreement {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for TransferProcess {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for EndpointDataReferenceEntry {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for DataPlaneInstanceSchema {
+    fn id(&self) -> Option<&str> {
+        self.at_id.as_deref()
+    }
+}
+
+impl Entity for dsp::ContractNegotiation {
+    fn id(&self) -> Option<&str> {
+        Some(&self.provider_pid)
+    }
+}
+
+impl Entity for dsp::TransferProcess {
+    fn id(&self) -> Option<&str> {
+        Some(&self.provider_pid)
+    }
+}
+
+/// Insertion ordered list of entities behind a lock
+#[derive(Debug)]
+pub struct Collection<T: Entity> {
+    items: RwLock<Vec<T>>,
+}
+
+impl<T: Entity> Default for Collection<T> {
+    fn default() -> Self {
+        Collection { items: RwLock::new(Vec::new()) }
+    }
+}
+
+impl<T: Entity> Collection<T> {
+
+    fn read(&self) -> RwLockReadGuard<'_, Vec<T>> {
+        self.items.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Vec<T>> {
+        self.items.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn get(&self, id: &str) -> Option<T> {
+        self.read().iter().find(|item| item.id() == Some(id)).cloned()
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.read().iter().any(|item| item.id() == Some(id))
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<T> {
+        self.read().iter().find(|item| predicate(item)).cloned()
+    }
+
+    pub fn any(&self, predicate: impl Fn(&T) -> bool) -> bool {
+        self.read().iter().any(predicate)
+    }
+
+    pub fn all(&self) -> Vec<T> {
+        self.read().clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Adds the item unless an item with the same id exists. Returns whether the item was added.
+    pub fn insert(&self, item: T) -> bool {
+        let mut items = self.write();
+        if item.id().is_some() && items.iter().any(|existing| existing.id() == item.id()) {
+            return false;
+        }
+        items.push(item);
+        true
+    }
+
+    /// Applies the update to the item with the given id and returns the updated item
+    pub fn update(&self, id: &str, update: impl FnOnce(&mut T)) -> Option<T> {
+        let mut items = self.write();
+        let item = items.iter_mut().find(|item| item.id() == Some(id))?;
+        update(item);
+        Some(item.clone())
+    }
+
+    /// Like [`Collection::update`], but the update may reject the item, e.g. because of its state. The check and the
+    /// write happen under the same lock, so no other update can change the item in between.
+    pub fn try_update<E>(&self, id: &str, update: impl FnOnce(&mut T) -> Result<(), E>) -> Option<Result<T, E>> {
+        let mut items = self.write();
+        let item = items.iter_mut().find(|item| item.id() == Some(id))?;
+        Some(update(item).map(|()| item.clone()))
+    }
+
+    pub fn remove(&self, id: &str) -> Option<T> {
+        let mut items = self.write();
+        let position = items.iter().position(|item| item.id() == Some(id))?;
+        Some(items.remove(position))
+    }
+
+}
+
+/// State of the management api. All objects live in memory only and are lost when the store is dropped.
+#[derive(Debug)]
+pub struct InMemoryStore {
+    pub participant_id: String,
+    /// Time after which requested negotiations and transfer processes reach their final state
+    pub processing_delay: Duration,
+    /// Endpoint handed out in the data address of endpoint data references
+    pub public_endpoint: String,
+    pub assets: Collection<AssetOutput>,
+    pub policy_definitions: Collection<PolicyDefinitionOutput>,
+    pub contract_definitions: Collection<ContractDefinitionOutput>,
+    pub contract_negotiations: Collection<ContractNegotiation>,
+    pub contract_agreements: Collection<ContractAgreement>,
+    pub transfer_processes: Collection<TransferProcess>,
+    pub dataplanes: Collection<DataPlaneInstanceSchema>,
+    /// Endpoint data references of started pull transfers, identified by the id of their transfer process
+    pub edrs: Collection<EndpointDataReferenceEntry>,
+    /// Negotiations requested over the dataspace protocol, identified by their provider pid
+    pub dsp_negotiations: Collection<dsp::ContractNegotiation>,
+    /// Transfer processes requested over the dataspace protocol, identified by their provider pid
+    pub dsp_transfer_processes: Collection<dsp::TransferProcess>,
+}
+
+impl InMemoryStore {
+
+    pub fn new() -> InMemoryStore {
+        InMemoryStore::with_participant_id("provider")
+    }
+
+    pub fn with_participant_id(participant_id: &str) -> InMemoryStore {
+        InMemoryStore {
+            participant_id: participant_id.to_string(),
+            processing_delay: Duration::from_millis(250),
+            public_endpoint: "http://localhost:19291/public".to_string(),
+            assets: Collection::default(),
+            policy_definitions: Collection::default(),
+            contract_definitions: Collection::default(),
+            contract_negotiations: Collection::default(),
+            contract_agreements: Collection::default(),
+            transfer_processes: Collection::default(),
+            dataplanes: Collection::default(),
+            edrs: Collection::default(),
+            dsp_negotiations: Collection::default(),
+            dsp_transfer_processes: Collection::default(),
+        }
+    }
+
+}
+
+impl Default for InMemoryStore {
+    fn default() -> Self {
+        InMemoryStore::new()
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * management-api
+ *
+ * REST API documentation for the Eclipse EDC management-api.
+ * https://app.swaggerhub.com/apis/eclipse-edc-bot/management-api/
+ * Version: 0.7.0
+ *
+ */
+
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::api_models::transfer_process::RHashType;
+use crate::api_models::transfer_state::TransferProcessState;
+use crate::api_models::{EndpointDataReferenceEntry, IdResponse, QuerySpec, SuspendTransfer, TerminateTransfer, TransferProcess, TransferRequest, TransferState};
+use crate::error::{parse_body, require_body, ApiError};
+use crate::{default_context, now_millis, query, SharedStore};
+
+
+pub fn routes() -> Router<SharedStore> {
+    Router::new()
+        .route("/v2/transferprocesses", post(initiate_transfer_process))
+        .route("/v2/transferprocesses/request", post(query_transfer_processes))
+        .route("/v2/transferprocesses/:id", get(get_transfer_process))
+        .route("/v2/transferprocesses/:id/state", get(get_transfer_process_state))
+        .route("/v2/transferprocesses/:id/deprovision", post(deprovision_transfer_process))
+        .route("/v2/transferprocesses/:id/resume", post(resume_transfer_process))
+        .route("/v2/transferprocesses/:id/suspend", post(suspend_transfer_process))
+        .route("/v2/transferprocesses/:id/terminate", post(terminate_transfer_process))
+}
+
+/// Moves the transfer process into the target state if its current state is one of the allowed ones
+fn transition(store: &SharedStore, id: &str, allowed: &[TransferProcessState], target: TransferProcessState, error_detail: Option<String>) -> Result<StatusCode, ApiError> {
+    store.transfer_processes.try_update(id, |transfer_process| {
+        let state = transfer_process.state.unwrap_or(TransferProcessState::Initial);
+        if !allowed.contains(&state) {
+            return Err(ApiError::Conflict(format!("TransferProcess {} cannot be moved from {:?} to {:?}", id, state, target)));
+        }
+        transfer_process.state = Some(target);
+        transfer_process.state_timestamp = Some(now_millis());
+        if error_detail.is_some() {
+            transfer_process.error_detail = error_detail;
+        }
+        Ok(())
+    }).ok_or_else(|| ApiError::not_found("TransferProcess", id))??;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Initiates a data transfer with the given parameters. After the processing delay of the store the transfer is started if the
+/// contract agreement exists in this store and covers the requested asset, otherwise it is terminated.
+pub async fn initiate_transfer_process(State(store): State<SharedStore>, body: Bytes) -> Result<Json<IdResponse>, ApiError> {
+    let transfer_request: TransferRequest = require_body(&body)?;
+    if transfer_request.transfer_type.is_empty() {
+        return Err(ApiError::BadRequest("transferType is mandatory".to_string()));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = now_millis();
+    let transfer_process = TransferProcess {
+        context: default_context(),
+        at_type: Some("TransferProcess".to_string()),
+        at_id: Some(id.clone()),
+        correlation_id: None,
+        callback_addresses: transfer_request.callback_addresses.unwrap_or_default(),
+        asset_id: Some(transfer_request.asset_id),
+        contract_agreement_id: Some(transfer_request.contract_id),
+        counter_party_address: Some(transfer_request.counter_party_address),
+        counter_party_id: None,
+        data_destination: Some(transfer_request.data_destination),
+        error_detail: None,
+        private_properties: transfer_request.private_properties,
+        protocol: Some(transfer_request.protocol),
+        state: Some(TransferProcessState::Requested),
+        state_timestamp: Some(created_at),
+        transfer_type: Some(transfer_request.transfer_type),
+        r#type: Some(RHashType::Consumer),
+    };
+    store.transfer_processes.insert(transfer_process);
+
+    let transfer_process_id = id.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(store.processing_delay).await;
+        start_transfer_process(&store, &transfer_process_id);
+    });
+
+    Ok(Json(IdResponse::new(Some(id), Some(created_at))))
+}
+
+/// Starts a requested transfer process if its contract agreement covers the asset, unless it was terminated in the meantime
+fn start_transfer_process(store: &SharedStore, id: &str) {
+    let Some(transfer_process) = store.transfer_processes.get(id) else {
+        return;
+    };
+
+    let contract_id = transfer_process.contract_agreement_id.unwrap_or_default();
+    let asset_id = transfer_process.asset_id.unwrap_or_default();
+    let (state, error_detail) = match store.contract_agreements.get(&contract_id) {
+        Some(agreement) if agreement.asset_id.as_deref() == Some(asset_id.as_str()) => (TransferProcessState::Started, None),
+        Some(_) => (TransferProcessState::Terminated, Some(format!("Contract agreement {} does not cover asset {}", contract_id, asset_id))),
+        None => (TransferProcessState::Terminated, Some(format!("Contract agreement {} was not found", contract_id))),
+    };
+    let updated = store.transfer_processes.try_update(id, |transfer_process| {
+        if transfer_process.state != Some(TransferProcessState::Requested) {
+            return Err(());
+        }
+        transfer_process.state = Some(state);
+        transfer_process.state_timestamp = Some(now_millis());
+        transfer_process.error_detail = error_detail;
+        Ok(())
+    });
+    if !matches!(updated, Some(Ok(_))) {
+        return;
+    }
+
+    let pull = transfer_process.transfer_type.is_some_and(|transfer_type| transfer_type.ends_with("-PULL"));
+    if state == TransferProcessState::Started && pull {
+        store.edrs.insert(EndpointDataReferenceEntry::new(default_context(), Some("EndpointDataReferenceEntry".to_string()), Some(id.to_string())));
+    }
+}
+
+/// Gets an transfer process with the given ID
+pub async fn get_transfer_process(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<TransferProcess>, ApiError> {
+    store.transfer_processes.get(&id).map(Json).ok_or_else(|| ApiError::not_found("TransferProcess", &id))
+}
+
+/// Gets the state of a transfer process with the given ID
+pub async fn get_transfer_process_state(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<Json<TransferState>, ApiError> {
+    store.transfer_processes.get(&id)
+        .map(|transfer_process| Json(TransferState { state: transfer_process.state.unwrap_or(TransferProcessState::Initial) }))
+        .ok_or_else(|| ApiError::not_found("TransferProcess", &id))
+}
+
+/// Returns all transfer process according to a query
+pub async fn query_transfer_processes(State(store): State<SharedStore>, body: Bytes) -> Result<Json<Vec<TransferProcess>>, ApiError> {
+    let query_spec: Option<QuerySpec> = parse_body(&body)?;
+    query::apply(store.transfer_processes.all(), query_spec.as_ref()).map(Json)
+}
+
+/// Requests the deprovisioning of resources associated with a transfer process. Only completed or terminated transfer processes can be deprovisioned.
+pub async fn deprovision_transfer_process(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    transition(&store, &id, &[TransferProcessState::Completed, TransferProcessState::Terminated], TransferProcessState::Deprovisioned, None)
+}
+
+/// Requests the resumption of a suspended transfer process
+pub async fn resume_transfer_process(State(store): State<SharedStore>, Path(id): Path<String>) -> Result<StatusCode, ApiError> {
+    transition(&store, &id, &[TransferProcessState::Suspended], TransferProcessState::Started, None)
+}
+
+/// Requests the suspension of a started transfer process
+pub async fn suspend_transfer_process(State(store): State<SharedStore>, Path(id): Path<String>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let suspend: Option<SuspendTransfer> = parse_body(&body)?;
+    transition(&store, &id, &[TransferProcessState::Started, TransferProcessState::Resumed], TransferProcessState::Suspended,
+               suspend.and_then(|suspend| suspend.reason))
+}
+
+/// Requests the termination of a transfer process that is not yet completed or terminated
+pub async fn terminate_transfer_process(State(store): State<SharedStore>, Path(id): Path<String>, body: Bytes) -> Result<StatusCode, ApiError> {
+    let terminate: Option<TerminateTransfer> = parse_body(&body)?;
+    let allowed = [
+        TransferProcessState::Initial,
+        TransferProcessState::Provisioning,
+        TransferProcessState::ProvisioningRequested,
+        TransferProcessState::Provisioned,
+        TransferProcessState::Requesting,
+        TransferProcessState::Requested,
+        TransferProcessState::Starting,
+        TransferProcessState::Started,
+        TransferProcessState::Suspending,
+        TransferProcessState::Suspended,
+        TransferProcessState::Resuming,
+        TransferProcessState::Resumed,
+    ];
+    transition(&store, &id, &allowed, TransferProcessState::Terminated, terminate.and_then(|terminate| terminate.reason))
+}
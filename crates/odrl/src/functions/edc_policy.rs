@@ -0,0 +1,613 @@
+//! Conversion between the ODRL policies of the EDC management api and the ODRL model.
+//!
+//! The connector accepts and returns policies as JSON-LD, either compacted with the ODRL context (`"permission"`),
+//! with the `odrl:` prefix (`"odrl:permission"`) or with full IRIs. [`from_edc`] reads all of these forms, [`to_edc`]
+//! writes the compacted form, e.g.
+//!
+//! ```no_run
+//! use edc_api::PolicyDefinitionInput;
+//! use odrl::model::policy::SetPolicy;
+//!
+//! let policy_definition = PolicyDefinitionInput::from(SetPolicy::default());
+//! ```
+
+
+use std::fmt;
+
+use edc_api::{ContractAgreement, ContractOffer, PolicyDefinitionInput, PolicyDefinitionOutput};
+use serde_json::{Map, Value};
+
+use crate::model::action::{Action, Refinements};
+use crate::model::asset::Asset;
+use crate::model::conflict_term::ConflictTerm;
+use crate::model::constraint::{Constraint, LeftOperand, Operator, RightOperand};
+use crate::model::party::{Function, Party};
+use crate::model::policy::{AgreementPolicy, OfferPolicy, Policy, SetPolicy};
+use crate::model::rule::{Duty, Obligation, Permission, Prohibition, Rule};
+use crate::model::type_alias::IRI;
+use crate::name_spaces::{EDC_NS, ODRL_NS};
+
+
+/// Context of the policies written by [`to_edc`]
+pub const ODRL_CONTEXT: &str = "http://www.w3.org/ns/odrl.jsonld";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyConversionError {
+    /// The policy is not a json object
+    NotAnObject,
+    /// The policy has an `@type` that is neither `Set`, `Offer` nor `Agreement`
+    UnsupportedType(String),
+    UnsupportedOperator(String),
+    /// Logical constraints other than `and` can't be represented in the model
+    UnsupportedConstraint(String),
+    /// The contract agreement has no policy
+    MissingPolicy,
+}
+
+impl fmt::Display for PolicyConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyConversionError::NotAnObject => write!(f, "policy is not a json object"),
+            PolicyConversionError::UnsupportedType(r#type) => write!(f, "unsupported policy type {}", r#type),
+            PolicyConversionError::UnsupportedOperator(operator) => write!(f, "unsupported operator {}", operator),
+            PolicyConversionError::UnsupportedConstraint(constraint) => write!(f, "unsupported logical constraint {}", constraint),
+            PolicyConversionError::MissingPolicy => write!(f, "contract agreement has no policy"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyConversionError {}
+
+
+/// Reads a policy as it is sent to or returned by the connector
+pub fn from_edc(policy: &Value) -> Result<Policy, PolicyConversionError> {
+    let parts = PolicyParts::parse(policy)?;
+    match parts.r#type.as_deref() {
+        None | Some("Set") | Some("Policy") => Ok(Policy::SetPolicy(parts.into_set())),
+        Some("Offer") => Ok(Policy::OfferPolicy(parts.into_offer())),
+        Some("Agreement") => Ok(Policy::AgreementPolicy(parts.into_agreement())),
+        Some(other) => Err(PolicyConversionError::UnsupportedType(other.to_string())),
+    }
+}
+
+/// Writes a policy in the compacted form that the connector accepts, e.g. as the `policy` of a policy definition
+pub fn to_edc(policy: &Policy) -> Value {
+    let (r#type, uid, assigner, assignee, rules, profiles, inherit_from, conflict, obligation) = match policy {
+        Policy::SetPolicy(p) => ("Set", &p.uid, None, None, &p.rules, &p.profiles, &p.inherit_from, &p.conflict, &p.obligation),
+        Policy::OfferPolicy(p) => ("Offer", &p.uid, Some(&p.assigner), None, &p.rules, &p.profiles, &p.inherit_from, &p.conflict, &p.obligation),
+        Policy::AgreementPolicy(p) => ("Agreement", &p.uid, Some(&p.assigner), Some(&p.assignee), &p.rules, &p.profiles, &p.inherit_from, &p.conflict, &p.obligation),
+    };
+
+    let mut map = Map::new();
+    map.insert("@context".to_string(), Value::from(ODRL_CONTEXT));
+    map.insert("@type".to_string(), Value::from(r#type));
+    if !uid.is_empty() {
+        map.insert("@id".to_string(), Value::from(uid.as_str()));
+    }
+    insert_party(&mut map, "assigner", assigner);
+    insert_party(&mut map, "assignee", assignee);
+
+    let mut permissions = vec![];
+    let mut prohibitions = vec![];
+    let mut obligations = vec![];
+    for rule in rules.iter().chain(obligation) {
+        match rule {
+            Rule::Permission(permission) => permissions.push(write_permission(permission)),
+            Rule::Prohibition(prohibition) => prohibitions.push(write_prohibition(prohibition)),
+            Rule::Duty(duty) => obligations.push(write_duty(duty)),
+            Rule::Obligation(obligation) => obligations.push(write_obligation(obligation)),
+        }
+    }
+    // The connector expects the rule arrays even if they are empty
+    map.insert("permission".to_string(), Value::Array(permissions));
+    map.insert("prohibition".to_string(), Value::Array(prohibitions));
+    map.insert("obligation".to_string(), Value::Array(obligations));
+
+    if !profiles.is_empty() {
+        map.insert("profile".to_string(), Value::from(profiles.clone()));
+    }
+    if !inherit_from.is_empty() {
+        map.insert("inheritFrom".to_string(), Value::from(inherit_from.clone()));
+    }
+    if let Some(conflict) = conflict {
+        map.insert("conflict".to_string(), serde_json::to_value(conflict).unwrap_or(Value::Null));
+    }
+
+    Value::Object(map)
+}
+
+/// Policy definition with the id `id` for `policy`
+pub fn policy_definition(id: impl Into<String>, policy: impl Into<Policy>) -> PolicyDefinitionInput {
+    let mut policy_definition = PolicyDefinitionInput::from(policy.into());
+    policy_definition.at_id = Some(id.into());
+    policy_definition
+}
+
+/// Reads the policy of a contract agreement. The parties and the target fall back to those of the agreement if the
+/// policy itself does not name them.
+pub fn agreement_policy(agreement: &ContractAgreement) -> Result<AgreementPolicy, PolicyConversionError> {
+    let policy = agreement.policy.as_ref().ok_or(PolicyConversionError::MissingPolicy)?;
+    let mut parts = PolicyParts::parse_with_target(policy, agreement.asset_id.as_deref())?;
+    if parts.uid.is_empty() {
+        parts.uid = agreement.at_id.clone().unwrap_or_default();
+    }
+    if parts.assigner.is_none() {
+        parts.assigner = agreement.provider_id.as_ref().map(|uid| party(uid.clone(), Function::Assigner));
+    }
+    if parts.assignee.is_none() {
+        parts.assignee = agreement.consumer_id.as_ref().map(|uid| party(uid.clone(), Function::Assignee));
+    }
+    Ok(parts.into_agreement())
+}
+
+
+/// Reads an offer of a catalog. The rules of the offer target its asset and are assigned by its assigner unless they
+/// name others themselves.
+pub fn offer_policy(offer: &ContractOffer) -> Result<OfferPolicy, PolicyConversionError> {
+    let mut policy = Map::new();
+    policy.insert("permission".to_string(), Value::from(offer.permission.clone()));
+    policy.insert("prohibition".to_string(), Value::from(offer.prohibition.clone()));
+    policy.insert("obligation".to_string(), Value::from(offer.obligation.clone()));
+    if let Some(assigner) = &offer.assigner {
+        policy.insert("assigner".to_string(), Value::from(assigner.as_str()));
+    }
+    let mut parts = PolicyParts::parse_with_target(&Value::Object(policy), offer.target.as_deref())?;
+    parts.uid = offer.at_id.clone();
+    Ok(parts.into_offer())
+}
+
+impl TryFrom<&ContractOffer> for OfferPolicy {
+    type Error = PolicyConversionError;
+
+    fn try_from(offer: &ContractOffer) -> Result<Self, Self::Error> {
+        offer_policy(offer)
+    }
+}
+
+/// Typed access to the policy of policy definitions
+pub trait PolicyDefinitionExt {
+
+    fn odrl_policy(&self) -> Result<Policy, PolicyConversionError>;
+
+}
+
+impl PolicyDefinitionExt for PolicyDefinitionInput {
+    fn odrl_policy(&self) -> Result<Policy, PolicyConversionError> {
+        from_edc(&self.policy)
+    }
+}
+
+impl PolicyDefinitionExt for PolicyDefinitionOutput {
+    fn odrl_policy(&self) -> Result<Policy, PolicyConversionError> {
+        from_edc(self.policy.as_ref().unwrap_or(&Value::Null))
+    }
+}
+
+/// Typed access to the policy of contract agreements, see [`agreement_policy`]
+pub trait ContractAgreementExt {
+
+    fn agreement_policy(&self) -> Result<AgreementPolicy, PolicyConversionError>;
+
+}
+
+impl ContractAgreementExt for ContractAgreement {
+    fn agreement_policy(&self) -> Result<AgreementPolicy, PolicyConversionError> {
+        agreement_policy(self)
+    }
+}
+
+/// Uses the uid of the policy as id of the policy definition, the connector generates one if the uid is empty
+impl From<Policy> for PolicyDefinitionInput {
+    fn from(policy: Policy) -> Self {
+        let uid = match &policy {
+            Policy::SetPolicy(p) => &p.uid,
+            Policy::OfferPolicy(p) => &p.uid,
+            Policy::AgreementPolicy(p) => &p.uid,
+        };
+        PolicyDefinitionInput {
+            context: std::collections::HashMap::from([("@vocab".to_string(), Value::from(EDC_NS))]),
+            at_id: if uid.is_empty() { None } else { Some(uid.clone()) },
+            at_type: Some("PolicyDefinition".to_string()),
+            policy: to_edc(&policy),
+        }
+    }
+}
+
+impl From<SetPolicy> for PolicyDefinitionInput {
+    fn from(policy: SetPolicy) -> Self {
+        PolicyDefinitionInput::from(Policy::SetPolicy(policy))
+    }
+}
+
+impl From<OfferPolicy> for PolicyDefinitionInput {
+    fn from(policy: OfferPolicy) -> Self {
+        PolicyDefinitionInput::from(Policy::OfferPolicy(policy))
+    }
+}
+
+
+/// Fields of a policy before it is known which kind of policy it is
+#[derive(Default)]
+struct PolicyParts {
+    r#type: Option<String>,
+    uid: IRI,
+    assigner: Option<Party>,
+    assignee: Option<Party>,
+    rules: Vec<Rule>,
+    profiles: Vec<IRI>,
+    inherit_from: Vec<IRI>,
+    conflict: Option<ConflictTerm>,
+}
+
+impl PolicyParts {
+
+    fn parse(policy: &Value) -> Result<PolicyParts, PolicyConversionError> {
+        PolicyParts::parse_with_target(policy, None)
+    }
+
+    fn parse_with_target(policy: &Value, default_target: Option<&str>) -> Result<PolicyParts, PolicyConversionError> {
+        let policy = compact(policy);
+        let policy = single(&policy);
+        let object = policy.as_object().ok_or(PolicyConversionError::NotAnObject)?;
+
+        // Agreements of the connector name the parties and the target on the policy instead of the rules
+        let target = object.get("target").and_then(id_of).or_else(|| default_target.map(str::to_string));
+        let mut context = RuleContext {
+            target,
+            assigner: object.get("assigner").and_then(id_of),
+            assignee: object.get("assignee").and_then(id_of),
+        };
+
+        let mut rules = vec![];
+        for permission in list(object.get("permission")) {
+            rules.push(Rule::Permission(parse_permission(permission, &context)?));
+        }
+        for prohibition in list(object.get("prohibition")) {
+            rules.push(Rule::Prohibition(parse_prohibition(prohibition, &context)?));
+        }
+        for obligation in list(object.get("obligation")) {
+            rules.push(Rule::Obligation(parse_obligation(obligation, &context)?));
+        }
+
+        // Offers without parties on the policy name them on their rules
+        if context.assigner.is_none() {
+            context.assigner = rules.iter().find_map(|rule| rule_party(rule, Function::Assigner));
+        }
+        if context.assignee.is_none() {
+            context.assignee = rules.iter().find_map(|rule| rule_party(rule, Function::Assignee));
+        }
+
+        Ok(PolicyParts {
+            r#type: object.get("@type").and_then(id_of).map(|t| term(&t)),
+            uid: object.get("@id").or_else(|| object.get("uid")).and_then(id_of).unwrap_or_default(),
+            assigner: context.assigner.map(|uid| party(uid, Function::Assigner)),
+            assignee: context.assignee.map(|uid| party(uid, Function::Assignee)),
+            rules,
+            profiles: list(object.get("profile")).into_iter().filter_map(id_of).collect(),
+            inherit_from: list(object.get("inheritFrom")).into_iter().filter_map(id_of).collect(),
+            conflict: object.get("conflict").and_then(id_of).and_then(|c| serde_json::from_value(Value::from(term(&c))).ok()),
+        })
+    }
+
+    fn into_set(self) -> SetPolicy {
+        SetPolicy::new(self.uid, self.rules, self.profiles, self.inherit_from, self.conflict, vec![])
+    }
+
+    fn into_offer(self) -> OfferPolicy {
+        let assigner = self.assigner.unwrap_or_else(|| Party::new(None, vec![], Function::Assigner, None));
+        OfferPolicy::new(self.uid, assigner, self.rules, self.profiles, self.inherit_from, self.conflict, vec![])
+    }
+
+    fn into_agreement(self) -> AgreementPolicy {
+        let assigner = self.assigner.unwrap_or_else(|| Party::new(None, vec![], Function::Assigner, None));
+        let assignee = self.assignee.unwrap_or_else(|| Party::new(None, vec![], Function::Assignee, None));
+        AgreementPolicy::new(self.uid, assigner, assignee, self.rules, self.profiles, self.inherit_from, self.conflict, vec![])
+    }
+
+}
+
+/// Defaults of the rules that are given on the policy
+struct RuleContext {
+    target: Option<String>,
+    assigner: Option<String>,
+    assignee: Option<String>,
+}
+
+struct RuleParts {
+    uid: Option<IRI>,
+    action: Action,
+    constraints: Vec<Constraint>,
+    target: Option<Asset>,
+    assigner: Option<Party>,
+    assignee: Option<Party>,
+}
+
+fn parse_rule(rule: &Value, context: &RuleContext) -> Result<RuleParts, PolicyConversionError> {
+    let rule = single(rule);
+    let target = rule.get("target").and_then(id_of).or_else(|| context.target.clone());
+    let assigner = rule.get("assigner").and_then(id_of).or_else(|| context.assigner.clone());
+    let assignee = rule.get("assignee").and_then(id_of).or_else(|| context.assignee.clone());
+
+    Ok(RuleParts {
+        uid: rule.get("@id").or_else(|| rule.get("uid")).and_then(id_of),
+        action: parse_action(rule.get("action"))?,
+        constraints: parse_constraints(rule.get("constraint"))?,
+        target: target.map(asset),
+        assigner: assigner.map(|uid| party(uid, Function::Assigner)),
+        assignee: assignee.map(|uid| party(uid, Function::Assignee)),
+    })
+}
+
+fn parse_permission(permission: &Value, context: &RuleContext) -> Result<Permission, PolicyConversionError> {
+    let parts = parse_rule(permission, context)?;
+    let duties = parse_duties(single(permission).get("duty"))?;
+    Ok(Permission::new(parts.uid, parts.action, None, vec![], vec![], parts.constraints, parts.target.unwrap_or_default(), parts.assigner, parts.assignee, duties))
+}
+
+fn parse_prohibition(prohibition: &Value, context: &RuleContext) -> Result<Prohibition, PolicyConversionError> {
+    let parts = parse_rule(prohibition, context)?;
+    let remedies = parse_duties(single(prohibition).get("remedy"))?;
+    Ok(Prohibition::new(parts.uid, parts.action, None, vec![], vec![], parts.constraints, parts.target.unwrap_or_default(), parts.assigner, parts.assignee, remedies))
+}
+
+fn parse_obligation(obligation: &Value, context: &RuleContext) -> Result<Obligation, PolicyConversionError> {
+    let parts = parse_rule(obligation, context)?;
+    let consequences = parse_duties(single(obligation).get("consequence"))?;
+    let assigner = parts.assigner.unwrap_or_else(|| Party::new(None, vec![], Function::Assigner, None));
+    let assignee = parts.assignee.unwrap_or_else(|| Party::new(None, vec![], Function::Assignee, None));
+    Ok(Obligation::new(parts.uid, parts.target.unwrap_or_default(), assigner, assignee, parts.action, consequences))
+}
+
+/// Duties only have a target or parties if they name them themselves
+fn parse_duties(duties: Option<&Value>) -> Result<Vec<Duty>, PolicyConversionError> {
+    let context = RuleContext { target: None, assigner: None, assignee: None };
+    list(duties).into_iter().map(|duty| {
+        let parts = parse_rule(duty, &context)?;
+        let consequences = parse_duties(single(duty).get("consequence"))?;
+        Ok(Duty::new(parts.uid, parts.action, None, vec![], vec![], parts.constraints, parts.target, parts.assigner, parts.assignee, consequences, None))
+    }).collect()
+}
+
+/// Actions are either a plain name or an object with the name in `@id`, `type` or `rdf:value`
+fn parse_action(action: Option<&Value>) -> Result<Action, PolicyConversionError> {
+    let action = match action {
+        Some(action) => single(action),
+        None => return Ok(Action::new("use", None, None, vec![])),
+    };
+    let name = id_of(action)
+        .or_else(|| action.get("rdf:value").and_then(id_of))
+        .map(|name| term(&name))
+        .unwrap_or_else(|| "use".to_string());
+    let refinements = match action.get("refinement") {
+        Some(refinement) => Some(Refinements::Constraints(parse_constraints(Some(refinement))?)),
+        None => None,
+    };
+    Ok(Action::new(&name, refinements, None, vec![]))
+}
+
+/// Constraints combined with `and` are flattened, as that is how the constraints of a rule are evaluated anyway
+fn parse_constraints(constraints: Option<&Value>) -> Result<Vec<Constraint>, PolicyConversionError> {
+    let mut parsed = vec![];
+    for constraint in list(constraints) {
+        if let Some(and) = constraint.get("and") {
+            parsed.extend(parse_constraints(Some(and))?);
+            continue;
+        }
+        if let Some(operator) = ["or", "xone", "andSequence"].into_iter().find(|operator| constraint.get(*operator).is_some()) {
+            return Err(PolicyConversionError::UnsupportedConstraint(operator.to_string()));
+        }
+        parsed.push(parse_constraint(constraint)?);
+    }
+    Ok(parsed)
+}
+
+fn parse_constraint(constraint: &Value) -> Result<Constraint, PolicyConversionError> {
+    let left_operand = match constraint.get("leftOperand").and_then(id_of) {
+        Some(operand) if is_iri(&operand) && !operand.starts_with(ODRL_NS) => LeftOperand::IRI(operand),
+        Some(operand) => LeftOperand::Literal(term(&operand)),
+        None => LeftOperand::Literal(String::new()),
+    };
+
+    let operator = constraint.get("operator").and_then(id_of).map(|o| term(&o)).unwrap_or_else(|| "eq".to_string());
+    let operator: Operator = serde_json::from_value(Value::from(operator.as_str()))
+        .map_err(|_| PolicyConversionError::UnsupportedOperator(operator))?;
+
+    let mut data_type = constraint.get("dataType").and_then(id_of);
+    let right_operand = match constraint.get("rightOperand").map(single) {
+        Some(Value::Object(object)) if object.contains_key("@id") => RightOperand::IRI(id_of(&object["@id"]).unwrap_or_default()),
+        Some(Value::Object(object)) => {
+            if data_type.is_none() {
+                data_type = object.get("@type").and_then(id_of);
+            }
+            RightOperand::Literal(object.get("@value").map(literal).unwrap_or_default())
+        },
+        Some(Value::Array(values)) => RightOperand::Literal(Value::Array(values.iter().map(|v| Value::from(literal(v))).collect()).to_string()),
+        Some(value) => RightOperand::Literal(literal(value)),
+        None => RightOperand::Literal(String::new()),
+    };
+
+    Ok(Constraint::new(
+        constraint.get("@id").or_else(|| constraint.get("uid")).and_then(id_of),
+        left_operand,
+        operator,
+        right_operand,
+        data_type,
+        constraint.get("unit").and_then(id_of),
+        String::new(),
+    ))
+}
+
+fn rule_party(rule: &Rule, function: Function) -> Option<String> {
+    let (assigner, assignee) = match rule {
+        Rule::Permission(p) => (p.assigner.as_ref(), p.assignee.as_ref()),
+        Rule::Prohibition(p) => (p.assigner.as_ref(), p.assignee.as_ref()),
+        Rule::Duty(d) => (d.assigner.as_ref(), d.assignee.as_ref()),
+        Rule::Obligation(o) => (Some(&o.assigner), Some(&o.assignee)),
+    };
+    match function {
+        Function::Assigner => assigner,
+        Function::Assignee => assignee,
+    }.and_then(|party| party.uid.clone())
+}
+
+fn party(uid: String, function: Function) -> Party {
+    Party::new(Some(uid), vec![], function, None)
+}
+
+fn asset(uid: String) -> Asset {
+    Asset::new(None, Some(uid), None, vec![], None, None, None, None, None, None)
+}
+
+
+fn write_rule(map: &mut Map<String, Value>, uid: &Option<IRI>, action: &Action, constraints: &[Constraint], target: Option<&Asset>, assigner: Option<&Party>, assignee: Option<&Party>) {
+    if let Some(uid) = uid {
+        map.insert("@id".to_string(), Value::from(uid.as_str()));
+    }
+    map.insert("action".to_string(), write_action(action));
+    if let Some(target) = target.and_then(|target| target.uid.as_ref()) {
+        map.insert("target".to_string(), Value::from(target.as_str()));
+    }
+    insert_party(map, "assigner", assigner);
+    insert_party(map, "assignee", assignee);
+    if !constraints.is_empty() {
+        map.insert("constraint".to_string(), Value::Array(constraints.iter().map(write_constraint).collect()));
+    }
+}
+
+fn write_permission(permission: &Permission) -> Value {
+    let mut map = Map::new();
+    write_rule(&mut map, &permission.uid, &permission.action, &permission.constraints, Some(&permission.target), permission.assigner.as_ref(), permission.assignee.as_ref());
+    if !permission.duties.is_empty() {
+        map.insert("duty".to_string(), Value::Array(permission.duties.iter().map(write_duty).collect()));
+    }
+    Value::Object(map)
+}
+
+fn write_prohibition(prohibition: &Prohibition) -> Value {
+    let mut map = Map::new();
+    write_rule(&mut map, &prohibition.uid, &prohibition.action, &prohibition.constraints, Some(&prohibition.target), prohibition.assigner.as_ref(), prohibition.assignee.as_ref());
+    if !prohibition.remedies.is_empty() {
+        map.insert("remedy".to_string(), Value::Array(prohibition.remedies.iter().map(write_duty).collect()));
+    }
+    Value::Object(map)
+}
+
+fn write_duty(duty: &Duty) -> Value {
+    let mut map = Map::new();
+    write_rule(&mut map, &duty.uid, &duty.action, &duty.constraints, duty.target.as_ref(), duty.assigner.as_ref(), duty.assignee.as_ref());
+    if !duty.consequences.is_empty() {
+        map.insert("consequence".to_string(), Value::Array(duty.consequences.iter().map(write_duty).collect()));
+    }
+    Value::Object(map)
+}
+
+fn write_obligation(obligation: &Obligation) -> Value {
+    let mut map = Map::new();
+    write_rule(&mut map, &obligation.uid, &obligation.action, &[], Some(&obligation.target), Some(&obligation.assigner), Some(&obligation.assignee));
+    if !obligation.consequence.is_empty() {
+        map.insert("consequence".to_string(), Value::Array(obligation.consequence.iter().map(write_duty).collect()));
+    }
+    Value::Object(map)
+}
+
+fn write_action(action: &Action) -> Value {
+    match &action.refinements {
+        Some(Refinements::Constraints(refinements)) if !refinements.is_empty() => serde_json::json!({
+            "rdf:value": { "@id": format!("odrl:{}", action.name) },
+            "refinement": refinements.iter().map(write_constraint).collect::<Vec<_>>(),
+        }),
+        _ => Value::from(action.name.as_str()),
+    }
+}
+
+fn write_constraint(constraint: &Constraint) -> Value {
+    let mut map = Map::new();
+    if let Some(uid) = &constraint.uid {
+        map.insert("@id".to_string(), Value::from(uid.as_str()));
+    }
+    let left_operand = match &constraint.left_operand {
+        LeftOperand::Literal(operand) | LeftOperand::IRI(operand) | LeftOperand::Reference(operand) => operand,
+    };
+    map.insert("leftOperand".to_string(), Value::from(left_operand.as_str()));
+    map.insert("operator".to_string(), serde_json::to_value(&constraint.operator).unwrap_or(Value::Null));
+    let right_operand = match &constraint.right_operand {
+        RightOperand::IRI(iri) | RightOperand::Reference(iri) => serde_json::json!({ "@id": iri }),
+        // Set based operators like isAnyOf keep their values as json array
+        RightOperand::Literal(literal) if literal.starts_with('[') => serde_json::from_str(literal).unwrap_or_else(|_| Value::from(literal.as_str())),
+        RightOperand::Literal(literal) => match &constraint.data_type {
+            Some(data_type) => serde_json::json!({ "@value": literal, "@type": data_type }),
+            None => Value::from(literal.as_str()),
+        },
+    };
+    map.insert("rightOperand".to_string(), right_operand);
+    if let Some(unit) = &constraint.unit {
+        map.insert("unit".to_string(), Value::from(unit.as_str()));
+    }
+    Value::Object(map)
+}
+
+fn insert_party(map: &mut Map<String, Value>, key: &str, party: Option<&Party>) {
+    if let Some(uid) = party.and_then(|party| party.uid.as_ref()) {
+        map.insert(key.to_string(), Value::from(uid.as_str()));
+    }
+}
+
+
+/// Removes the ODRL namespace and prefix from all keys and drops `@context`
+fn compact(value: &Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(object.iter()
+            .filter(|(key, _)| key.as_str() != "@context")
+            .map(|(key, value)| (term(key), compact(value)))
+            .collect()),
+        Value::Array(values) => Value::Array(values.iter().map(compact).collect()),
+        _ => value.clone(),
+    }
+}
+
+/// Term of an ODRL IRI, e.g. `use` for `odrl:use`. Other values are kept.
+fn term(iri: &str) -> String {
+    iri.strip_prefix(ODRL_NS)
+        .or_else(|| iri.strip_prefix("odrl:"))
+        .unwrap_or(iri)
+        .to_string()
+}
+
+/// Expanded JSON-LD wraps every value in an array
+fn single(value: &Value) -> &Value {
+    match value {
+        Value::Array(values) if values.len() == 1 => &values[0],
+        _ => value,
+    }
+}
+
+fn list(value: Option<&Value>) -> Vec<&Value> {
+    match value {
+        None | Some(Value::Null) => vec![],
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+    }
+}
+
+/// Identifier of a node, given as plain string or as an object with `@id`, `uid`, `@value` or `type`
+fn id_of(value: &Value) -> Option<String> {
+    match single(value) {
+        Value::String(id) => Some(id.clone()),
+        Value::Object(object) => ["@id", "uid", "@value", "type", "@type"].into_iter()
+            .find_map(|key| object.get(key))
+            .and_then(id_of),
+        _ => None,
+    }
+}
+
+fn literal(value: &Value) -> String {
+    match single(value) {
+        Value::String(literal) => literal.clone(),
+        Value::Object(object) if object.contains_key("@value") => literal(&object["@value"]),
+        value => value.to_string(),
+    }
+}
+
+fn is_iri(value: &str) -> bool {
+    value.starts_with("http://") || value.starts_with("https://")
+}
@@ -0,0 +1,344 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use serde_json::Value;
+
+use crate::model::action::{Action, Refinements};
+use crate::model::asset::Asset;
+use crate::model::conflict_term::ConflictTerm;
+use crate::model::constraint::{Constraint, LeftOperand, LogicalConstraint, LogicalOperator, Operator, RightOperand};
+use crate::model::party::Party;
+use crate::model::policy::Policy;
+use crate::model::rule::{Duty, Rule};
+use crate::model::type_alias::IRI;
+use crate::name_spaces::ODRL_NS;
+
+
+/// Outcome of evaluating a policy against a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+
+    Permit,
+    Deny,
+    NotApplicable,
+
+}
+
+/// The request a policy is evaluated against: who wants to exercise which action on which asset, and the current values of
+/// left operands such as `dateTime`, `spatial` or `count`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RequestContext {
+
+    pub action: Action,
+    pub target: IRI,
+    pub target_collections: Vec<IRI>,
+    pub assignee: Option<IRI>,
+    pub assignee_collections: Vec<IRI>,
+    pub operands: HashMap<String, Value>,
+
+}
+
+impl RequestContext {
+
+    pub fn new(action: Action, target: &str) -> RequestContext {
+        RequestContext {
+            action,
+            target: target.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_assignee(mut self, assignee: &str) -> RequestContext {
+        self.assignee = Some(assignee.to_string());
+        self
+    }
+
+    /// Collections (`partOf`) the target belongs to. Rules targeting one of them apply to the target as well.
+    pub fn with_target_collection(mut self, collection: &str) -> RequestContext {
+        self.target_collections.push(collection.to_string());
+        self
+    }
+
+    /// Party collections the assignee belongs to. Rules assigned to one of them apply to the assignee as well.
+    pub fn with_assignee_collection(mut self, collection: &str) -> RequestContext {
+        self.assignee_collections.push(collection.to_string());
+        self
+    }
+
+    /// Value of a left operand, e.g. `with_operand("count", 5.into())`. The name may be given with or without ODRL namespace.
+    pub fn with_operand(mut self, left_operand: &str, value: Value) -> RequestContext {
+        self.operands.insert(local_name(left_operand).to_string(), value);
+        self
+    }
+
+}
+
+/// Result of an evaluation. `matched_rules` are the rules that decided the request: the permissions granting it, the
+/// prohibitions denying it or, if nothing is in effect, the applicable rules whose constraints were not satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvaluationResult {
+
+    pub decision: Decision,
+    pub matched_rules: Vec<Rule>,
+    /// Duties of the granting permissions that have to be fulfilled when exercising the action
+    pub duties: Vec<Duty>,
+
+}
+
+/// Decides whether a request is permitted by a policy.
+///
+/// Logical constraints only reference their operands by uid, so the constraints they point to have to be registered,
+/// e.g. with the `parsed_constraints` returned by `json_parser::parse`.
+#[derive(Debug, Clone, Default)]
+pub struct Evaluator {
+
+    constraints: HashMap<IRI, Constraint>,
+
+}
+
+impl Evaluator {
+
+    pub fn new() -> Self {
+        Evaluator {
+            constraints: HashMap::new(),
+        }
+    }
+
+    pub fn with_constraints(constraints: Vec<Constraint>) -> Self {
+        let mut evaluator = Evaluator::new();
+        for constraint in constraints {
+            evaluator.register_constraint(constraint);
+        }
+        evaluator
+    }
+
+    pub fn register_constraint(&mut self, constraint: Constraint) {
+        if let Some(uid) = constraint.uid.clone() {
+            self.constraints.insert(uid, constraint);
+        }
+    }
+
+    pub fn evaluate(&self, policy: &Policy, request: &RequestContext) -> EvaluationResult {
+        let (rules, conflict, policy_assignee) = match policy {
+            Policy::SetPolicy(policy) => (&policy.rules, policy.conflict.clone(), None),
+            Policy::OfferPolicy(policy) => (&policy.rules, policy.conflict.clone(), None),
+            Policy::AgreementPolicy(policy) => (&policy.rules, policy.conflict.clone(), Some(&policy.assignee)),
+        };
+
+        let mut granting = vec![];
+        let mut denying = vec![];
+        let mut inactive = vec![];
+        let mut duties = vec![];
+
+        for rule in rules {
+            match rule {
+                Rule::Permission(permission) => {
+                    let assignee = permission.assignee.as_ref().or(policy_assignee);
+                    if !self.applies(&permission.action, &permission.target, assignee, request) {
+                        continue;
+                    }
+                    if self.refinements_satisfied(&permission.action, request) && self.all_satisfied(&permission.constraints, request) {
+                        granting.push(rule.clone());
+                        duties.extend(permission.duties.iter().cloned());
+                    } else {
+                        inactive.push(rule.clone());
+                    }
+                }
+                Rule::Prohibition(prohibition) => {
+                    let assignee = prohibition.assignee.as_ref().or(policy_assignee);
+                    if !self.applies(&prohibition.action, &prohibition.target, assignee, request) {
+                        continue;
+                    }
+                    if self.refinements_satisfied(&prohibition.action, request) && self.all_satisfied(&prohibition.constraints, request) {
+                        denying.push(rule.clone());
+                    } else {
+                        inactive.push(rule.clone());
+                    }
+                }
+                // Duties and obligations do not grant or deny anything on their own
+                Rule::Duty(_) | Rule::Obligation(_) => {}
+            }
+        }
+
+        let permit = EvaluationResult { decision: Decision::Permit, matched_rules: granting.clone(), duties: duties.clone() };
+        let deny = EvaluationResult { decision: Decision::Deny, matched_rules: denying.clone(), duties: vec![] };
+
+        match (granting.is_empty(), denying.is_empty()) {
+            (false, true) => permit,
+            (true, false) => deny,
+            // Permission and prohibition are in effect at the same time, the conflict strategy of the policy decides.
+            // Without a strategy the policy is invalid and therefore grants nothing.
+            (false, false) => match conflict {
+                Some(ConflictTerm::Perm) => permit,
+                Some(ConflictTerm::Prohibit) | Some(ConflictTerm::Invalid) | None => deny,
+            },
+            (true, true) if inactive.is_empty() => EvaluationResult { decision: Decision::NotApplicable, matched_rules: vec![], duties: vec![] },
+            (true, true) => EvaluationResult { decision: Decision::Deny, matched_rules: inactive, duties: vec![] },
+        }
+    }
+
+    /// Whether the rule is about the requested action, target and assignee, independent of its constraints and refinements
+    fn applies(&self, action: &Action, target: &Asset, assignee: Option<&Party>, request: &RequestContext) -> bool {
+        action_applies(action, request) && target_applies(target, request) && assignee_applies(assignee, request)
+    }
+
+    fn refinements_satisfied(&self, action: &Action, request: &RequestContext) -> bool {
+        match &action.refinements {
+            None => true,
+            Some(Refinements::Constraints(constraints)) => self.all_satisfied(constraints, request),
+            Some(Refinements::LogicalConstraints(logical_constraints)) => logical_constraints.iter()
+                .all(|logical_constraint| self.logical_constraint_satisfied(logical_constraint, request)),
+        }
+    }
+
+    fn all_satisfied(&self, constraints: &[Constraint], request: &RequestContext) -> bool {
+        constraints.iter().all(|constraint| constraint_satisfied(constraint, request))
+    }
+
+    pub fn logical_constraint_satisfied(&self, logical_constraint: &LogicalConstraint, request: &RequestContext) -> bool {
+        let Some((operator, operands)) = &logical_constraint.operand else {
+            return true;
+        };
+        // Unknown constraint references can never be satisfied
+        let results: Vec<bool> = operands.iter()
+            .map(|uid| self.constraints.get(uid).is_some_and(|constraint| constraint_satisfied(constraint, request)))
+            .collect();
+
+        match operator {
+            LogicalOperator::Or => results.iter().any(|satisfied| *satisfied),
+            LogicalOperator::Xone => results.iter().filter(|satisfied| **satisfied).count() == 1,
+            LogicalOperator::And | LogicalOperator::AndSequence => results.iter().all(|satisfied| *satisfied),
+        }
+    }
+
+}
+
+/// A rule about an action also covers all actions included in it
+fn action_applies(action: &Action, request: &RequestContext) -> bool {
+    let name = local_name(&action.name);
+    let mut requested = Some(&request.action);
+    while let Some(current) = requested {
+        if local_name(&current.name) == name {
+            return true;
+        }
+        requested = current.included_in.as_deref();
+    }
+    false
+}
+
+/// Rules without target uid apply to every asset. Rules on an asset collection apply to the assets that are part of it.
+fn target_applies(target: &Asset, request: &RequestContext) -> bool {
+    let Some(uid) = target.uid.as_deref() else {
+        return true;
+    };
+    uid == request.target
+        || request.target_collections.iter().any(|collection| collection == uid)
+}
+
+fn assignee_applies(assignee: Option<&Party>, request: &RequestContext) -> bool {
+    let Some(uid) = assignee.and_then(|party| party.uid.as_deref()) else {
+        return true;
+    };
+    request.assignee.as_deref() == Some(uid) || request.assignee_collections.iter().any(|collection| collection == uid)
+}
+
+/// Strips the ODRL namespace or prefix of a term
+fn local_name(term: &str) -> &str {
+    term.trim_start_matches(ODRL_NS).trim_start_matches("odrl:")
+}
+
+fn left_operand_value(constraint: &Constraint, request: &RequestContext) -> Option<Value> {
+    let name = match &constraint.left_operand {
+        LeftOperand::Literal(name) | LeftOperand::IRI(name) | LeftOperand::Reference(name) => local_name(name),
+    };
+    match request.operands.get(name) {
+        Some(value) => Some(value.clone()),
+        // Time based constraints are evaluated against the current time unless the request states otherwise
+        None if name == "dateTime" => Some(Value::String(Utc::now().to_rfc3339())),
+        None => None,
+    }
+}
+
+fn right_operand_value(constraint: &Constraint) -> &str {
+    match &constraint.right_operand {
+        RightOperand::Literal(value) | RightOperand::IRI(value) | RightOperand::Reference(value) => value,
+    }
+}
+
+fn constraint_satisfied(constraint: &Constraint, request: &RequestContext) -> bool {
+    // A constraint on an operand the request knows nothing about can not be satisfied
+    let Some(left) = left_operand_value(constraint, request) else {
+        return false;
+    };
+    let right = right_operand_value(constraint);
+    let left_values = values(&left);
+
+    match constraint.operator {
+        Operator::Equal => compare(&left, right) == Some(Ordering::Equal),
+        Operator::NotEqual => compare(&left, right).is_some_and(|ordering| ordering != Ordering::Equal),
+        Operator::GreaterThan => compare(&left, right) == Some(Ordering::Greater),
+        Operator::LessThan => compare(&left, right) == Some(Ordering::Less),
+        Operator::GreaterThanOrEqual => matches!(compare(&left, right), Some(Ordering::Greater | Ordering::Equal)),
+        Operator::LessThanOrEqual => matches!(compare(&left, right), Some(Ordering::Less | Ordering::Equal)),
+        Operator::IsA => left_values.iter().any(|value| value == right),
+        Operator::HasPart => left_values.iter().any(|value| value == right)
+            || left.as_str().is_some_and(|value| value.contains(right)),
+        Operator::IsPartOf => {
+            let right_values = list(right);
+            !left_values.is_empty() && left_values.iter().all(|value| right_values.contains(value))
+        }
+        Operator::IsAnyOf => {
+            let right_values = list(right);
+            left_values.iter().any(|value| right_values.contains(value))
+        }
+        Operator::IsAllOf => list(right).iter().all(|value| left_values.contains(value)),
+        Operator::IsNoneOf => {
+            let right_values = list(right);
+            !left_values.iter().any(|value| right_values.contains(value))
+        }
+    }
+}
+
+/// Flattens a request value into the strings it consists of
+fn values(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(elements) => elements.iter().flat_map(values).collect(),
+        Value::String(text) => vec![text.clone()],
+        Value::Null => vec![],
+        other => vec![other.to_string()],
+    }
+}
+
+/// Right operands of set based operators are either a json array or a comma separated list
+fn list(right: &str) -> Vec<String> {
+    match serde_json::from_str::<Value>(right) {
+        Ok(value @ Value::Array(_)) => values(&value),
+        _ => right.split(',').map(|value| value.trim().to_string()).filter(|value| !value.is_empty()).collect(),
+    }
+}
+
+fn parse_date_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value).ok().or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date_time| date_time.and_utc().fixed_offset())
+    })
+}
+
+/// Compares numerically, as date time or as string, whichever both sides can be read as
+fn compare(left: &Value, right: &str) -> Option<Ordering> {
+    let left = match left {
+        Value::String(text) => text.clone(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(boolean) => boolean.to_string(),
+        _ => return None,
+    };
+    if let (Ok(l), Ok(r)) = (left.parse::<f64>(), right.parse::<f64>()) {
+        return l.partial_cmp(&r);
+    }
+    if let (Some(l), Some(r)) = (parse_date_time(&left), parse_date_time(right)) {
+        return Some(l.cmp(&r));
+    }
+    Some(left.as_str().cmp(right))
+}
@@ -894,14 +894,14 @@ fn json_ld_from_graph(graph: LightGraph) -> Value {
 
     let mut nt_stringifier = NtSerializer::new_stringifier();
     let graph_as_str = nt_stringifier.serialize_graph(&graph).unwrap().as_str();
-    println!("\n\nThe resulting graph:\n{}", graph_as_str);
+    tracing::trace!(graph = %graph_as_str, "Serialized ODRL object to graph");
 
     let data = graph.into_dataset();
     let mut jsonifier = JsonLdStringifier::new_stringifier();
     let json_ld_string = jsonifier.serialize_dataset(&data).unwrap().to_string();
 
     let pretty_print_obj: Value = serde_json::from_str(&json_ld_string).unwrap();
-    println!("The resulting JSON-LD from graph (parsed):\n{}\n", serde_json::to_string_pretty(&pretty_print_obj).unwrap());
+    tracing::trace!(json_ld = %pretty_print_obj, "Serialized graph to JSON-LD");
 
     pretty_print_obj
 
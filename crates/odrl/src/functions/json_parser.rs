@@ -225,7 +225,7 @@ fn parse_policy(policy: &Value) -> Result<Policy> {
                 policy_obj = agreement_policy;
             }
         } else {
-            println!("Unknown policy type: {}\n", type_);
+            tracing::warn!(policy_type = %type_, "Unknown policy type");
         }
     }
 
@@ -263,7 +263,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
         },
         None => {
             action = Action::new("Unknown", None, None, vec![]);
-            println!("No action field\n")
+            tracing::warn!(rule_type = %rule_type, "Rule has no action field, using \"Unknown\"")
         },
     }
 
@@ -308,7 +308,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
                     let parsed_duty = parse_rule("duty", duty)?;
                     match parsed_duty {
                         Rule::Duty(duty) => duties_vec.push(duty),
-                        _ => println!("Unknown rule type in duties: {}\n", rule_type),
+                        _ => tracing::warn!(rule_type = %rule_type, field = "duties", "Unknown rule type"),
                     }
                 }
             }
@@ -327,7 +327,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
                     let parsed_remedy = parse_rule("duty", remedy)?;
                     match parsed_remedy {
                         Rule::Duty(remedy) => remedies_vec.push(remedy),
-                        _ => println!("Unknown rule type in remedies: {}\n", rule_type),
+                        _ => tracing::warn!(rule_type = %rule_type, field = "remedies", "Unknown rule type"),
                     }
                 }
             }
@@ -348,7 +348,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
                     let parsed_consequence = parse_rule("duty", consequence)?;
                     match parsed_consequence {
                         Rule::Duty(consequence) => consequences_vec.push(consequence),
-                        _ => println!("Unknown rule type in consequences: {}\n", rule_type),
+                        _ => tracing::warn!(rule_type = %rule_type, field = "consequences", "Unknown rule type"),
                     }
                 }
             }
@@ -357,7 +357,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
                     let parsed_pre_condition = parse_rule("duty", pre_condition)?;
                     match parsed_pre_condition {
                         Rule::Duty(pre_condition) => pre_conditions_vec.push(pre_condition),
-                        _ => println!("Unknown rule type in pre_conditions: {}\n", rule_type),
+                        _ => tracing::warn!(rule_type = %rule_type, field = "pre_conditions", "Unknown rule type"),
                     }
                 }
             }
@@ -380,7 +380,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
                     let parsed_consequence = parse_rule("duty", consequence)?;
                     match parsed_consequence {
                         Rule::Duty(consequence) => consequences_vec.push(consequence),
-                        _ => println!("Unknown rule type in consequences: {}\n", rule_type),
+                        _ => tracing::warn!(rule_type = %rule_type, field = "consequences", "Unknown rule type"),
                     }
                 }
             }
@@ -390,7 +390,7 @@ fn parse_rule(rule_type: &str, rule: &Value) -> Result<Rule> {
         }
         _ => {
             parsed_rule = Rule::Permission(Permission::new(None, action, None, vec![], vec![], vec![], asset, None, None, vec![]));
-            println!("Unknown rule type: {}\n", rule_type);
+            tracing::warn!(rule_type = %rule_type, "Unknown rule type, parsing it as permission");
         }
     }
 
@@ -477,6 +477,12 @@ fn parse_constraint(constraint: &Value) -> Result<Constraint> {
             "lt" => operator = Operator::LessThan,
             "gteq" => operator = Operator::GreaterThanOrEqual,
             "lteq" => operator = Operator::LessThanOrEqual,
+            "hasPart" => operator = Operator::HasPart,
+            "isA" => operator = Operator::IsA,
+            "isAllOf" => operator = Operator::IsAllOf,
+            "isAnyOf" => operator = Operator::IsAnyOf,
+            "isNoneOf" => operator = Operator::IsNoneOf,
+            "isPartOf" => operator = Operator::IsPartOf,
             _ => operator = Operator::Equal,
         }
     } else {
@@ -502,6 +508,10 @@ fn parse_constraint(constraint: &Value) -> Result<Constraint> {
         } else {
             right_operand = RightOperand::Literal("Unknown".to_string());
         }
+    } else if let Some(values) = constraint["rightOperand"].as_array() { // Set based operators like isAnyOf
+        right_operand = RightOperand::Literal(Value::Array(values.clone()).to_string());
+    } else if let Some(value) = constraint["rightOperand"].as_number() {
+        right_operand = RightOperand::Literal(value.to_string());
     } else {
         right_operand = RightOperand::Literal("Unknown".to_string());
     }
@@ -567,7 +577,6 @@ pub fn parse(json_data: &str) -> Result<ParsedData> {
             if let Some(policy) = entry.get("policy") {
                 let parsed_policy = parse_policy(policy)?;
                 parsed_policies.push(parsed_policy);
-                //println!("Parsed Policy: {:#?}\n", parsed_policy);
             }
 
             // JsonLD-representation
@@ -575,11 +584,9 @@ pub fn parse(json_data: &str) -> Result<ParsedData> {
                 if object_type.to_lowercase() == "constraint" {
                     let parsed_constraint = parse_constraint(entry)?;
                     parsed_constraints.push(parsed_constraint);
-                    //println!("Parsed Constraint: {:#?}\n", parsed_constraint);
                 } else if object_type.to_lowercase() == "set" || object_type.to_lowercase() == "offer" || object_type.to_lowercase() == "agreement" {
                     let parsed_policy = parse_policy(entry)?;
                     parsed_policies.push(parsed_policy);
-                    //println!("Parsed Policy: {:#?}\n", parsed_policy);
                 }
             }
         }
@@ -600,6 +607,8 @@ pub fn parse(json_data: &str) -> Result<ParsedData> {
         }
     }
 
+    tracing::debug!(policies = parsed_policies.len(), constraints = parsed_constraints.len(), "Parsed ODRL document");
+
     let parsed_data = ParsedData::new(parsed_policies, parsed_constraints);
 
     Ok(parsed_data)
@@ -1,4 +1,127 @@
+use std::fmt;
+
 use chrono::{Local, DateTime};
+use edc_api::dsp::{ContractAgreementMessage, ContractAgreementVerificationMessage, ContractNegotiationEventMessage, ContractNegotiationTerminationMessage,
+                   ContractOfferMessage, ContractRequestMessage, NegotiationEventType};
+
+// The state machines only check and record the negotiation, they never do any I/O themselves. A runtime sends the message
+// of an -ing state to the counter party, e.g. with the dsp_client of edc_client, and moves on to the -ed state once it was
+// delivered. Received messages are handed to `receive`. Both machines are Send, so they can be owned by an async task.
+
+/// Messages of the dataspace protocol that are exchanged during a contract negotiation
+#[derive(Debug, Clone, PartialEq)]
+pub enum NegotiationMessage {
+    ContractRequest(ContractRequestMessage),
+    ContractOffer(ContractOfferMessage),
+    ContractNegotiationEvent(ContractNegotiationEventMessage),
+    ContractAgreement(ContractAgreementMessage),
+    ContractAgreementVerification(ContractAgreementVerificationMessage),
+    ContractNegotiationTermination(ContractNegotiationTerminationMessage),
+}
+
+impl NegotiationMessage {
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NegotiationMessage::ContractRequest(_) => "ContractRequestMessage",
+            NegotiationMessage::ContractOffer(_) => "ContractOfferMessage",
+            NegotiationMessage::ContractNegotiationEvent(_) => "ContractNegotiationEventMessage",
+            NegotiationMessage::ContractAgreement(_) => "ContractAgreementMessage",
+            NegotiationMessage::ContractAgreementVerification(_) => "ContractAgreementVerificationMessage",
+            NegotiationMessage::ContractNegotiationTermination(_) => "ContractNegotiationTerminationMessage",
+        }
+    }
+
+    // Process ids of provider and consumer the message refers to
+    fn process_ids(&self) -> (Option<&str>, Option<&str>) {
+        match self {
+            NegotiationMessage::ContractRequest(message) => (message.provider_pid.as_deref(), Some(&message.consumer_pid)),
+            NegotiationMessage::ContractOffer(message) => (Some(&message.provider_pid), message.consumer_pid.as_deref()),
+            NegotiationMessage::ContractNegotiationEvent(message) => (Some(&message.provider_pid), Some(&message.consumer_pid)),
+            NegotiationMessage::ContractAgreement(message) => (Some(&message.provider_pid), Some(&message.consumer_pid)),
+            NegotiationMessage::ContractAgreementVerification(message) => (Some(&message.provider_pid), Some(&message.consumer_pid)),
+            NegotiationMessage::ContractNegotiationTermination(message) => (Some(&message.provider_pid), Some(&message.consumer_pid)),
+        }
+    }
+
+    fn event_type(&self) -> Option<NegotiationEventType> {
+        match self {
+            NegotiationMessage::ContractNegotiationEvent(message) => Some(message.event_type),
+            _ => None,
+        }
+    }
+
+}
+
+impl From<ContractRequestMessage> for NegotiationMessage {
+    fn from(message: ContractRequestMessage) -> Self {
+        NegotiationMessage::ContractRequest(message)
+    }
+}
+
+impl From<ContractOfferMessage> for NegotiationMessage {
+    fn from(message: ContractOfferMessage) -> Self {
+        NegotiationMessage::ContractOffer(message)
+    }
+}
+
+impl From<ContractNegotiationEventMessage> for NegotiationMessage {
+    fn from(message: ContractNegotiationEventMessage) -> Self {
+        NegotiationMessage::ContractNegotiationEvent(message)
+    }
+}
+
+impl From<ContractAgreementMessage> for NegotiationMessage {
+    fn from(message: ContractAgreementMessage) -> Self {
+        NegotiationMessage::ContractAgreement(message)
+    }
+}
+
+impl From<ContractAgreementVerificationMessage> for NegotiationMessage {
+    fn from(message: ContractAgreementVerificationMessage) -> Self {
+        NegotiationMessage::ContractAgreementVerification(message)
+    }
+}
+
+impl From<ContractNegotiationTerminationMessage> for NegotiationMessage {
+    fn from(message: ContractNegotiationTerminationMessage) -> Self {
+        NegotiationMessage::ContractNegotiationTermination(message)
+    }
+}
+
+/// Reasons why a state machine rejects a transition or a message. The state machine is left unchanged in every case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateMachineError {
+    /// The state machine cannot move from its current state into the target state
+    InvalidTransition { state: &'static str, target: &'static str },
+    /// The message is not expected in the current state
+    UnexpectedMessage { state: &'static str, message: &'static str },
+    /// The message belongs to another negotiation
+    ProcessIdMismatch { expected: String, actual: String },
+}
+
+impl fmt::Display for StateMachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateMachineError::InvalidTransition { state, target } => write!(f, "Cannot transition to {} from {}", target, state),
+            StateMachineError::UnexpectedMessage { state, message } => write!(f, "Cannot receive {} in {}", message, state),
+            StateMachineError::ProcessIdMismatch { expected, actual } => write!(f, "Message for process {} does not belong to process {}", actual, expected),
+        }
+    }
+}
+
+impl std::error::Error for StateMachineError {}
+
+// Checks a process id of a message against the one of the negotiation, unknown process ids accept every message
+pub(crate) fn check_process_id(expected: Option<&str>, actual: Option<&str>) -> Result<(), StateMachineError> {
+    match (expected, actual) {
+        (Some(expected), Some(actual)) if expected != actual => Err(StateMachineError::ProcessIdMismatch {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum ConsumerState {
@@ -20,10 +143,55 @@ pub enum ConsumerState {
     Finalized(Finalized),       // can only be reached if a ContractNegotiationEventMessage is received
 
     // Termination
-    Terminating(Terminating),   // if the consumer terminates the transfer he will send a TransferTerminationMessage
+    Terminating(Terminating),   // if the consumer terminates the negotiation he will send a ContractNegotiationTerminationMessage
     Terminated(Terminated),     // final state
 }
 
+impl ConsumerState {
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConsumerState::Initial(_) => "Initial",
+            ConsumerState::Requesting(_) => "Requesting",
+            ConsumerState::Requested(_) => "Requested",
+            ConsumerState::Offered(_) => "Offered",
+            ConsumerState::Accepting(_) => "Accepting",
+            ConsumerState::Accepted(_) => "Accepted",
+            ConsumerState::Agreed(_) => "Agreed",
+            ConsumerState::Verifying(_) => "Verifying",
+            ConsumerState::Verified(_) => "Verified",
+            ConsumerState::Finalized(_) => "Finalized",
+            ConsumerState::Terminating(_) => "Terminating",
+            ConsumerState::Terminated(_) => "Terminated",
+        }
+    }
+
+    /// Whether the negotiation has ended, either with an agreement or without
+    pub fn is_final(&self) -> bool {
+        matches!(self, ConsumerState::Finalized(_) | ConsumerState::Terminated(_))
+    }
+
+    /// The last message sent or received, `None` before the negotiation has started
+    pub fn message(&self) -> Option<&NegotiationMessage> {
+        let negotiation_state = match self {
+            ConsumerState::Initial(_) => return None,
+            ConsumerState::Requesting(state) => &state.negotiation_state,
+            ConsumerState::Requested(state) => &state.negotiation_state,
+            ConsumerState::Offered(state) => &state.negotiation_state,
+            ConsumerState::Accepting(state) => &state.negotiation_state,
+            ConsumerState::Accepted(state) => &state.negotiation_state,
+            ConsumerState::Agreed(state) => &state.negotiation_state,
+            ConsumerState::Verifying(state) => &state.negotiation_state,
+            ConsumerState::Verified(state) => &state.negotiation_state,
+            ConsumerState::Finalized(state) => &state.negotiation_state,
+            ConsumerState::Terminating(state) => &state.negotiation_state,
+            ConsumerState::Terminated(state) => &state.negotiation_state,
+        };
+        Some(&negotiation_state.message)
+    }
+
+}
+
 #[derive(Debug, Clone)]
 pub enum ProviderState {
     // Negotiation
@@ -42,21 +210,66 @@ pub enum ProviderState {
     Finalized(Finalized),
 
     // Termination
-    Terminating(Terminating),   // if the provider terminates the transfer he will send a TransferTerminationMessage
+    Terminating(Terminating),   // if the provider terminates the negotiation he will send a ContractNegotiationTerminationMessage
     Terminated(Terminated),     // final state
 }
 
+impl ProviderState {
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProviderState::Initial(_) => "Initial",
+            ProviderState::Offering(_) => "Offering",
+            ProviderState::Offered(_) => "Offered",
+            ProviderState::Requested(_) => "Requested",
+            ProviderState::Accepted(_) => "Accepted",
+            ProviderState::Agreeing(_) => "Agreeing",
+            ProviderState::Agreed(_) => "Agreed",
+            ProviderState::Verified(_) => "Verified",
+            ProviderState::Finalizing(_) => "Finalizing",
+            ProviderState::Finalized(_) => "Finalized",
+            ProviderState::Terminating(_) => "Terminating",
+            ProviderState::Terminated(_) => "Terminated",
+        }
+    }
+
+    /// Whether the negotiation has ended, either with an agreement or without
+    pub fn is_final(&self) -> bool {
+        matches!(self, ProviderState::Finalized(_) | ProviderState::Terminated(_))
+    }
+
+    /// The last message sent or received, `None` before the negotiation has started
+    pub fn message(&self) -> Option<&NegotiationMessage> {
+        let negotiation_state = match self {
+            ProviderState::Initial(_) => return None,
+            ProviderState::Offering(state) => &state.negotiation_state,
+            ProviderState::Offered(state) => &state.negotiation_state,
+            ProviderState::Requested(state) => &state.negotiation_state,
+            ProviderState::Accepted(state) => &state.negotiation_state,
+            ProviderState::Agreeing(state) => &state.negotiation_state,
+            ProviderState::Agreed(state) => &state.negotiation_state,
+            ProviderState::Verified(state) => &state.negotiation_state,
+            ProviderState::Finalizing(state) => &state.negotiation_state,
+            ProviderState::Finalized(state) => &state.negotiation_state,
+            ProviderState::Terminating(state) => &state.negotiation_state,
+            ProviderState::Terminated(state) => &state.negotiation_state,
+        };
+        Some(&negotiation_state.message)
+    }
+
+}
+
 #[derive(Debug, Clone)]
 pub struct NegotiationState {
     pub consumer: String,               // iri
     pub provider: String,               // iri
     pub state: String,                  // Current state of the state machine
-    pub message: String,                // Current state of the negotiation
+    pub message: NegotiationMessage,    // Last message of the negotiation
     pub timestamp: DateTime<Local>,     // Timestamp to ensure a history of the negotiation
 }
 
 impl NegotiationState {
-    pub fn new(consumer: String, provider: String, state: String, message: String, timestamp: DateTime<Local>) -> Self {
+    pub fn new(consumer: String, provider: String, state: String, message: NegotiationMessage, timestamp: DateTime<Local>) -> Self {
         NegotiationState {
             consumer,
             provider,
@@ -72,6 +285,8 @@ pub struct ConsumerStateMachine<CS> {
     pub state: CS,
     pub iri: String,
     pub negotiation_partner: String,
+    pub consumer_pid: String,               // process id of the negotiation on the consumer side
+    pub provider_pid: Option<String>,       // process id on the provider side, known after the first message of the provider
 }
 
 impl ConsumerStateMachine<ConsumerState> {
@@ -80,180 +295,237 @@ impl ConsumerStateMachine<ConsumerState> {
             state: ConsumerState::Initial(Initial::new()),
             iri: c_iri.to_string(),
             negotiation_partner: p_iri.to_string(),
+            consumer_pid: uuid::Uuid::new_v4().to_string(),
+            provider_pid: None,
         }
     }
 
-    // Method to transition to a certain state if the provider receives messages
+    // Method to transition to a certain state if the consumer sends or receives messages
     fn transition_to_state(&mut self, state: ConsumerState) {
-        self.state = state.clone();
-        println!("Provider state transitioned to {:?}", self.state);
+        self.state = state;
+        tracing::debug!(iri = %self.iri, state = ?self.state, "Consumer state transitioned");
+    }
+
+    fn invalid_transition(&self, target: &'static str) -> StateMachineError {
+        StateMachineError::InvalidTransition { state: self.state.name(), target }
+    }
+
+    fn unexpected_message(&self, message: &NegotiationMessage) -> StateMachineError {
+        tracing::debug!(iri = %self.iri, state = ?self.state, message = message.name(), "Consumer cannot receive message in current state");
+        StateMachineError::UnexpectedMessage { state: self.state.name(), message: message.name() }
+    }
+
+    // Both process ids of a message have to match the negotiation, the one of the provider as soon as it is known
+    fn check_process_ids(&self, message: &NegotiationMessage) -> Result<(), StateMachineError> {
+        let (provider_pid, consumer_pid) = message.process_ids();
+        check_process_id(Some(&self.consumer_pid), consumer_pid)?;
+        check_process_id(self.provider_pid.as_deref(), provider_pid)
+    }
+
+    // The message of the current -ing state, which is carried over into the following -ed state
+    fn sent_message(&self) -> NegotiationMessage {
+        self.state.message().cloned().expect("Sending states always carry a message")
     }
 
-    pub fn transition_to_requesting(&mut self, msg: &str) {
-        // Check if the current state is Initial or Offered
+    pub fn transition_to_requesting(&mut self, message: ContractRequestMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // Check if the current state is Initial or Offered, the latter makes the request a counteroffer
         match &self.state {
-            ConsumerState::Initial(_) => {
+            ConsumerState::Initial(_) | ConsumerState::Offered(_) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Requesting
-                self.transition_to_state(ConsumerState::Requesting(Requesting::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Requesting(Requesting::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            ConsumerState::Offered(_) => {    // Counteroffer
-                // Update the state machine's state to Requesting
-                self.transition_to_state(ConsumerState::Requesting(Requesting::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
-            },
-            _ => println!("Cannot transition to Requesting from current state"),
+            _ => Err(self.invalid_transition("Requesting")),
         }
     }
 
-    pub fn transition_to_requested(&mut self, msg: &str) {
+    pub fn transition_to_requested(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Requesting
         match &self.state {
             ConsumerState::Requesting(_) => {
                 // Update the state machine's state to Requested
-                self.transition_to_state(ConsumerState::Requested(Requested::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Requested(Requested::new(self.sent_message(), self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Requested from current state"),
+            _ => Err(self.invalid_transition("Requested")),
         }
     }
 
-    pub fn transition_to_accepting(&mut self, msg: &str) {
-        // Check if the current state is Offered
+    pub fn transition_to_accepting(&mut self, message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // Check if the current state is Offered, the consumer can only accept offers
         match &self.state {
-            ConsumerState::Offered(_) => {
+            ConsumerState::Offered(_) if message.event_type() == Some(NegotiationEventType::Accepted) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Accepting
-                self.transition_to_state(ConsumerState::Accepting(Accepting::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Accepting(Accepting::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Accepting from current state"),
+            _ => Err(self.invalid_transition("Accepting")),
         }
     }
 
-    pub fn transition_to_accepted(&mut self, msg: &str) {
+    pub fn transition_to_accepted(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Accepting
         match &self.state {
             ConsumerState::Accepting(_) => {
                 // Update the state machine's state to Accepted
-                self.transition_to_state(ConsumerState::Accepted(Accepted::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Accepted(Accepted::new(self.sent_message(), self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Accepted from current state"),
+            _ => Err(self.invalid_transition("Accepted")),
         }
     }
-    pub fn transition_to_verifying(&mut self, msg: &str) {
+
+    pub fn transition_to_verifying(&mut self, message: ContractAgreementVerificationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
         // Check if the current state is Agreed
         match &self.state {
             ConsumerState::Agreed(_) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Verifying
-                self.transition_to_state(ConsumerState::Verifying(Verifying::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Verifying(Verifying::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Verifying from current state"),
+            _ => Err(self.invalid_transition("Verifying")),
         }
     }
 
-    pub fn transition_to_verified(&mut self, msg: &str) {
+    pub fn transition_to_verified(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Verifying
         match &self.state {
             ConsumerState::Verifying(_) => {
                 // Update the state machine's state to Verified
-                self.transition_to_state(ConsumerState::Verified(Verified::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Verified(Verified::new(self.sent_message(), self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Verified from current state"),
+            _ => Err(self.invalid_transition("Verified")),
         }
     }
 
-    pub fn transition_to_terminating(&mut self, msg: &str) {
-        // Check if the current state is Finalized
+    pub fn transition_to_terminating(&mut self, message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // A negotiation can be terminated as long as it has started and has not ended yet
         match &self.state {
-            ConsumerState::Finalized(_) => {
+            ConsumerState::Initial(_) | ConsumerState::Finalized(_) | ConsumerState::Terminating(_) | ConsumerState::Terminated(_) => {
+                Err(self.invalid_transition("Terminating"))
+            },
+            _ => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Terminating
-                self.transition_to_state(ConsumerState::Terminating(Terminating::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Terminating(Terminating::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Terminating from current state"),
         }
     }
-    pub fn transition_to_terminated(&mut self, msg: &str) {
+
+    pub fn transition_to_terminated(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Terminating
         match &self.state {
             ConsumerState::Terminating(_) => {
                 // Update the state machine's state to Terminated
-                self.transition_to_state(ConsumerState::Terminated(Terminated::new(msg, self.iri.as_str(), self.negotiation_partner.as_str())));
+                self.transition_to_state(ConsumerState::Terminated(Terminated::new(self.sent_message(), self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Terminated from current state"),
+            _ => Err(self.invalid_transition("Terminated")),
         }
     }
 
-    // Method to send a contract request message to the provider
-    pub fn send_contract_request(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_request_message: String) {
-        provider.receive_contract_request(contract_request_message);
+    // Method to send a contract request message to a provider state machine in the same process
+    pub fn send_contract_request(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_request_message: ContractRequestMessage) -> Result<(), StateMachineError> {
+        provider.receive_contract_request(contract_request_message)
     }
 
-    // Method to send a contract negotiation event message to the provider
-    pub fn send_contract_negotiation_event(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_negotiation_event_message: String) {
-        provider.receive_contract_negotiation_event(contract_negotiation_event_message);
+    // Method to send a contract negotiation event message to a provider state machine in the same process
+    pub fn send_contract_negotiation_event(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_negotiation_event_message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        provider.receive_contract_negotiation_event(contract_negotiation_event_message)
     }
 
-    // Method to send a contract agreement verification message to the provider
-    pub fn send_contract_agreement_verification(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_agreement_verification_message: String) {
-        provider.receive_contract_agreement_verification(contract_agreement_verification_message);
+    // Method to send a contract agreement verification message to a provider state machine in the same process
+    pub fn send_contract_agreement_verification(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_agreement_verification_message: ContractAgreementVerificationMessage) -> Result<(), StateMachineError> {
+        provider.receive_contract_agreement_verification(contract_agreement_verification_message)
     }
 
-    // Method to send a transfer termination message to the provider
-    pub fn send_transfer_termination(&self, provider: &mut ProviderStateMachine<ProviderState>, transfer_termination_message: String) {
-        provider.receive_transfer_termination(transfer_termination_message);
+    // Method to send a contract negotiation termination message to a provider state machine in the same process
+    pub fn send_contract_negotiation_termination(&self, provider: &mut ProviderStateMachine<ProviderState>, contract_negotiation_termination_message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        provider.receive_contract_negotiation_termination(contract_negotiation_termination_message)
     }
 
-    pub fn receive_contract_offer(&mut self, contract_offer_message: String) {
-        match &mut self.state {
+    /// Dispatches a message received from the provider
+    pub fn receive(&mut self, message: NegotiationMessage) -> Result<(), StateMachineError> {
+        match message {
+            NegotiationMessage::ContractOffer(message) => self.receive_contract_offer(message),
+            NegotiationMessage::ContractAgreement(message) => self.receive_contract_agreement(message),
+            NegotiationMessage::ContractNegotiationEvent(message) => self.receive_contract_negotiation_event(message),
+            NegotiationMessage::ContractNegotiationTermination(message) => self.receive_contract_negotiation_termination(message),
+            message => Err(self.unexpected_message(&message)),
+        }
+    }
+
+    pub fn receive_contract_offer(&mut self, contract_offer_message: ContractOfferMessage) -> Result<(), StateMachineError> {
+        let provider_pid = contract_offer_message.provider_pid.clone();
+        let message = NegotiationMessage::from(contract_offer_message);
+        match &self.state {
             // If the consumer is in the Initial or Requested state, he can receive a contract offer
             // and transition to the Offered state
             // if the consumer is in the Requesting state, he should not receive a contract offer
             // because he is waiting for the provider to accept or decline the request
-            ConsumerState::Initial(_) => {
-                println!("Consumer received contract offer");
-                self.transition_to_state(ConsumerState::Offered(Offered::new(contract_offer_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
-            },
-            ConsumerState::Requested(_) => {
-                println!("Consumer received contract offer");
-                self.transition_to_state(ConsumerState::Offered(Offered::new(contract_offer_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
+            ConsumerState::Initial(_) | ConsumerState::Requested(_) => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Consumer received contract offer");
+                self.provider_pid = Some(provider_pid);
+                self.transition_to_state(ConsumerState::Offered(Offered::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Consumer cannot receive contract offer in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
-    pub fn receive_contract_agreement(&mut self, contract_agreement_message: String) {
-        match &mut self.state {
+    pub fn receive_contract_agreement(&mut self, contract_agreement_message: ContractAgreementMessage) -> Result<(), StateMachineError> {
+        let provider_pid = contract_agreement_message.provider_pid.clone();
+        let message = NegotiationMessage::from(contract_agreement_message);
+        match &self.state {
             // If the consumer is in the Accepted or Requested state, he can receive a contract agreement
             // and transition to the Agreed state
-            ConsumerState::Accepted(_) => {
-                println!("Consumer received contract agreement");
-                self.transition_to_state(ConsumerState::Agreed(Agreed::new(contract_agreement_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
-            },
-            ConsumerState::Requested(_) => {
-                println!("Consumer received contract agreement");
-                self.transition_to_state(ConsumerState::Agreed(Agreed::new(contract_agreement_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
+            ConsumerState::Accepted(_) | ConsumerState::Requested(_) => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Consumer received contract agreement");
+                self.provider_pid = Some(provider_pid);
+                self.transition_to_state(ConsumerState::Agreed(Agreed::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Consumer cannot receive contract agreement in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
-    pub fn receive_contract_negotiation_event(&mut self, contract_negotiation_event_message: String) {
-        match &mut self.state {
+    pub fn receive_contract_negotiation_event(&mut self, contract_negotiation_event_message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(contract_negotiation_event_message);
+        match &self.state {
             // If the consumer is in the Verified state, he can receive a contract negotiation event message
             // and transition to the Finalized state when the provider has finalized the contract
-            ConsumerState::Verified(_) => {
-                println!("Consumer received contract negotiation event message");
-                self.transition_to_state(ConsumerState::Finalized(Finalized::new(contract_negotiation_event_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
+            ConsumerState::Verified(_) if message.event_type() == Some(NegotiationEventType::Finalized) => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Consumer received contract negotiation event message");
+                self.transition_to_state(ConsumerState::Finalized(Finalized::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Consumer cannot receive contract negotiation event message in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
-    pub fn receive_transfer_termination(&mut self, transfer_termination_message: String) {
-        match &mut self.state {
-            // If the consumer is in the Finalized state, he can receive a transfer termination message
-            // and transition to the Terminated state
-            ConsumerState::Finalized(_) => {
-                println!("Consumer received transfer termination message");
-                self.transition_to_state(ConsumerState::Terminated(Terminated::new(transfer_termination_message.as_str(), self.iri.as_str(), self.negotiation_partner.as_str())));
+    pub fn receive_contract_negotiation_termination(&mut self, contract_negotiation_termination_message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(contract_negotiation_termination_message);
+        match &self.state {
+            // The provider can terminate the negotiation as long as it has started and has not ended yet
+            ConsumerState::Initial(_) | ConsumerState::Finalized(_) | ConsumerState::Terminated(_) => Err(self.unexpected_message(&message)),
+            _ => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Consumer received contract negotiation termination message");
+                self.transition_to_state(ConsumerState::Terminated(Terminated::new(message, self.iri.as_str(), self.negotiation_partner.as_str())));
+                Ok(())
             },
-            _ => println!("Consumer cannot receive transfer termination message in current state"),
         }
     }
 }
@@ -263,190 +535,250 @@ pub struct ProviderStateMachine<PS> {
     pub state: PS,
     pub iri: String,
     pub negotiation_partner: String,
+    pub provider_pid: String,               // process id of the negotiation on the provider side
+    pub consumer_pid: Option<String>,       // process id on the consumer side, known after the first message of the consumer
 }
 
 impl ProviderStateMachine<ProviderState> {
-    pub fn new(c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(p_iri: &str, c_iri: &str) -> Self {
         ProviderStateMachine {
             state: ProviderState::Initial(Initial::new()),
-            iri: c_iri.to_string(),
-            negotiation_partner: p_iri.to_string(),
+            iri: p_iri.to_string(),
+            negotiation_partner: c_iri.to_string(),
+            provider_pid: uuid::Uuid::new_v4().to_string(),
+            consumer_pid: None,
         }
     }
 
-    // Method to transition to a certain state if the provider receives messages
+    // Method to transition to a certain state if the provider sends or receives messages
     fn transition_to_state(&mut self, state: ProviderState) {
-        self.state = state.clone();
-        println!("Provider state transitioned to {:?}", self.state);
+        self.state = state;
+        tracing::debug!(iri = %self.iri, state = ?self.state, "Provider state transitioned");
+    }
+
+    fn invalid_transition(&self, target: &'static str) -> StateMachineError {
+        StateMachineError::InvalidTransition { state: self.state.name(), target }
+    }
+
+    fn unexpected_message(&self, message: &NegotiationMessage) -> StateMachineError {
+        tracing::debug!(iri = %self.iri, state = ?self.state, message = message.name(), "Provider cannot receive message in current state");
+        StateMachineError::UnexpectedMessage { state: self.state.name(), message: message.name() }
+    }
+
+    // Both process ids of a message have to match the negotiation, the one of the consumer as soon as it is known.
+    // A contract request without provider process id starts the negotiation.
+    fn check_process_ids(&self, message: &NegotiationMessage) -> Result<(), StateMachineError> {
+        let (provider_pid, consumer_pid) = message.process_ids();
+        check_process_id(Some(&self.provider_pid), provider_pid)?;
+        check_process_id(self.consumer_pid.as_deref(), consumer_pid)
     }
 
-    pub fn transition_to_offering(&mut self, msg: &str) {
-        // Check if the current state is Initial or Requested
+    // The message of the current -ing state, which is carried over into the following -ed state
+    fn sent_message(&self) -> NegotiationMessage {
+        self.state.message().cloned().expect("Sending states always carry a message")
+    }
+
+    pub fn transition_to_offering(&mut self, message: ContractOfferMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // Check if the current state is Initial or Requested, the latter makes the offer a counteroffer
         match &self.state {
-            ProviderState::Initial(_) => {
-                // Update the state machine's state to Offering
-                self.transition_to_state(ProviderState::Offering(Offering::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
-            },
-            ProviderState::Requested(_) => {    // Counteroffer
+            ProviderState::Initial(_) | ProviderState::Requested(_) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Offering
-                self.transition_to_state(ProviderState::Offering(Offering::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Offering(Offering::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Offering from current state"),
+            _ => Err(self.invalid_transition("Offering")),
         }
     }
 
-    pub fn transition_to_offered(&mut self, msg: &str) {
+    pub fn transition_to_offered(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Offering
         match &self.state {
             ProviderState::Offering(_) => {
                 // Update the state machine's state to Offered
-                self.transition_to_state(ProviderState::Offered(Offered::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Offered(Offered::new(self.sent_message(), self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Offered from current state"),
+            _ => Err(self.invalid_transition("Offered")),
         }
     }
 
-    pub fn transition_to_agreeing(&mut self, msg: &str) {
+    pub fn transition_to_agreeing(&mut self, message: ContractAgreementMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
         // Check if the current state is Accepted or Requested
         match &self.state {
-            ProviderState::Accepted(_) => {
+            ProviderState::Accepted(_) | ProviderState::Requested(_) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Agreeing
-                self.transition_to_state(ProviderState::Agreeing(Agreeing::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Agreeing(Agreeing::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            ProviderState::Requested(_) => {
-                // Update the state machine's state to Agreeing
-                self.transition_to_state(ProviderState::Agreeing(Agreeing::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
-            },
-            _ => println!("Cannot transition to Agreeing from current state"),
+            _ => Err(self.invalid_transition("Agreeing")),
         }
     }
 
-    pub fn transition_to_agreed(&mut self, msg: &str) {
+    pub fn transition_to_agreed(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Agreeing
         match &self.state {
             ProviderState::Agreeing(_) => {
                 // Update the state machine's state to Agreed
-                self.transition_to_state(ProviderState::Agreed(Agreed::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Agreed(Agreed::new(self.sent_message(), self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Agreed from current state"),
+            _ => Err(self.invalid_transition("Agreed")),
         }
     }
 
-    pub fn transition_to_finalizing(&mut self, msg: &str) {
-        // Check if the current state is Verified
+    pub fn transition_to_finalizing(&mut self, message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // Check if the current state is Verified, the provider can only finalize
         match &self.state {
-            ProviderState::Verified(_) => {
+            ProviderState::Verified(_) if message.event_type() == Some(NegotiationEventType::Finalized) => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Finalizing
-                self.transition_to_state(ProviderState::Finalizing(Finalizing::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Finalizing(Finalizing::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Finalizing from current state"),
+            _ => Err(self.invalid_transition("Finalizing")),
         }
     }
 
-    pub fn transition_to_finalized(&mut self, msg: &str) {
+    pub fn transition_to_finalized(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Finalizing
         match &self.state {
             ProviderState::Finalizing(_) => {
                 // Update the state machine's state to Finalized
-                self.transition_to_state(ProviderState::Finalized(Finalized::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Finalized(Finalized::new(self.sent_message(), self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Finalized from current state"),
+            _ => Err(self.invalid_transition("Finalized")),
         }
     }
 
-    pub fn transition_to_terminating(&mut self, msg: &str) {
-        // Check if the current state is Finalized
+    pub fn transition_to_terminating(&mut self, message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(message);
+        // A negotiation can be terminated as long as it has started and has not ended yet
         match &self.state {
-            ProviderState::Finalized(_) => {
+            ProviderState::Initial(_) | ProviderState::Finalized(_) | ProviderState::Terminating(_) | ProviderState::Terminated(_) => {
+                Err(self.invalid_transition("Terminating"))
+            },
+            _ => {
+                self.check_process_ids(&message)?;
                 // Update the state machine's state to Terminating
-                self.transition_to_state(ProviderState::Terminating(Terminating::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Terminating(Terminating::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Terminating from current state"),
         }
     }
 
-    pub fn transition_to_terminated(&mut self, msg: &str) {
+    pub fn transition_to_terminated(&mut self) -> Result<(), StateMachineError> {
         // Check if the current state is Terminating
         match &self.state {
             ProviderState::Terminating(_) => {
                 // Update the state machine's state to Terminated
-                self.transition_to_state(ProviderState::Terminated(Terminated::new(msg, self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.transition_to_state(ProviderState::Terminated(Terminated::new(self.sent_message(), self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Cannot transition to Terminated from current state"),
+            _ => Err(self.invalid_transition("Terminated")),
         }
     }
 
-    pub fn send_contract_offer(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_offer_message: String) {
-        consumer.receive_contract_offer(contract_offer_message);
+    // Method to send a contract offer message to a consumer state machine in the same process
+    pub fn send_contract_offer(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_offer_message: ContractOfferMessage) -> Result<(), StateMachineError> {
+        consumer.receive_contract_offer(contract_offer_message)
     }
 
-    pub fn send_contract_agreement(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_agreement_message: String) {
-        consumer.receive_contract_agreement(contract_agreement_message);
+    // Method to send a contract agreement message to a consumer state machine in the same process
+    pub fn send_contract_agreement(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_agreement_message: ContractAgreementMessage) -> Result<(), StateMachineError> {
+        consumer.receive_contract_agreement(contract_agreement_message)
     }
 
-    pub fn send_contract_negotiation_event(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_negotiation_event_message: String) {
-        consumer.receive_contract_negotiation_event(contract_negotiation_event_message);
+    // Method to send a contract negotiation event message to a consumer state machine in the same process
+    pub fn send_contract_negotiation_event(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_negotiation_event_message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        consumer.receive_contract_negotiation_event(contract_negotiation_event_message)
     }
 
-    pub fn send_transfer_termination(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, transfer_termination_message: String) {
-        consumer.receive_transfer_termination(transfer_termination_message);
+    // Method to send a contract negotiation termination message to a consumer state machine in the same process
+    pub fn send_contract_negotiation_termination(&self, consumer: &mut ConsumerStateMachine<ConsumerState>, contract_negotiation_termination_message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        consumer.receive_contract_negotiation_termination(contract_negotiation_termination_message)
+    }
+
+    /// Dispatches a message received from the consumer
+    pub fn receive(&mut self, message: NegotiationMessage) -> Result<(), StateMachineError> {
+        match message {
+            NegotiationMessage::ContractRequest(message) => self.receive_contract_request(message),
+            NegotiationMessage::ContractNegotiationEvent(message) => self.receive_contract_negotiation_event(message),
+            NegotiationMessage::ContractAgreementVerification(message) => self.receive_contract_agreement_verification(message),
+            NegotiationMessage::ContractNegotiationTermination(message) => self.receive_contract_negotiation_termination(message),
+            message => Err(self.unexpected_message(&message)),
+        }
     }
 
     // Method to receive a contract request message from the consumer
-    pub fn receive_contract_request(&mut self, contract_request_message: String) {
-        match &mut self.state {
+    pub fn receive_contract_request(&mut self, contract_request_message: ContractRequestMessage) -> Result<(), StateMachineError> {
+        let consumer_pid = contract_request_message.consumer_pid.clone();
+        let message = NegotiationMessage::from(contract_request_message);
+        match &self.state {
             // If the provider is in the Initial or Offered state, he can receive a contract request
             // and transition to the Requested state
             // if the provider is in the Offering state, he should not receive a contract request
             // because he is waiting for the consumer to accept or decline the offer
-            ProviderState::Initial(_) => {
-                println!("Provider received contract request");
-                self.transition_to_state(ProviderState::Requested(Requested::new(contract_request_message.as_str(), self.negotiation_partner.as_str(), self.iri.as_str())));
+            ProviderState::Initial(_) | ProviderState::Offered(_) => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Provider received contract request");
+                self.consumer_pid = Some(consumer_pid);
+                self.transition_to_state(ProviderState::Requested(Requested::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            ProviderState::Offered(_) => {
-                println!("Provider received contract request");
-                self.transition_to_state(ProviderState::Requested(Requested::new(contract_request_message.as_str(), self.negotiation_partner.as_str(), self.iri.as_str())));
-            },
-            _ => println!("Provider cannot receive contract request in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
     // Method to receive a contract negotiation event message from the consumer
-    pub fn receive_contract_negotiation_event(&mut self, contract_negotiation_event_message: String) {
-        match &mut self.state {
+    pub fn receive_contract_negotiation_event(&mut self, contract_negotiation_event_message: ContractNegotiationEventMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(contract_negotiation_event_message);
+        match &self.state {
             // If the provider is in the Offered state, he can receive a contract negotiation event message
             // and transition to the Accepted state when the consumer accepts the offer
-            ProviderState::Offered(_) => {
-                println!("Provider received contract negotiation event message");
-                self.transition_to_state(ProviderState::Accepted(Accepted::new(contract_negotiation_event_message.as_str(), self.negotiation_partner.as_str(), self.iri.as_str())));
+            ProviderState::Offered(_) if message.event_type() == Some(NegotiationEventType::Accepted) => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Provider received contract negotiation event message");
+                self.transition_to_state(ProviderState::Accepted(Accepted::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Provider cannot receive contract negotiation event message in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
     // Method to receive a contract agreement verification message from the consumer
-    pub fn receive_contract_agreement_verification(&mut self, contract_agreement_verification_message: String) {
-        match &mut self.state {
+    pub fn receive_contract_agreement_verification(&mut self, contract_agreement_verification_message: ContractAgreementVerificationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(contract_agreement_verification_message);
+        match &self.state {
             // If the provider is in the Agreed state, he can receive a contract agreement verification message
             // and transition to the Verified state
             ProviderState::Agreed(_) => {
-                println!("Provider received contract agreement verification message");
-                self.transition_to_state(ProviderState::Verified(Verified::new(contract_agreement_verification_message.as_str(), self.negotiation_partner.as_str(), self.iri.as_str())));
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Provider received contract agreement verification message");
+                self.transition_to_state(ProviderState::Verified(Verified::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Provider cannot receive contract agreement verification message in current state"),
+            _ => Err(self.unexpected_message(&message)),
         }
     }
 
-    // Method to receive a transfer termination message from the consumer
-    pub fn receive_transfer_termination(&mut self, transfer_termination_message: String) {
-        match &mut self.state {
-            // If the provider is in the Finalized state, he can receive a transfer termination message
-            // and transition to the Terminated state
-            ProviderState::Finalized(_) => {
-                println!("Provider received transfer termination message");
-                self.transition_to_state(ProviderState::Terminated(Terminated::new(transfer_termination_message.as_str(), self.negotiation_partner.as_str(), self.iri.as_str())));
+    // Method to receive a contract negotiation termination message from the consumer
+    pub fn receive_contract_negotiation_termination(&mut self, contract_negotiation_termination_message: ContractNegotiationTerminationMessage) -> Result<(), StateMachineError> {
+        let message = NegotiationMessage::from(contract_negotiation_termination_message);
+        match &self.state {
+            // The consumer can terminate the negotiation as long as it has started and has not ended yet
+            ProviderState::Initial(_) | ProviderState::Finalized(_) | ProviderState::Terminated(_) => Err(self.unexpected_message(&message)),
+            _ => {
+                self.check_process_ids(&message)?;
+                tracing::debug!(iri = %self.iri, "Provider received contract negotiation termination message");
+                self.transition_to_state(ProviderState::Terminated(Terminated::new(message, self.negotiation_partner.as_str(), self.iri.as_str())));
+                Ok(())
             },
-            _ => println!("Provider cannot receive transfer termination message in current state"),
         }
     }
 
@@ -476,13 +808,13 @@ pub struct Requesting {
 }
 
 impl Requesting {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Requesting {
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Requesting".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -498,13 +830,13 @@ pub struct Offering {
 }
 
 impl Offering {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Offering {
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Offering".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -520,14 +852,14 @@ pub struct Requested {
 }
 
 impl Requested {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Requested {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Requested".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -543,14 +875,14 @@ pub struct Offered {
 }
 
 impl Offered {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Offered {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Offered".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -566,14 +898,14 @@ pub struct Accepting {
 }
 
 impl Accepting {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Accepting {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Accepting".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -589,14 +921,14 @@ pub struct Accepted {
 }
 
 impl Accepted {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Accepted {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Accepted".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -612,14 +944,14 @@ pub struct Agreeing {
 }
 
 impl Agreeing {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Agreeing {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Agreeing".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -635,14 +967,14 @@ pub struct Agreed {
 }
 
 impl Agreed {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Agreed {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Agreed".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -658,14 +990,14 @@ pub struct Verifying {
 }
 
 impl Verifying {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Verifying {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Verifying".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -681,14 +1013,14 @@ pub struct Verified {
 }
 
 impl Verified {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Verified {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Verified".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -704,14 +1036,14 @@ pub struct Finalizing {
 }
 
 impl Finalizing {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Finalizing {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Finalizing".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -727,14 +1059,14 @@ pub struct Finalized {
 }
 
 impl Finalized {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Finalized {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Finalized".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -750,14 +1082,14 @@ pub struct Terminating {
 }
 
 impl Terminating {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Terminating {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Terminating".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
@@ -773,81 +1105,87 @@ pub struct Terminated {
 }
 
 impl Terminated {
-    pub fn new(negotiation_message: &str, c_iri: &str, p_iri: &str) -> Self {
+    pub fn new(negotiation_message: NegotiationMessage, c_iri: &str, p_iri: &str) -> Self {
         Terminated {
             // Specify the values
             negotiation_state: NegotiationState::new(
                 c_iri.to_string(),
                 p_iri.to_string(),
                 "Terminated".to_string(),
-                negotiation_message.to_string(),
+                negotiation_message,
                 Local::now()
             ),
         }
     }
 }
 
-pub fn main() {
-    let mut simple_consumer_fsm = ConsumerStateMachine {
-        state: ConsumerState::Initial(Initial::new()),
-        iri: "http://example.com/consumer".to_string(),
-        negotiation_partner: "http://example.com/provider".to_string()
-    };
-    let mut simple_provider_fsm = ProviderStateMachine {
-        state: ProviderState::Initial(Initial::new()),
-        iri: "http://example.com/provider".to_string(),
-        negotiation_partner: "http://example.com/consumer".to_string()
+pub fn main() -> Result<(), StateMachineError> {
+    use edc_api::dsp::Agreement;
+    use edc_api::ContractOffer;
+
+    let mut simple_consumer_fsm = ConsumerStateMachine::new("http://example.com/consumer", "http://example.com/provider");
+    let mut simple_provider_fsm = ProviderStateMachine::new("http://example.com/provider", "http://example.com/consumer");
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
+
+    let provider_pid = simple_provider_fsm.provider_pid.clone();
+    let consumer_pid = simple_consumer_fsm.consumer_pid.clone();
+    let offer = ContractOffer {
+        at_id: "offer-1".to_string(),
+        assigner: Some("http://example.com/provider".to_string()),
+        target: Some("http://example.com/asset".to_string()),
+        ..ContractOffer::default()
     };
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
 
     // Consumer starting a request
-    simple_consumer_fsm.transition_to_requesting("Requesting contract");
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_consumer_fsm.send_contract_request(&mut simple_provider_fsm, "Contract request message".to_string());
-    simple_consumer_fsm.transition_to_requested("Requested contract");
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
+    let contract_request = ContractRequestMessage::new(consumer_pid.clone(), offer.clone(), "http://example.com/consumer/protocol".to_string());
+    simple_consumer_fsm.transition_to_requesting(contract_request.clone())?;
+    simple_consumer_fsm.send_contract_request(&mut simple_provider_fsm, contract_request)?;
+    simple_consumer_fsm.transition_to_requested()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
 
     // Provider counter offering
-    simple_provider_fsm.transition_to_offering("Offering contract");
-    simple_provider_fsm.send_contract_offer(&mut simple_consumer_fsm, "Contract offer message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_provider_fsm.transition_to_offered("Offered contract");
+    let contract_offer = ContractOfferMessage { consumer_pid: Some(consumer_pid.clone()), ..ContractOfferMessage::new(provider_pid.clone(), offer) };
+    simple_provider_fsm.transition_to_offering(contract_offer.clone())?;
+    simple_provider_fsm.send_contract_offer(&mut simple_consumer_fsm, contract_offer)?;
+    simple_provider_fsm.transition_to_offered()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
 
     // Consumer accepting the offer
-    simple_consumer_fsm.transition_to_accepting("Accepting contract");
-    simple_consumer_fsm.send_contract_negotiation_event(&mut simple_provider_fsm, "Contract negotiation event message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_consumer_fsm.transition_to_accepted("Accepted contract");
+    let accepted = ContractNegotiationEventMessage::new(provider_pid.clone(), consumer_pid.clone(), NegotiationEventType::Accepted);
+    simple_consumer_fsm.transition_to_accepting(accepted.clone())?;
+    simple_consumer_fsm.send_contract_negotiation_event(&mut simple_provider_fsm, accepted)?;
+    simple_consumer_fsm.transition_to_accepted()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
 
     // Provider agreeing to the contract
-    simple_provider_fsm.transition_to_agreeing("Agreeing contract");
-    simple_provider_fsm.send_contract_agreement(&mut simple_consumer_fsm, "Contract agreement message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_provider_fsm.transition_to_agreed("Agreed contract");
+    let agreement = Agreement::new("agreement-1".to_string(), Some("http://example.com/asset".to_string()),
+                                   Some("http://example.com/provider".to_string()), Some("http://example.com/consumer".to_string()));
+    let contract_agreement = ContractAgreementMessage::new(provider_pid.clone(), consumer_pid.clone(), agreement);
+    simple_provider_fsm.transition_to_agreeing(contract_agreement.clone())?;
+    simple_provider_fsm.send_contract_agreement(&mut simple_consumer_fsm, contract_agreement)?;
+    simple_provider_fsm.transition_to_agreed()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
 
     // Consumer verifying the agreement
-    simple_consumer_fsm.transition_to_verifying("Verifying agreement");
-    simple_consumer_fsm.send_contract_agreement_verification(&mut simple_provider_fsm, "Contract agreement verification message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_consumer_fsm.transition_to_verified("Verified agreement");
+    let verification = ContractAgreementVerificationMessage::new(provider_pid.clone(), consumer_pid.clone());
+    simple_consumer_fsm.transition_to_verifying(verification.clone())?;
+    simple_consumer_fsm.send_contract_agreement_verification(&mut simple_provider_fsm, verification)?;
+    simple_consumer_fsm.transition_to_verified()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
 
     // Provider finalizing the agreement
-    simple_provider_fsm.transition_to_finalizing("Finalizing agreement");
-    simple_provider_fsm.send_contract_negotiation_event(&mut simple_consumer_fsm, "Contract negotiation event message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-    simple_provider_fsm.transition_to_finalized("Finalized agreement");
-
-    // Consumer terminating the transfer
-    simple_consumer_fsm.transition_to_terminating("Terminating transfer");
-    simple_consumer_fsm.send_transfer_termination(&mut simple_provider_fsm, "Transfer termination message".to_string());
-    println!("Consumer State Machine: {:#?}", simple_consumer_fsm);
-    println!("Provider State Machine: {:#?}", simple_provider_fsm);
-}
\ No newline at end of file
+    let finalized = ContractNegotiationEventMessage::new(provider_pid, consumer_pid, NegotiationEventType::Finalized);
+    simple_provider_fsm.transition_to_finalizing(finalized.clone())?;
+    simple_provider_fsm.send_contract_negotiation_event(&mut simple_consumer_fsm, finalized)?;
+    simple_provider_fsm.transition_to_finalized()?;
+    tracing::info!(state = ?simple_consumer_fsm.state, "Consumer state machine");
+    tracing::info!(state = ?simple_provider_fsm.state, "Provider state machine");
+
+    Ok(())
+}
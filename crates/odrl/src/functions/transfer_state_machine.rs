@@ -0,0 +1,331 @@
+use chrono::{Local, DateTime};
+use edc_api::dsp::{TransferCompletionMessage, TransferRequestMessage, TransferStartMessage, TransferSuspensionMessage, TransferTerminationMessage};
+use edc_api::transfer_state::TransferProcessState;
+
+use crate::functions::state_machine::{check_process_id, StateMachineError};
+
+// The transfer state machine mirrors the states of a transfer process in the EDC. Consumer and provider pass through
+// different states: only the consumer requests a transfer, only the provider starts one. Staying in a state is always legal,
+// as the connector retries the -ing states until the counter party has received the message.
+
+/// Side of the transfer process a state machine keeps track of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferRole {
+    Consumer,
+    Provider,
+}
+
+/// Messages of the dataspace protocol that are exchanged during a transfer process
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferMessage {
+    TransferRequest(TransferRequestMessage),
+    TransferStart(TransferStartMessage),
+    TransferCompletion(TransferCompletionMessage),
+    TransferSuspension(TransferSuspensionMessage),
+    TransferTermination(TransferTerminationMessage),
+}
+
+impl TransferMessage {
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransferMessage::TransferRequest(_) => "TransferRequestMessage",
+            TransferMessage::TransferStart(_) => "TransferStartMessage",
+            TransferMessage::TransferCompletion(_) => "TransferCompletionMessage",
+            TransferMessage::TransferSuspension(_) => "TransferSuspensionMessage",
+            TransferMessage::TransferTermination(_) => "TransferTerminationMessage",
+        }
+    }
+
+    // Process ids of provider and consumer the message refers to
+    fn process_ids(&self) -> (Option<&str>, &str) {
+        match self {
+            TransferMessage::TransferRequest(message) => (None, &message.consumer_pid),
+            TransferMessage::TransferStart(message) => (Some(&message.provider_pid), &message.consumer_pid),
+            TransferMessage::TransferCompletion(message) => (Some(&message.provider_pid), &message.consumer_pid),
+            TransferMessage::TransferSuspension(message) => (Some(&message.provider_pid), &message.consumer_pid),
+            TransferMessage::TransferTermination(message) => (Some(&message.provider_pid), &message.consumer_pid),
+        }
+    }
+
+}
+
+impl From<TransferRequestMessage> for TransferMessage {
+    fn from(message: TransferRequestMessage) -> Self {
+        TransferMessage::TransferRequest(message)
+    }
+}
+
+impl From<TransferStartMessage> for TransferMessage {
+    fn from(message: TransferStartMessage) -> Self {
+        TransferMessage::TransferStart(message)
+    }
+}
+
+impl From<TransferCompletionMessage> for TransferMessage {
+    fn from(message: TransferCompletionMessage) -> Self {
+        TransferMessage::TransferCompletion(message)
+    }
+}
+
+impl From<TransferSuspensionMessage> for TransferMessage {
+    fn from(message: TransferSuspensionMessage) -> Self {
+        TransferMessage::TransferSuspension(message)
+    }
+}
+
+impl From<TransferTerminationMessage> for TransferMessage {
+    fn from(message: TransferTerminationMessage) -> Self {
+        TransferMessage::TransferTermination(message)
+    }
+}
+
+/// Name of the state as used by the management api
+pub fn state_name(state: TransferProcessState) -> &'static str {
+    match state {
+        TransferProcessState::Initial => "INITIAL",
+        TransferProcessState::Provisioning => "PROVISIONING",
+        TransferProcessState::ProvisioningRequested => "PROVISIONING_REQUESTED",
+        TransferProcessState::Provisioned => "PROVISIONED",
+        TransferProcessState::Requesting => "REQUESTING",
+        TransferProcessState::Requested => "REQUESTED",
+        TransferProcessState::Starting => "STARTING",
+        TransferProcessState::Started => "STARTED",
+        TransferProcessState::Suspending => "SUSPENDING",
+        TransferProcessState::Suspended => "SUSPENDED",
+        TransferProcessState::Resuming => "RESUMING",
+        TransferProcessState::Resumed => "RESUMED",
+        TransferProcessState::Completing => "COMPLETING",
+        TransferProcessState::Completed => "COMPLETED",
+        TransferProcessState::Terminating => "TERMINATING",
+        TransferProcessState::Terminated => "TERMINATED",
+        TransferProcessState::Deprovisioning => "DEPROVISIONING",
+        TransferProcessState::DeprovisioningRequested => "DEPROVISIONING_REQUESTED",
+        TransferProcessState::Deprovisioned => "DEPROVISIONED",
+    }
+}
+
+/// Whether no data will be transferred anymore. Completed and terminated processes may still be deprovisioned.
+pub fn is_final(state: TransferProcessState) -> bool {
+    use TransferProcessState::*;
+    matches!(state, Completed | Terminated | Deprovisioning | DeprovisioningRequested | Deprovisioned)
+}
+
+/// States that can directly follow the given state on the given side of the transfer process
+pub fn legal_transitions(role: TransferRole, state: TransferProcessState) -> &'static [TransferProcessState] {
+    use TransferProcessState::*;
+    match (role, state) {
+        // Provisioning of the resources, on both sides
+        (_, Initial) => &[Provisioning, Terminating, Terminated],
+        (_, Provisioning) => &[ProvisioningRequested, Provisioned, Terminating, Terminated],
+        (_, ProvisioningRequested) => &[Provisioned, Terminating, Terminated],
+
+        // The consumer requests the transfer and waits for the start message of the provider
+        (TransferRole::Consumer, Provisioned) => &[Requesting, Terminating, Terminated],
+        (TransferRole::Consumer, Requesting) => &[Requested, Terminating, Terminated],
+        (TransferRole::Consumer, Requested) => &[Started, Terminating, Terminated],
+        (TransferRole::Consumer, Starting) => &[],
+
+        // The provider starts the transfer once it is provisioned
+        (TransferRole::Provider, Provisioned) => &[Starting, Terminating, Terminated],
+        (TransferRole::Provider, Requesting) => &[],
+        (TransferRole::Provider, Requested) => &[],
+        (TransferRole::Provider, Starting) => &[Started, Terminating, Terminated],
+
+        // Running transfers can be suspended and resumed by both sides
+        (_, Started) => &[Suspending, Suspended, Completing, Completed, Terminating, Terminated],
+        (_, Suspending) => &[Suspended, Terminating, Terminated],
+        (TransferRole::Consumer, Suspended) => &[Resuming, Started, Terminating, Terminated],
+        (TransferRole::Consumer, Resuming) => &[Resumed, Started, Terminating, Terminated],
+        (TransferRole::Consumer, Resumed) => &[Started, Terminating, Terminated],
+        (TransferRole::Provider, Suspended) => &[Resuming, Starting, Started, Terminating, Terminated],
+        (TransferRole::Provider, Resuming) => &[Resumed, Starting, Started, Terminating, Terminated],
+        (TransferRole::Provider, Resumed) => &[Starting, Started, Terminating, Terminated],
+
+        // Ending the transfer
+        (_, Completing) => &[Completed, Terminating, Terminated],
+        (_, Completed) => &[Deprovisioning],
+        (_, Terminating) => &[Terminated],
+        (_, Terminated) => &[Deprovisioning],
+        (_, Deprovisioning) => &[DeprovisioningRequested, Deprovisioned],
+        (_, DeprovisioningRequested) => &[Deprovisioned],
+        (_, Deprovisioned) => &[],
+    }
+}
+
+/// Whether the target state can directly follow the given state
+pub fn is_legal_transition(role: TransferRole, state: TransferProcessState, target: TransferProcessState) -> bool {
+    state == target || legal_transitions(role, state).contains(&target)
+}
+
+/// Whether the target state can be reached from the given state in any number of transitions. States polled from a
+/// connector may skip the short-lived ones in between.
+pub fn is_reachable(role: TransferRole, state: TransferProcessState, target: TransferProcessState) -> bool {
+    let mut visited = vec![state];
+    let mut pending = vec![state];
+    while let Some(current) = pending.pop() {
+        if current == target {
+            return true;
+        }
+        for next in legal_transitions(role, current) {
+            if !visited.contains(next) {
+                visited.push(*next);
+                pending.push(*next);
+            }
+        }
+    }
+    false
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferStateMachine {
+    pub role: TransferRole,
+    pub state: TransferProcessState,
+    pub consumer_pid: Option<String>,       // process id on the consumer side
+    pub provider_pid: Option<String>,       // process id on the provider side
+    pub timestamp: DateTime<Local>,         // Timestamp of the last transition
+}
+
+impl TransferStateMachine {
+
+    /// Creates a state machine in the initial state. The process id of the own side is generated, the one of the counter
+    /// party is learned from the first message it sends.
+    pub fn new(role: TransferRole) -> Self {
+        let pid = Some(uuid::Uuid::new_v4().to_string());
+        let (consumer_pid, provider_pid) = match role {
+            TransferRole::Consumer => (pid, None),
+            TransferRole::Provider => (None, pid),
+        };
+        TransferStateMachine {
+            role,
+            state: TransferProcessState::Initial,
+            consumer_pid,
+            provider_pid,
+            timestamp: Local::now(),
+        }
+    }
+
+    /// Creates a state machine for a transfer process that is already known, e.g. one returned by the management api
+    pub fn with_state(role: TransferRole, state: TransferProcessState) -> Self {
+        TransferStateMachine {
+            state,
+            ..TransferStateMachine::new(role)
+        }
+    }
+
+    pub fn is_final(&self) -> bool {
+        is_final(self.state)
+    }
+
+    /// Moves to the target state if it can directly follow the current one
+    pub fn transition_to(&mut self, target: TransferProcessState) -> Result<(), StateMachineError> {
+        if !is_legal_transition(self.role, self.state, target) {
+            return Err(StateMachineError::InvalidTransition { state: state_name(self.state), target: state_name(target) });
+        }
+        self.state = target;
+        self.timestamp = Local::now();
+        tracing::debug!(role = ?self.role, state = ?self.state, "Transfer state transitioned");
+        Ok(())
+    }
+
+    /// Follows a state reported by a connector, which may have passed through other states since it was last observed
+    pub fn observe(&mut self, reported: TransferProcessState) -> Result<(), StateMachineError> {
+        if !is_reachable(self.role, self.state, reported) {
+            return Err(StateMachineError::InvalidTransition { state: state_name(self.state), target: state_name(reported) });
+        }
+        if reported != self.state {
+            self.state = reported;
+            self.timestamp = Local::now();
+            tracing::debug!(role = ?self.role, state = ?self.state, "Transfer state observed");
+        }
+        Ok(())
+    }
+
+    pub fn suspend(&mut self) -> Result<(), StateMachineError> {
+        self.transition_to(TransferProcessState::Suspending)
+    }
+
+    pub fn resume(&mut self) -> Result<(), StateMachineError> {
+        self.transition_to(TransferProcessState::Resuming)
+    }
+
+    pub fn complete(&mut self) -> Result<(), StateMachineError> {
+        self.transition_to(TransferProcessState::Completing)
+    }
+
+    pub fn terminate(&mut self) -> Result<(), StateMachineError> {
+        self.transition_to(TransferProcessState::Terminating)
+    }
+
+    fn unexpected_message(&self, message: &TransferMessage) -> StateMachineError {
+        tracing::debug!(role = ?self.role, state = ?self.state, message = message.name(), "Cannot receive transfer message in current state");
+        StateMachineError::UnexpectedMessage { state: state_name(self.state), message: message.name() }
+    }
+
+    // Both process ids of a message have to match the transfer process as soon as they are known
+    fn check_process_ids(&self, message: &TransferMessage) -> Result<(), StateMachineError> {
+        let (provider_pid, consumer_pid) = message.process_ids();
+        check_process_id(self.provider_pid.as_deref(), provider_pid)?;
+        check_process_id(self.consumer_pid.as_deref(), Some(consumer_pid))
+    }
+
+    /// Handles a message of the counter party. The message is rejected if it is not expected in the current state, in
+    /// which case the state machine is left unchanged.
+    pub fn receive(&mut self, message: TransferMessage) -> Result<(), StateMachineError> {
+        use TransferProcessState::*;
+
+        let target = match (self.role, &message, self.state) {
+            // The provider learns about a transfer process with its request
+            (TransferRole::Provider, TransferMessage::TransferRequest(_), Initial) => None,
+            // The provider starts a requested transfer, or resumes a suspended one
+            (TransferRole::Consumer, TransferMessage::TransferStart(_), Requested | Suspended | Resuming | Resumed) => Some(Started),
+            // The consumer resumes a suspended transfer
+            (TransferRole::Provider, TransferMessage::TransferStart(_), Suspended | Resuming | Resumed) => Some(Started),
+            (_, TransferMessage::TransferCompletion(_), Started | Completing) => Some(Completed),
+            (_, TransferMessage::TransferSuspension(_), Started | Suspending) => Some(Suspended),
+            (_, TransferMessage::TransferTermination(_), state) if !is_final(state) => Some(Terminated),
+            _ => return Err(self.unexpected_message(&message)),
+        };
+        if target.is_some_and(|target| !is_legal_transition(self.role, self.state, target)) {
+            return Err(self.unexpected_message(&message));
+        }
+        self.check_process_ids(&message)?;
+
+        let (provider_pid, consumer_pid) = message.process_ids();
+        if self.provider_pid.is_none() {
+            self.provider_pid = provider_pid.map(|pid| pid.to_string());
+        }
+        if self.consumer_pid.is_none() {
+            self.consumer_pid = Some(consumer_pid.to_string());
+        }
+
+        tracing::debug!(role = ?self.role, message = message.name(), "Transfer message received");
+        if let Some(target) = target {
+            self.state = target;
+            self.timestamp = Local::now();
+            tracing::debug!(role = ?self.role, state = ?self.state, "Transfer state transitioned");
+        }
+        Ok(())
+    }
+
+    pub fn receive_transfer_request(&mut self, transfer_request_message: TransferRequestMessage) -> Result<(), StateMachineError> {
+        self.receive(transfer_request_message.into())
+    }
+
+    pub fn receive_transfer_start(&mut self, transfer_start_message: TransferStartMessage) -> Result<(), StateMachineError> {
+        self.receive(transfer_start_message.into())
+    }
+
+    pub fn receive_transfer_completion(&mut self, transfer_completion_message: TransferCompletionMessage) -> Result<(), StateMachineError> {
+        self.receive(transfer_completion_message.into())
+    }
+
+    pub fn receive_transfer_suspension(&mut self, transfer_suspension_message: TransferSuspensionMessage) -> Result<(), StateMachineError> {
+        self.receive(transfer_suspension_message.into())
+    }
+
+    pub fn receive_transfer_termination(&mut self, transfer_termination_message: TransferTerminationMessage) -> Result<(), StateMachineError> {
+        self.receive(transfer_termination_message.into())
+    }
+
+}
@@ -6,10 +6,13 @@ extern crate url;
 extern crate reqwest;
 
 pub mod functions {
+    pub mod edc_policy;
+    pub mod evaluator;
     pub mod json_ld_serializer;
     pub mod json_parser;
     pub mod json_serializer;
     pub mod state_machine;
+    pub mod transfer_state_machine;
     pub mod validator;
     pub mod vocabulary;
 }
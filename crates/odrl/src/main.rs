@@ -1,7 +0,0 @@
-pub mod edc_connector;
-
-pub fn main() {
-
-    let _ = edc_connector::main();
-
-}
\ No newline at end of file
@@ -128,4 +128,22 @@ impl Default for Policy {
         // Default to SetPolicy
         Policy::SetPolicy(SetPolicy::default())
     }
+}
+
+impl From<SetPolicy> for Policy {
+    fn from(policy: SetPolicy) -> Self {
+        Policy::SetPolicy(policy)
+    }
+}
+
+impl From<OfferPolicy> for Policy {
+    fn from(policy: OfferPolicy) -> Self {
+        Policy::OfferPolicy(policy)
+    }
+}
+
+impl From<AgreementPolicy> for Policy {
+    fn from(policy: AgreementPolicy) -> Self {
+        Policy::AgreementPolicy(policy)
+    }
 }
\ No newline at end of file
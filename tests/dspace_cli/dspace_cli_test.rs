@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod dspace_cli_test {
+    extern crate dspace_cli;
+    extern crate edc_server;
+
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use clap::Parser;
+    use dspace_cli::cli::Cli;
+    use dspace_cli::config::Config;
+    use dspace_cli::CliError;
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+    use edc_server::InMemoryStore;
+    use uuid::Uuid;
+
+    /// Serves management and protocol api from one store, returns the base path of the management api
+    async fn setup_server() -> String {
+        let mut store = InMemoryStore::with_participant_id("provider");
+        store.processing_delay = Duration::from_millis(10);
+        let store = Arc::new(store);
+
+        let management = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = management.local_addr().unwrap();
+        tokio::spawn(edc_server::serve(management, "/management", store));
+        format!("http://{}/management", address)
+    }
+
+    async fn dspace(base_path: &str, args: &[&str]) -> Result<String, CliError> {
+        let cli = Cli::try_parse_from(["dspace", "--base-path", base_path].iter().chain(args)).unwrap();
+        let mut out = Vec::new();
+        dspace_cli::run(cli, &mut out).await?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    fn write_file(content: &str) -> PathBuf {
+        write_file_with_extension(content, "json")
+    }
+
+    fn write_file_with_extension(content: &str, extension: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("dspace-{}.{}", Uuid::new_v4(), extension));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_assets() {
+        let base_path = setup_server().await;
+
+        let created = dspace(&base_path, &["assets", "create", "--id", "asset-1", "--base-url", "https://example.com", "--property", "name=Example"]).await.unwrap();
+        assert!(created.starts_with("ID"));
+        assert!(created.contains("asset-1"));
+
+        let listed = dspace(&base_path, &["assets", "list"]).await.unwrap();
+        let lines = listed.lines().collect::<Vec<_>>();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with("ID       NAME     DATA ADDRESS  CREATED AT"));
+        assert!(lines[1].starts_with("asset-1  Example  HttpData"));
+
+        let json: serde_json::Value = serde_json::from_str(&dspace(&base_path, &["-o", "json", "assets", "get", "asset-1"]).await.unwrap()).unwrap();
+        assert_eq!("asset-1", json["@id"]);
+
+        dspace(&base_path, &["assets", "delete", "asset-1"]).await.unwrap();
+        match dspace(&base_path, &["assets", "get", "asset-1"]).await {
+            Err(CliError::Api(message)) => assert!(message.starts_with("status code 404"), "{}", message),
+            other => panic!("Expected a not found error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_policies() {
+        let base_path = setup_server().await;
+        let file = write_file(r#"[
+            {"@id": "policy-1", "policy": {"@context": "http://www.w3.org/ns/odrl.jsonld", "@type": "Set", "permission": [{"action": "use"}]}},
+            {"@id": "policy-2", "policy": {"@context": "http://www.w3.org/ns/odrl.jsonld", "@type": "Set"}}
+        ]"#);
+        let file = file.to_str().unwrap();
+
+        let applied = dspace(&base_path, &["-o", "json", "policies", "apply", "-f", file]).await.unwrap();
+        let applied: serde_json::Value = serde_json::from_str(&applied).unwrap();
+        assert_eq!(serde_json::json!([{"@id": "policy-1", "result": "created"}, {"@id": "policy-2", "result": "created"}]), applied);
+
+        let applied = dspace(&base_path, &["policies", "apply", "-f", file]).await.unwrap();
+        assert_eq!("ID        RESULT\npolicy-1  updated\npolicy-2  updated\n", applied);
+
+        let listed = dspace(&base_path, &["policies", "list", "--limit", "1"]).await.unwrap();
+        assert_eq!(2, listed.lines().count());
+    }
+
+    #[tokio::test]
+    async fn test_apply_manifest() {
+        let base_path = setup_server().await;
+        let manifest = write_file_with_extension(r#"
+policyDefinitions:
+  - "@id": open
+    policy:
+      "@context": http://www.w3.org/ns/odrl.jsonld
+      "@type": Set
+assets:
+  - "@id": asset-1
+    properties:
+      name: Example
+    dataAddress:
+      type: HttpData
+      baseUrl: https://example.com
+contractDefinitions:
+  - "@id": definition-1
+    accessPolicyId: open
+    contractPolicyId: open
+    assetsSelector: []
+"#, "yaml");
+        let manifest = manifest.to_str().unwrap();
+        dspace(&base_path, &["assets", "create", "--id", "leftover", "--base-url", "https://example.com"]).await.unwrap();
+
+        let plan = dspace(&base_path, &["apply", "-f", manifest, "--prune", "--dry-run"]).await.unwrap();
+        assert_eq!(concat!(
+            "ACTION  KIND                 ID            CHANGES\n",
+            "create  policy definition    open\n",
+            "create  asset                asset-1\n",
+            "create  contract definition  definition-1\n",
+            "delete  asset                leftover\n",
+        ), plan);
+        assert_eq!(1, dspace(&base_path, &["contract-definitions", "list"]).await.unwrap().lines().count());
+
+        dspace(&base_path, &["apply", "-f", manifest, "--prune"]).await.unwrap();
+        let applied = dspace(&base_path, &["-o", "json", "apply", "-f", manifest, "--prune"]).await.unwrap();
+        let applied: serde_json::Value = serde_json::from_str(&applied).unwrap();
+        assert!(applied.as_array().unwrap().iter().all(|step| step["action"] == "unchanged"), "{}", applied);
+        assert_eq!(2, dspace(&base_path, &["assets", "list"]).await.unwrap().lines().count());
+
+        let invalid = write_file(r#"{"assets": [{"properties": {}, "dataAddress": {"type": "HttpData"}}]}"#);
+        match dspace(&base_path, &["apply", "-f", invalid.to_str().unwrap()]).await {
+            Err(CliError::Input(message)) => assert_eq!("asset without @id in the manifest", message),
+            other => panic!("Expected an input error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_and_transfer() {
+        let base_path = setup_server().await;
+        let counterparty = "http://localhost/protocol";
+
+        dspace(&base_path, &["assets", "create", "--id", "asset-1", "--base-url", "https://example.com"]).await.unwrap();
+        let policy = write_file(r#"{"@id": "open", "policy": {"@context": "http://www.w3.org/ns/odrl.jsonld", "@type": "Set"}}"#);
+        dspace(&base_path, &["policies", "apply", "-f", policy.to_str().unwrap()]).await.unwrap();
+        let contract_definition = write_file(r#"{"@id": "definition-1", "accessPolicyId": "open", "contractPolicyId": "open", "assetsSelector": []}"#);
+        dspace(&base_path, &["contract-definitions", "apply", "-f", contract_definition.to_str().unwrap()]).await.unwrap();
+
+        let catalog = dspace(&base_path, &["catalog", "fetch", "--counterparty", counterparty]).await.unwrap();
+        assert!(catalog.lines().any(|line| line.starts_with("asset-1")));
+
+        let negotiation = dspace(&base_path, &["-o", "json", "negotiate", "--counterparty", counterparty, "--counterparty-id", "provider", "--asset", "asset-1", "--wait"]).await.unwrap();
+        let negotiation: serde_json::Value = serde_json::from_str(&negotiation).unwrap();
+        assert_eq!("FINALIZED", negotiation["state"]);
+        let agreement_id = negotiation["contractAgreementId"].as_str().unwrap();
+
+        let transfer = dspace(&base_path, &["-o", "json", "transfer", "--counterparty", counterparty, "--agreement", agreement_id, "--wait"]).await.unwrap();
+        let transfer: serde_json::Value = serde_json::from_str(&transfer).unwrap();
+        assert_eq!("STARTED", transfer["state"]);
+        assert_eq!("asset-1", transfer["assetId"]);
+
+        let edrs = dspace(&base_path, &["edr", "list"]).await.unwrap();
+        assert!(edrs.contains(transfer["@id"].as_str().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_missing_ids() {
+        let server = MockServer::start().await.unwrap();
+        let counterparty = "http://localhost/protocol";
+        server.respond(Method::POST, "/v2/contractnegotiations", MockResponse::json(StatusCode::OK, &serde_json::json!({})));
+        server.respond(Method::POST, "/v2/transferprocesses", MockResponse::json(StatusCode::OK, &serde_json::json!({})));
+
+        let args = ["negotiate", "--counterparty", counterparty, "--counterparty-id", "provider", "--asset", "asset-1", "--offer", "offer-1"];
+        match dspace(&server.url(), &args).await {
+            Err(CliError::Api(message)) => assert!(message.contains("no id for the contract negotiation"), "{}", message),
+            other => panic!("Expected an api error, got {:?}", other),
+        }
+
+        let args = ["transfer", "--counterparty", counterparty, "--agreement", "agreement-1", "--asset", "asset-1"];
+        match dspace(&server.url(), &args).await {
+            Err(CliError::Api(message)) => assert!(message.contains("no id for the transfer process"), "{}", message),
+            other => panic!("Expected an api error, got {:?}", other),
+        }
+        assert!(server.requests_to(Method::GET, "/v2/*/*").is_empty());
+    }
+
+    #[test]
+    fn test_profiles() {
+        let config = Config::parse(r#"
+            default = "provider"
+
+            [profiles.provider]
+            base_path = "http://localhost:29193/management/"
+            api_key = "123456"
+
+            [profiles.consumer]
+            base_path = "http://localhost:19193/management"
+            api_key_env = "DSPACE_TEST_UNSET_API_KEY"
+        "#).unwrap();
+
+        let provider = config.profile(None).unwrap().configuration().unwrap();
+        assert_eq!("http://localhost:29193/management", provider.base_path);
+        assert_eq!("123456", provider.api_key.unwrap().key);
+
+        assert!(matches!(config.profile(Some("consumer")).unwrap().configuration(), Err(CliError::Config(_))));
+        assert!(matches!(config.profile(Some("unknown")), Err(CliError::Config(_))));
+        assert!(Config::parse("[profiles.provider]\nbase_url = \"http://localhost\"").is_err());
+    }
+
+}
@@ -0,0 +1,280 @@
+#[cfg(test)]
+mod edc_api_test {
+    extern crate edc_api;
+
+    use edc_api::{Catalog, ContractDefinitionOutput, ContractNegotiationState, DataAddress, CriterionOperator, Event, EventEnvelope, NegotiationState, Query, QuerySpec, TransferState};
+    use edc_api::data_address::{AzureStorageDataAddress, HttpDataAddress, KafkaDataAddress, S3DataAddress, TypedDataAddress};
+    use edc_api::query::PropertyPath;
+    use edc_api::query_spec::SortOrder;
+    use edc_api::transfer_state::TransferProcessState;
+
+    #[test]
+    fn test_negotiation_state_serialization() {
+        let state = NegotiationState { state: ContractNegotiationState::Finalized };
+        let json = serde_json::to_value(state).unwrap();
+        assert_eq!(json, serde_json::json!({"state": "FINALIZED"}));
+        assert_eq!(serde_json::from_value::<NegotiationState>(json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_transfer_state_serialization() {
+        let state = TransferState { state: TransferProcessState::DeprovisioningRequested };
+        let json = serde_json::to_value(state).unwrap();
+        assert_eq!(json, serde_json::json!({"state": "DEPROVISIONING_REQUESTED"}));
+        assert_eq!(serde_json::from_value::<TransferState>(json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_query_spec_deserialization() {
+        let json = r#"
+        {
+            "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+            "@type": "QuerySpec",
+            "offset": 0,
+            "limit": 10,
+            "sortOrder": "ASC",
+            "sortField": "id",
+            "filterExpression": [{ "operandLeft": "id", "operator": "=", "operandRight": "asset-1" }]
+        }
+        "#;
+
+        let query_spec: QuerySpec = serde_json::from_str(json).unwrap();
+        assert_eq!(query_spec.limit, Some(10));
+        assert_eq!(query_spec.offset, Some(0));
+        assert_eq!(query_spec.filter_expression.len(), 1);
+        assert_eq!(query_spec.filter_expression[0].operator, CriterionOperator::Eq);
+    }
+
+    #[test]
+    fn test_catalog_deserialization() {
+        // Compacted JSON-LD as returned by the EDC: single values are not wrapped in arrays and references are node objects
+        let json = r#"
+        {
+            "@id": "7df65569-8c59-4013-b1c0-fa14f6641bf2",
+            "@type": "dcat:Catalog",
+            "dcat:dataset": {
+                "@id": "asset-1",
+                "@type": "dcat:Dataset",
+                "odrl:hasPolicy": {
+                    "@id": "def-1:asset-1:a1b2",
+                    "@type": "odrl:Offer",
+                    "odrl:permission": [],
+                    "odrl:prohibition": [],
+                    "odrl:obligation": [],
+                    "odrl:target": { "@id": "asset-1" }
+                },
+                "dcat:distribution": [{
+                    "@type": "dcat:Distribution",
+                    "dct:format": { "@id": "HttpData-PULL" },
+                    "dcat:accessService": "a2a5ef73-ba3a-4ce1-9d1f-4fa2b7a1b3c9"
+                }],
+                "name": "product description",
+                "id": "asset-1"
+            },
+            "dcat:service": {
+                "@id": "a2a5ef73-ba3a-4ce1-9d1f-4fa2b7a1b3c9",
+                "@type": "dcat:DataService",
+                "dct:terms": "connector",
+                "dct:endpointUrl": "http://provider:19194/protocol"
+            },
+            "dspace:participantId": "provider",
+            "@context": {
+                "@vocab": "https://w3id.org/edc/v0.0.1/ns/",
+                "dcat": "http://www.w3.org/ns/dcat#"
+            }
+        }
+        "#;
+
+        let catalog: Catalog = serde_json::from_str(json).unwrap();
+        assert_eq!(catalog.participant_id.as_deref(), Some("provider"));
+        assert_eq!(catalog.service[0].endpoint_url.as_deref(), Some("http://provider:19194/protocol"));
+
+        let dataset = catalog.find_dataset("asset-1").unwrap();
+        assert_eq!(dataset.properties.get("name"), Some(&serde_json::json!("product description")));
+
+        let offer = dataset.find_offer("def-1:asset-1:a1b2").unwrap();
+        assert_eq!(offer.target.as_deref(), Some("asset-1"));
+        assert!(offer.permission.is_empty());
+
+        let distribution = &dataset.distribution[0];
+        assert_eq!(distribution.format.as_deref(), Some("HttpData-PULL"));
+        assert_eq!(distribution.access_service.as_ref().and_then(|service| service.at_id.as_deref()), Some("a2a5ef73-ba3a-4ce1-9d1f-4fa2b7a1b3c9"));
+    }
+
+    #[test]
+    fn test_query_builder() {
+        let query_spec = Query::assets()
+            .where_("https://w3id.org/edc/v0.0.1/ns/id").eq("asset-1")
+            .and("name").in_(["a", "b"])
+            .and("dct:title").ilike("%report%")
+            .sort_by("edc:createdAt", SortOrder::Desc)
+            .limit(50)
+            .build();
+
+        let json = serde_json::to_value(&query_spec).unwrap();
+        assert_eq!(json, serde_json::json!({
+            "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+            "@type": "QuerySpec",
+            "filterExpression": [
+                { "@type": "Criterion", "operandLeft": "https://w3id.org/edc/v0.0.1/ns/id", "operator": "=", "operandRight": "asset-1" },
+                { "@type": "Criterion", "operandLeft": "https://w3id.org/edc/v0.0.1/ns/name", "operator": "in", "operandRight": ["a", "b"] },
+                { "@type": "Criterion", "operandLeft": "http://purl.org/dc/terms/title", "operator": "ilike", "operandRight": "%report%" },
+            ],
+            "limit": 50,
+            "sortField": "https://w3id.org/edc/v0.0.1/ns/createdAt",
+            "sortOrder": "DESC",
+        }));
+
+        // Field paths of other resources are not expanded
+        let criteria = Query::new().where_("contractAgreement.assetId").ne("asset-1").criteria();
+        assert_eq!("contractAgreement.assetId", criteria[0].operand_left);
+        assert_eq!(CriterionOperator::Ne, criteria[0].operator);
+    }
+
+    #[test]
+    fn test_property_path() {
+        let path = PropertyPath::parse("privateProperties.'https://w3id.org/edc/v0.0.1/ns/key'");
+        assert_eq!(["privateProperties", "https://w3id.org/edc/v0.0.1/ns/key"], path.segments());
+        assert_eq!("privateProperties.'https://w3id.org/edc/v0.0.1/ns/key'", path.to_string());
+
+        // Unquoted IRIs extend to the end of the path
+        let path = PropertyPath::parse("https://w3id.org/edc/v0.0.1/ns/id");
+        assert_eq!(["https://w3id.org/edc/v0.0.1/ns/id"], path.segments());
+        assert_eq!("https://w3id.org/edc/v0.0.1/ns/id", path.to_string());
+
+        let path = PropertyPath::parse("policy.odrl:permission.action");
+        assert_eq!(["policy", "http://www.w3.org/ns/odrl/2/permission", "action"], path.segments());
+        assert_eq!("policy.'http://www.w3.org/ns/odrl/2/permission'.action", path.to_string());
+    }
+
+    #[test]
+    fn test_criterion_operator() {
+        for operator in CriterionOperator::ALL {
+            assert_eq!(Ok(operator.clone()), operator.as_str().parse());
+            assert_eq!(serde_json::json!(operator.as_str()), serde_json::to_value(&operator).unwrap());
+        }
+        assert_eq!(Ok(CriterionOperator::ILike), "ILIKE".parse());
+        assert_eq!(Ok(CriterionOperator::Ge), ">=".parse());
+        assert_eq!(Ok(CriterionOperator::Other("==".to_string())), "==".parse());
+        assert_eq!(CriterionOperator::In, serde_json::from_value(serde_json::json!("IN")).unwrap());
+
+        // Operators a connector registers itself are kept as they are
+        let operator = serde_json::from_value::<CriterionOperator>(serde_json::json!("~")).unwrap();
+        assert_eq!(CriterionOperator::Other("~".to_string()), operator);
+        assert_eq!(serde_json::json!("~"), serde_json::to_value(&operator).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_operator_deserialization() {
+        let contract_definition: ContractDefinitionOutput = serde_json::from_value(serde_json::json!({
+            "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+            "@id": "contract-definition-1",
+            "@type": "ContractDefinition",
+            "accessPolicyId": "access-policy",
+            "contractPolicyId": "contract-policy",
+            "assetsSelector": [
+                { "@type": "Criterion", "operandLeft": "https://w3id.org/edc/v0.0.1/ns/version", "operator": "<=", "operandRight": 2 },
+                { "@type": "Criterion", "operandLeft": "https://w3id.org/edc/v0.0.1/ns/name", "operator": "regex", "operandRight": "^weather" },
+            ],
+        })).unwrap();
+
+        let operators: Vec<_> = contract_definition.assets_selector.unwrap().into_iter().map(|criterion| criterion.operator).collect();
+        assert_eq!(vec![CriterionOperator::Le, CriterionOperator::Other("regex".to_string())], operators);
+    }
+
+    #[test]
+    fn test_event_deserialization() {
+        let json = r#"
+        {
+            "id": "6b9a9d44-3e44-4b2c-a1b4-3c0d0a4b3b2e",
+            "at": 1718000000000,
+            "payload": {
+                "contractNegotiationId": "negotiation-1",
+                "counterPartyAddress": "http://provider-connector:9194/protocol",
+                "counterPartyId": "provider",
+                "callbackAddresses": [{ "uri": "http://consumer-backend/callbacks", "events": ["contract.negotiation"], "transactional": false }],
+                "contractOffers": [],
+                "protocol": "dataspace-protocol-http",
+                "contractAgreement": { "id": "agreement-1", "assetId": "asset-1", "policy": {} }
+            },
+            "type": "ContractNegotiationFinalized"
+        }
+        "#;
+
+        let envelope: EventEnvelope = serde_json::from_str(json).unwrap();
+        assert_eq!(1718000000000, envelope.at);
+        assert_eq!("contract.negotiation.finalized", envelope.event.name());
+        let Event::ContractNegotiationFinalized(negotiation) = &envelope.event else {
+            panic!("Unexpected event {:?}", envelope.event);
+        };
+        assert_eq!("negotiation-1", negotiation.contract_negotiation_id);
+        assert_eq!(Some("asset-1"), negotiation.contract_agreement.as_ref().and_then(|agreement| agreement["assetId"].as_str()));
+        assert_eq!(envelope, serde_json::from_value(serde_json::to_value(&envelope).unwrap()).unwrap());
+
+        let envelope: EventEnvelope = serde_json::from_value(serde_json::json!({
+            "id": "1", "at": 0, "type": "TransferProcessProvisioningRequested", "payload": { "transferProcessId": "transfer-1", "type": "CONSUMER" }
+        })).unwrap();
+        assert_eq!("transfer.process.provisioningRequested", envelope.event.name());
+        assert_eq!(Some("CONSUMER"), envelope.event.transfer_process().and_then(|transfer| transfer.r#type.as_deref()));
+
+        // Events without a typed variant are kept as they are
+        let envelope: EventEnvelope = serde_json::from_value(serde_json::json!({
+            "id": "2", "at": 0, "type": "AssetCreated", "payload": { "assetId": "asset-1" }
+        })).unwrap();
+        assert_eq!(Event::Other { r#type: "AssetCreated".to_string(), payload: serde_json::json!({ "assetId": "asset-1" }) }, envelope.event);
+        assert_eq!("AssetCreated", envelope.event.name());
+        assert!(envelope.event.contract_negotiation().is_none());
+    }
+
+    #[test]
+    fn test_data_address_round_trip() {
+        let json = serde_json::json!({
+            "@type": "DataAddress",
+            "type": "HttpData",
+            "baseUrl": "https://example.com/api",
+            "proxyPath": "true",
+            "proxyBody": "false",
+            "authKey": "Authorization",
+            "secretName": "api-token",
+            "header:Accept": "application/json",
+            "nonChunkedTransfer": true
+        });
+
+        let data_address: DataAddress = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(Some("https://example.com/api".to_string()), data_address.base_url);
+        assert_eq!(Some(&serde_json::json!(true)), data_address.property("nonChunkedTransfer"));
+        assert_eq!(json, serde_json::to_value(&data_address).unwrap());
+
+        let TypedDataAddress::HttpData(http_data) = data_address.typed().unwrap() else {
+            panic!("Expected an HttpData address");
+        };
+        assert_eq!(Some(true), http_data.proxy_path);
+        assert_eq!(Some(false), http_data.proxy_body);
+        assert_eq!(None, http_data.proxy_method);
+        assert_eq!(vec![("Accept", "application/json")], http_data.headers().collect::<Vec<_>>());
+        assert_eq!(data_address, DataAddress::from(http_data));
+    }
+
+    #[test]
+    fn test_typed_data_addresses() {
+        let s3 = DataAddress::from(S3DataAddress { object_name: Some("report.csv".to_string()), ..S3DataAddress::new("eu-central-1", "reports") });
+        assert_eq!(Some("AmazonS3".to_string()), s3.r#type);
+        assert_eq!(Some(&serde_json::json!("reports")), s3.property("bucketName"));
+
+        let azure = DataAddress::from(AzureStorageDataAddress::new("account", "container"));
+        assert_eq!(Some(&serde_json::json!("container")), azure.property("container"));
+
+        let kafka = DataAddress::from(KafkaDataAddress::new("kafka:9092", "events"));
+        assert_eq!(Some(&serde_json::json!("kafka:9092")), kafka.property("kafka.bootstrap.servers"));
+        assert!(matches!(kafka.typed().unwrap(), TypedDataAddress::Kafka(KafkaDataAddress { topic: Some(topic), .. }) if topic == "events"));
+
+        let proxy = DataAddress::new(None, Some("HttpProxy".to_string()), None).with_property("endpoint", "http://provider/public");
+        assert_eq!(TypedDataAddress::Other(proxy.clone()), proxy.typed().unwrap());
+        assert_eq!(proxy, DataAddress::from(proxy.typed().unwrap()));
+
+        let http_data = DataAddress::from(HttpDataAddress::new("https://example.com").with_header("X-Api-Key", "123"));
+        assert_eq!(Some("https://example.com".to_string()), http_data.base_url);
+        assert_eq!(Some(&serde_json::json!("123")), http_data.property("header:X-Api-Key"));
+    }
+
+}
@@ -106,6 +106,7 @@ mod asset_api_get_test {
 
     use crate::common::setup_provider_configuration;
     use edc_api::{AssetInput, DataAddress};
+    use edc_api::data_address::{HttpDataAddress, TypedDataAddress};
     use edc_client::{asset_api, Error};
 
     use uuid::Uuid;
@@ -141,6 +142,43 @@ mod asset_api_get_test {
 
     }
 
+    #[tokio::test]
+    async fn test_get_asset_with_typed_data_address() {
+        let configuration = setup_provider_configuration();
+
+        let id = Uuid::new_v4().to_string();
+        let http_data = HttpDataAddress {
+            path: Some("/users".to_string()),
+            proxy_path: Some(true),
+            proxy_query_params: Some(true),
+            auth_key: Some("Authorization".to_string()),
+            secret_name: Some("users-api-token".to_string()),
+            ..HttpDataAddress::new("https://jsonplaceholder.typicode.com")
+        }.with_header("X-Tenant", "dspace");
+        let data_address = DataAddress::from(http_data).with_property("custom", "kept");
+
+        let mut asset = AssetInput::default();
+        asset.at_id = Some(id.clone());
+        asset.data_address = Box::new(data_address.clone());
+        asset.properties = std::collections::HashMap::from([("name".to_string(), serde_json::Value::String("typed".to_string()))]);
+
+        let _ = asset_api::create_asset(&configuration, Some(asset)).await.unwrap();
+
+        let response = asset_api::get_asset(&configuration, &id).await.unwrap();
+
+        // Every property survives the round trip through the connector
+        let returned = response.data_address.unwrap();
+        assert_eq!(*returned, data_address);
+        match returned.typed().unwrap() {
+            TypedDataAddress::HttpData(returned) => {
+                assert_eq!(Some(true), returned.proxy_path);
+                assert_eq!(vec![("X-Tenant", "dspace")], returned.headers().collect::<Vec<_>>());
+                assert_eq!(Some("https://jsonplaceholder.typicode.com".to_string()), returned.base_url);
+            },
+            other => panic!("Expected an HttpData address, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_asset_with_unknown_id() {
         let configuration = setup_provider_configuration();
@@ -226,8 +264,10 @@ mod asset_api_request_test {
     extern crate odrl;
 
     use crate::common::setup_provider_configuration;
-    use edc_api::{AssetInput, Criterion, DataAddress, QuerySpec};
-    use edc_client::{asset_api, Error};
+    use edc_api::{AssetInput, Criterion, CriterionOperator, DataAddress, Query, QuerySpec};
+    use edc_client::{asset_api, Error, EdcClient};
+    use edc_client::pagination::DEFAULT_PAGE_SIZE;
+    use futures::TryStreamExt;
     use odrl::name_spaces as NameSpaces;
 
     use uuid::Uuid;
@@ -265,7 +305,7 @@ mod asset_api_request_test {
         let criterion = Criterion {
             at_type: None,
             operand_left: serde_json::Value::from(format!("{}{}", NameSpaces::EDC_NS, "id")),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
             operand_right: serde_json::Value::from(id_1.clone()),
         };
 
@@ -288,6 +328,83 @@ mod asset_api_request_test {
 
     }
 
+    #[tokio::test]
+    async fn test_request_assets_with_query_builder() {
+        let configuration = setup_provider_configuration();
+
+        let id = Uuid::new_v4().to_string();
+        let mut properties = std::collections::HashMap::new();
+        properties.insert("name".to_string(), serde_json::Value::String(format!("Report {}", id)));
+        let mut data_address = DataAddress::default();
+        data_address.r#type = Some("https://w3id.org/edc/v0.0.1/ns/DataAddress".to_string());
+
+        let mut asset = AssetInput::default();
+        asset.at_id = Some(id.clone());
+        asset.data_address = Box::new(data_address);
+        asset.properties = properties;
+
+        asset_api::create_asset(&configuration, Some(asset)).await.unwrap();
+
+        let query = Query::assets()
+            .where_("name").ilike(format!("report {}", id))
+            .and("edc:id").in_([id.clone(), Uuid::new_v4().to_string()])
+            .build();
+        let response = asset_api::request_assets(&configuration, Some(query)).await.unwrap();
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].at_id, Some(id.clone()));
+
+        let query = Query::assets().where_("name").eq("Report").and("id").eq(id).build();
+        assert!(asset_api::request_assets(&configuration, Some(query)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_request_assets_stream() {
+        let configuration = setup_provider_configuration();
+
+        // Assets of this test share a batch id, so the query only matches them
+        let batch = Uuid::new_v4().to_string();
+        let mut ids = Vec::new();
+        for _ in 0..7 {
+            let id = Uuid::new_v4().to_string();
+            let mut properties = std::collections::HashMap::new();
+            properties.insert("batch".to_string(), serde_json::Value::String(batch.clone()));
+            let mut data_address = DataAddress::default();
+            data_address.r#type = Some("https://w3id.org/edc/v0.0.1/ns/DataAddress".to_string());
+
+            let mut asset = AssetInput::default();
+            asset.at_id = Some(id.clone());
+            asset.data_address = Box::new(data_address);
+            asset.properties = properties;
+
+            asset_api::create_asset(&configuration, Some(asset)).await.unwrap();
+            ids.push(id);
+        }
+
+        let criterion = Criterion {
+            at_type: None,
+            operand_left: serde_json::Value::from(format!("{}{}", NameSpaces::EDC_NS, "batch")),
+            operator: CriterionOperator::Eq,
+            operand_right: serde_json::Value::from(batch),
+        };
+        let query = QuerySpec {
+            at_context: Some(std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(NameSpaces::EDC_NS.to_string()))])),
+            filter_expression: vec![criterion],
+            ..QuerySpec::default()
+        };
+
+        // Three pages, the last one is not full
+        let assets: Vec<_> = asset_api::request_assets_stream(&configuration, Some(query.clone()), 3).try_collect().await.unwrap();
+        let mut streamed: Vec<_> = assets.into_iter().map(|asset| asset.at_id.unwrap()).collect();
+        streamed.sort();
+        ids.sort();
+        assert_eq!(ids, streamed);
+
+        let client = EdcClient::new(configuration);
+        let assets: Vec<_> = client.assets().query_stream(Some(query), DEFAULT_PAGE_SIZE).try_collect().await.unwrap();
+        assert_eq!(7, assets.len());
+    }
+
 }
 
 #[cfg(test)]
@@ -371,4 +488,4 @@ mod asset_api_update_test {
         }
     }
 
-}
\ No newline at end of file
+}
@@ -0,0 +1,224 @@
+mod common;
+
+#[cfg(test)]
+mod auth_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use edc_client::auth::{ClientCredentials, TokenError, TokenProvider};
+    use edc_client::configuration::{Configuration, RetryPolicy};
+    use edc_client::{asset_api, Error};
+    use edc_server::mock::{Method, MockResponse, MockServer, RecordedRequest, StatusCode};
+    use crate::common::asset_response;
+
+    fn token(access_token: &str, expires_in: u64) -> MockResponse {
+        MockResponse::json(StatusCode::OK, &serde_json::json!({"access_token": access_token, "token_type": "Bearer", "expires_in": expires_in}))
+    }
+
+    /// The mock is token endpoint and connector at once
+    fn keycloak_configuration(server: &MockServer) -> Configuration {
+        Configuration {
+            base_path: server.url(),
+            api_key: None,
+            basic_auth: None,
+            ..Configuration::default()
+        }
+        .with_retry(RetryPolicy::none())
+        .with_token_provider(ClientCredentials::new(format!("{}/token", server.url()), "dspace", "secret").with_scope("management"))
+    }
+
+    fn authorization(request: &RecordedRequest) -> Option<&str> {
+        request.header("authorization")
+    }
+
+    #[tokio::test]
+    async fn test_tokens_are_cached() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", token("token-1", 300));
+        server.respond(Method::GET, "/v3/assets/asset-1", asset_response("asset-1"));
+        let configuration = keycloak_configuration(&server);
+
+        asset_api::get_asset(&configuration, "asset-1").await.unwrap();
+        asset_api::get_asset(&configuration, "asset-1").await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(3, requests.len());
+        assert_eq!((Method::POST, "/token"), (requests[0].method.clone(), requests[0].path.as_str()));
+        assert_eq!(Some("Bearer token-1"), authorization(&requests[1]));
+        assert_eq!(Some("Bearer token-1"), authorization(&requests[2]));
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_are_refreshed() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", token("token-1", 0).times(1));
+        server.respond(Method::POST, "/token", token("token-2", 300));
+        server.respond(Method::GET, "/v3/assets/asset-1", asset_response("asset-1"));
+        let configuration = keycloak_configuration(&server);
+
+        asset_api::get_asset(&configuration, "asset-1").await.unwrap();
+        asset_api::get_asset(&configuration, "asset-1").await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(4, requests.len());
+        assert_eq!(Some("Bearer token-1"), authorization(&requests[1]));
+        assert_eq!("/token", requests[2].path);
+        assert_eq!(Some("Bearer token-2"), authorization(&requests[3]));
+    }
+
+    #[tokio::test]
+    async fn test_retry_once_after_unauthorized() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", token("revoked", 300).times(1));
+        server.respond(Method::POST, "/token", token("token-2", 300));
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::new(StatusCode::UNAUTHORIZED).times(1));
+        server.respond(Method::GET, "/v3/assets/asset-1", asset_response("asset-1"));
+
+        asset_api::get_asset(&keycloak_configuration(&server), "asset-1").await.unwrap();
+        assert_eq!(Some("Bearer token-2"), authorization(&server.requests()[3]));
+
+        // A fresh token that is rejected as well is not retried again
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", token("token-1", 300).times(1));
+        server.respond(Method::POST, "/token", token("token-2", 300));
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::new(StatusCode::UNAUTHORIZED));
+        match asset_api::get_asset(&keycloak_configuration(&server), "asset-1").await {
+            Err(Error::ResponseError(response)) => {
+                assert_eq!(reqwest::StatusCode::UNAUTHORIZED, response.status);
+                assert_eq!(2, response.attempts);
+            },
+            _ => panic!("Expected Status Code 401"),
+        }
+        assert_eq!(4, server.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_only_drops_the_rejected_token() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", token("token-1", 300).times(1));
+        server.respond(Method::POST, "/token", token("token-2", 300));
+        let provider = ClientCredentials::new(format!("{}/token", server.url()), "dspace", "secret");
+
+        assert_eq!("token-1", provider.token().await.unwrap());
+        // A request that was rejected with an older token does not drop the one that replaced it
+        provider.invalidate("token-0");
+        assert_eq!("token-1", provider.token().await.unwrap());
+        assert_eq!(1, server.requests_to(Method::POST, "/token").len());
+
+        provider.invalidate("token-1");
+        assert_eq!("token-2", provider.token().await.unwrap());
+        assert_eq!(2, server.requests_to(Method::POST, "/token").len());
+    }
+
+    #[tokio::test]
+    async fn test_token_endpoint_errors() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/token", MockResponse::json(StatusCode::UNAUTHORIZED, &serde_json::json!({"error": "unauthorized_client"})));
+
+        let provider = ClientCredentials::new(format!("{}/token", server.url()), "dspace", "wrong");
+        assert!(!format!("{:?}", provider).contains("wrong"));
+        assert!(matches!(provider.token().await, Err(TokenError::ResponseError { status: reqwest::StatusCode::UNAUTHORIZED, .. })));
+
+        match asset_api::get_asset(&keycloak_configuration(&server), "asset-1").await {
+            Err(Error::Token(TokenError::ResponseError { content, .. })) => assert!(content.contains("unauthorized_client")),
+            _ => panic!("Expected the error of the token endpoint"),
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod authentication_test {
+
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use edc_client::configuration::{ApiKey, Configuration};
+    use edc_client::{asset_api, dsp_client};
+    use edc_server::mock::{Method, MockServer, RecordedRequest};
+    use crate::common::asset_response;
+
+    fn unauthenticated(base_path: String) -> Configuration {
+        Configuration {
+            base_path,
+            api_key: None,
+            basic_auth: None,
+            ..Configuration::default()
+        }
+    }
+
+    /// Sends one request with the configuration and returns it as the mock received it
+    async fn sent_request(configure: impl FnOnce(Configuration) -> Configuration) -> RecordedRequest {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::GET, "/v3/assets/asset-1", asset_response("asset-1"));
+        asset_api::get_asset(&configure(unauthenticated(server.url())), "asset-1").await.unwrap();
+        server.requests().remove(0)
+    }
+
+    fn header_values<'a>(request: &'a RecordedRequest, name: &str) -> Vec<&'a str> {
+        request.headers.get_all(name).iter().filter_map(|value| value.to_str().ok()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_api_key() {
+        let request = sent_request(|configuration| Configuration { api_key: Some(ApiKey::new("123456")), ..configuration }).await;
+        assert_eq!(vec!["123456"], header_values(&request, "x-api-key"));
+
+        let api_key = ApiKey { prefix: Some("Key".to_string()), key: "123456".to_string() };
+        let request = sent_request(|configuration| Configuration { api_key: Some(api_key), ..configuration }).await;
+        assert_eq!(vec!["Key 123456"], header_values(&request, "x-api-key"));
+        assert!(header_values(&request, "authorization").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_basic_auth_and_bearer() {
+        let basic_auth = Some(("user".to_string(), Some("password".to_string())));
+        let request = sent_request(|configuration| Configuration { basic_auth: basic_auth.clone(), ..configuration }).await;
+        assert_eq!(vec!["Basic dXNlcjpwYXNzd29yZA=="], header_values(&request, "authorization"));
+
+        let request = sent_request(|configuration| Configuration { bearer_access_token: Some("token".to_string()), ..configuration }).await;
+        assert_eq!(vec!["Bearer token"], header_values(&request, "authorization"));
+
+        let request = sent_request(|configuration| Configuration { oauth_access_token: Some("oauth-token".to_string()), ..configuration }).await;
+        assert_eq!(vec!["Bearer oauth-token"], header_values(&request, "authorization"));
+
+        // Only one authorization header is sent, the token wins
+        let request = sent_request(|configuration| Configuration { basic_auth, bearer_access_token: Some("token".to_string()), ..configuration }).await;
+        assert_eq!(vec!["Bearer token"], header_values(&request, "authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_client_is_kept() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-tenant", reqwest::header::HeaderValue::from_static("tenant-1"));
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(std::time::Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        let request = sent_request(|configuration| Configuration { client, api_key: Some(ApiKey::new("123456")), ..configuration }).await;
+        assert_eq!(vec!["tenant-1"], header_values(&request, "x-tenant"));
+        assert_eq!(vec!["123456"], header_values(&request, "x-api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_protocol_requests_carry_no_management_credentials() {
+        let server = MockServer::start().await.unwrap();
+        let configuration = Configuration {
+            api_key: Some(ApiKey::new("123456")),
+            basic_auth: Some(("user".to_string(), None)),
+            bearer_access_token: Some("identity-token".to_string()),
+            ..unauthenticated(server.url())
+        };
+
+        let _ = dsp_client::get_dataset(&configuration, "dataset-1").await;
+
+        let request = server.requests().remove(0);
+        assert!(header_values(&request, "x-api-key").is_empty());
+        assert_eq!(vec!["identity-token"], header_values(&request, "authorization"));
+    }
+
+}
@@ -0,0 +1,53 @@
+mod common;
+
+#[cfg(test)]
+mod blocking_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+
+    use crate::common::{asset_input, setup_consumer_configuration, setup_provider_configuration, setup_random_contract_definition, PROVIDER_PROTOCOL};
+    use edc_api::{CatalogRequest, QuerySpec};
+    use edc_client::blocking::EdcClient;
+
+    use uuid::Uuid;
+
+    #[test]
+    fn test_asset_lifecycle() {
+        let client = EdcClient::new(setup_provider_configuration());
+        let id = Uuid::new_v4().to_string();
+
+        let response = client.assets().create(asset_input(&id, "blocking")).unwrap();
+        assert_eq!(Some(id.clone()), response.at_id);
+
+        client.assets().update(asset_input(&id, "updated")).unwrap();
+        let updated = client.assets().get(&id).unwrap();
+        assert_eq!(Some(&serde_json::Value::String("updated".to_string())), updated.properties.unwrap_or_default().get("name"));
+
+        let found = client.assets()
+            .query_iter(Some(QuerySpec::default()), 2)
+            .map(Result::unwrap)
+            .any(|asset| asset.at_id == Some(id.clone()));
+        assert!(found);
+
+        client.assets().delete(&id).unwrap();
+        assert!(client.assets().get(&id).unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_async_api_on_the_client_runtime() {
+        let provider = EdcClient::new(setup_provider_configuration());
+        let consumer = EdcClient::from(setup_consumer_configuration());
+
+        let (asset_id, _, definition_id) = provider.block_on(setup_random_contract_definition(provider.configuration()));
+        assert_eq!(Some(definition_id.clone()), provider.contract_definitions().get(&definition_id).unwrap().at_id);
+
+        let catalog = consumer.catalog().request(CatalogRequest {
+            counter_party_address: PROVIDER_PROTOCOL.to_string(),
+            protocol: "dataspace-protocol-http".to_string(),
+            ..CatalogRequest::default()
+        }).unwrap();
+        assert!(catalog.find_dataset(&asset_id).is_some());
+    }
+
+}
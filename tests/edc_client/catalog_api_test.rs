@@ -5,10 +5,12 @@ mod catalog_api_test {
 
     extern crate edc_api;
     extern crate edc_client;
+    extern crate odrl;
 
     use crate::common::{setup_random_contract_definition, setup_provider_configuration, setup_consumer_configuration, PROVIDER_PROTOCOL};
     use edc_api::{CatalogRequest, DatasetRequest};
     use edc_client::catalog_api;
+    use odrl::model::policy::OfferPolicy;
 
     #[tokio::test]
     async fn test_get_dataset() {
@@ -29,8 +31,9 @@ mod catalog_api_test {
 
         let dataset = catalog_api::get_dataset(&consumer, Some(request)).await.unwrap();
 
-        assert!(dataset.get("id").is_some());
-        assert_eq!(asset_id, dataset.get("id").unwrap().to_string().replace("\"", ""));
+        assert_eq!(Some(asset_id.clone()), dataset.at_id);
+        assert_eq!(Some(&serde_json::Value::String(asset_id)), dataset.properties.get("id"));
+        assert_eq!(1, dataset.has_policy.len());
 
     }
 
@@ -52,13 +55,41 @@ mod catalog_api_test {
 
         let catalog = catalog_api::request_catalog(&consumer, Some(request)).await.unwrap();
 
-        assert!(catalog.get("dcat:dataset").is_some());
+        assert!(!catalog.dataset.is_empty());
 
-        let dataset = catalog.get("dcat:dataset").unwrap().as_array().unwrap().iter().find(|ds| ds.get("id").unwrap().to_string().replace("\"", "") == asset_id);
+        let dataset = catalog.find_dataset(&asset_id);
 
         assert!(dataset.is_some());
-        assert_eq!(asset_id, dataset.unwrap().get("id").unwrap().to_string().replace("\"", ""));
+        assert_eq!(Some(asset_id.clone()), dataset.unwrap().at_id);
+        assert!(!dataset.unwrap().has_policy.is_empty());
 
     }
 
-}
\ No newline at end of file
+    #[tokio::test]
+    async fn test_offer_policy() {
+        let consumer = setup_consumer_configuration();
+        let provider = setup_provider_configuration();
+
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let request = DatasetRequest {
+            context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String("https://w3id.org/edc/v0.0.1/ns/".to_string()))]),
+            at_type: Some("DatasetRequest".to_string()),
+            at_id: Some(asset_id.to_string()),
+            counter_party_address: Some(PROVIDER_PROTOCOL.to_string()),
+            counter_party_id: None,
+            protocol: Some("dataspace-protocol-http".to_string()),
+            query_spec: None,
+        };
+
+        let dataset = catalog_api::get_dataset(&consumer, Some(request)).await.unwrap();
+        let offer = &dataset.has_policy[0];
+        let policy = OfferPolicy::try_from(offer).unwrap();
+
+        assert_eq!(offer.at_id, policy.uid);
+        assert_eq!(offer.assigner, policy.assigner.uid);
+        assert!(policy.rules.is_empty());
+
+    }
+
+}
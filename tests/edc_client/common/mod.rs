@@ -3,10 +3,12 @@ extern crate odrl;
 
 use std::{future::Future, time::Duration};
 use std::collections::HashSet;
-use serde::__private::de::IdentifierDeserializer;
+use std::sync::{Arc, OnceLock};
 use tokio::time::sleep;
-use edc_api::{AssetInput, CallbackAddress, ContractDefinitionInput, ContractNegotiation, ContractOfferDescription, ContractRequest, Criterion, DataAddress, DataPlaneInstanceSchema, DatasetRequest, NegotiationState, Offer, PolicyDefinitionInput, TransferRequest, TransferState};
+use edc_api::{AssetInput, CallbackAddress, ContractDefinitionInput, ContractNegotiation, ContractOfferDescription, ContractRequest, Criterion, CriterionOperator, DataAddress, DataPlaneInstanceSchema, DatasetRequest, NegotiationState, Offer, PolicyDefinitionInput, TransferRequest, TransferState};
+use edc_api::data_address::HttpDataAddress;
 use edc_client::configuration::{ApiKey, Configuration};
+use edc_server::mock::{MockResponse, StatusCode};
 use edc_client::{asset_api, catalog_api, contract_agreement_api, contract_definition_api, contract_negotiation_api, dataplane_selector_api, policy_definition_api, transfer_process_api};
 
 use uuid::Uuid;
@@ -21,24 +23,72 @@ pub const PROVIDER_PROTOCOL: &str = "http://provider-connector:9194/protocol";
 pub const PROVIDER_ID: &str = "provider";
 pub const DATASPACE_PROTOCOL: &str = "dataspace-protocol-http";
 
+/// Serves provider and consumer from one in-memory edc_server if `EDC_IN_MEMORY` is set, so the suite runs without docker.
+/// Both share one store, which makes the provider catalog visible to the consumer.
+fn ensure_in_memory_connectors() {
+    static STARTED: OnceLock<()> = OnceLock::new();
+
+    if std::env::var("EDC_IN_MEMORY").is_err() {
+        return;
+    }
+
+    STARTED.get_or_init(|| {
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+            runtime.block_on(async {
+                let store = Arc::new(edc_server::InMemoryStore::with_participant_id(PROVIDER_ID));
+                let provider = tokio::net::TcpListener::bind("127.0.0.1:29193").await.unwrap();
+                let consumer = tokio::net::TcpListener::bind("127.0.0.1:19193").await.unwrap();
+                let protocol = tokio::net::TcpListener::bind("127.0.0.1:29194").await.unwrap();
+                ready_tx.send(()).unwrap();
+                let _ = tokio::join!(
+                    edc_server::serve(provider, "/management", store.clone()),
+                    edc_server::serve(consumer, "/management", store.clone()),
+                    edc_server::serve_protocol(protocol, "/protocol", store),
+                );
+            });
+        });
+        ready_rx.recv().unwrap();
+    });
+}
+
 pub fn setup_provider_configuration() -> Configuration {
+    ensure_in_memory_connectors();
     let mut provider = Configuration::default();
     provider.base_path ="http://localhost:29193/management".to_string();
-    provider.api_key = Some(ApiKey {
-            prefix: Some("x-api-key".to_string()),
-            key: "123456".to_owned(),
-        });
-    provider.with_headers()
+    provider.api_key = Some(ApiKey::new("123456"));
+    provider
 }
 
 pub fn setup_consumer_configuration() -> Configuration {
+    ensure_in_memory_connectors();
     let mut consumer = Configuration::default();
     consumer.base_path = "http://localhost:19193/management".to_owned();
-    consumer.api_key = Some(ApiKey {
-            prefix: Some("x-api-key".to_string()),
-            key: "123456".to_owned(),
-        });
-    consumer.with_headers()
+    consumer.api_key = Some(ApiKey::new("123456"));
+    consumer
+}
+
+/// Configuration for talking to the protocol endpoint of the provider directly. It carries no management credentials.
+pub fn setup_provider_protocol_configuration() -> Configuration {
+    ensure_in_memory_connectors();
+    Configuration {
+        base_path: "http://localhost:29194/protocol".to_owned(),
+        bearer_access_token: Some("consumer-identity-token".to_owned()),
+        ..Configuration::default()
+    }
+}
+
+/// Asset with the given name that points to a public http endpoint
+pub fn asset_input(id: &str, name: &str) -> AssetInput {
+    let properties = std::collections::HashMap::from([("name".to_string(), serde_json::Value::String(name.to_string()))]);
+    let data_address = HttpDataAddress::new("https://jsonplaceholder.typicode.com/users").into();
+    AssetInput { at_id: Some(id.to_string()), data_address: Box::new(data_address), properties, ..AssetInput::default() }
+}
+
+/// Response of a [`edc_server::mock::MockServer`] holding a minimal asset with the given id
+pub fn asset_response(id: &str) -> MockResponse {
+    MockResponse::json(StatusCode::OK, &serde_json::json!({"@context": {}, "@id": id, "@type": "Asset", "properties": {}, "dataAddress": {"type": "HttpData"}}))
 }
 
 pub async fn setup_random_contract_definition(configuration: &Configuration) -> (String, String, String) {
@@ -52,6 +102,7 @@ pub async fn setup_random_contract_definition(configuration: &Configuration) ->
             at_type: Some("DataAddress".to_string()),
             r#type: Some("HttpData".to_string()),
             base_url: Some("https://jsonplaceholder.typicode.com/users".to_string()),
+            additional_properties: std::collections::HashMap::new(),
         }),
         private_properties: None,
         properties: Default::default(),
@@ -88,7 +139,7 @@ pub async fn setup_random_contract_definition(configuration: &Configuration) ->
             at_type: Some("Criterion".to_string()),
             operand_left: serde_json::Value::from(format!("{}{}", EDC_NS, "id")),
             operand_right: serde_json::Value::from(asset_response.clone().at_id.unwrap()),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
         }],
         contract_policy_id: policy_response.clone().at_id.unwrap(),
     };
@@ -118,7 +169,7 @@ pub async fn setup_random_contract_negotiation(consumer: &Configuration, provide
 
     let dataset = catalog_api::get_dataset(&consumer, Some(dataset_request)).await.unwrap();
 
-    let offer_id = dataset.get("hasPolicy").unwrap().get("@id").unwrap().to_string().replace("\"", "");
+    let offer_id = dataset.has_policy[0].at_id.clone();
 
     let offer = Offer {
         context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(ODRL_NS.to_string()))]),
@@ -205,6 +256,7 @@ pub async fn setup_random_transfer_process(consumer: &Configuration, provider: &
             at_type: None,
             r#type: Some("HttpProxy".to_string()),
             base_url: Some(PROVIDER_PROTOCOL.to_string()),
+            additional_properties: std::collections::HashMap::new(),
         }),
         private_properties: None,
         protocol: "dataspace-protocol-http".to_string(),
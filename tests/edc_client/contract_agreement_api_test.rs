@@ -10,6 +10,7 @@ mod contract_agreement_api_test {
     use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_contract_negotiation, wait_for_negotiation_state};
     use edc_api::NegotiationState;
     use edc_client::{contract_agreement_api, contract_negotiation_api, Error};
+    use odrl::functions::edc_policy::ContractAgreementExt;
     use odrl::name_spaces::EDC_NS;
 
     #[tokio::test]
@@ -31,6 +32,33 @@ mod contract_agreement_api_test {
         assert_eq!(agreement_id, agreement.clone().at_id.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_get_agreement_policy() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (negotiation_id, asset_id) = setup_random_contract_negotiation(&consumer, &provider).await;
+
+        wait_for_negotiation_state(
+            &consumer,
+            &negotiation_id,
+            NegotiationState { state: edc_api::ContractNegotiationState::Finalized },
+        ).await;
+
+        let agreement_id = contract_negotiation_api::get_negotiation(&consumer, &negotiation_id).await.unwrap().contract_agreement_id.unwrap();
+
+        let agreement = contract_agreement_api::get_agreement_by_id(&consumer, &agreement_id).await.unwrap();
+        let policy = agreement.agreement_policy().unwrap();
+
+        assert_eq!(policy.assigner.uid, agreement.provider_id);
+        assert_eq!(policy.assignee.uid, agreement.consumer_id);
+        assert!(!policy.uid.is_empty());
+        for rule in policy.rules {
+            if let odrl::model::rule::Rule::Permission(permission) = rule {
+                assert_eq!(permission.target.uid, Some(asset_id.clone()));
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_get_agreement_with_unknown_id() {
         let consumer = setup_consumer_configuration();
@@ -107,7 +135,7 @@ mod contract_agreement_api_test {
         let criterion = edc_api::Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("assetId"),
-            operator: "=".to_string(),
+            operator: edc_api::CriterionOperator::Eq,
             operand_right: serde_json::Value::from(asset_id.clone()),
         };
 
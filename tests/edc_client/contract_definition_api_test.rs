@@ -242,7 +242,7 @@ mod contract_definition_api_query_test {
         let criterion = edc_api::Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("id"),
-            operator: "=".to_string(),
+            operator: edc_api::CriterionOperator::Eq,
             operand_right: serde_json::Value::from(definition_id.clone()),
         };
 
@@ -31,7 +31,7 @@ mod contract_negotiation_api_initiate_test {
 
         let dataset = catalog_api::get_dataset(&consumer, Some(dataset_request)).await.unwrap();
 
-        let offer_id = dataset.get("hasPolicy").unwrap().get("@id").unwrap().to_string().replace("\"", "");
+        let offer_id = dataset.has_policy[0].at_id.clone();
 
         let offer = Offer {
             context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(ODRL_NS.to_string()))]),
@@ -80,7 +80,7 @@ mod contract_negotiation_api_request_test {
     extern crate edc_client;
 
     use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_contract_negotiation};
-    use edc_api::{Criterion, QuerySpec};
+    use edc_api::{Criterion, CriterionOperator, QuerySpec};
     use edc_client::contract_negotiation_api;
     use odrl::name_spaces::EDC_NS;
 
@@ -95,7 +95,7 @@ mod contract_negotiation_api_request_test {
         let criterion = Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("id"),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
             operand_right: serde_json::Value::from(first_negotiation_id.clone()),
         };
 
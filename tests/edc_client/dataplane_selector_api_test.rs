@@ -79,11 +79,13 @@ mod dataplane_selector_api_test {
                 at_type: Some("test-dst1".to_string()),
                 r#type: Some("test-dst1".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             })),
             source: Some(Box::new(DataAddress {
                 at_type: Some("test-src1".to_string()),
                 r#type: Some("test-src1".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             })),
             strategy: None,
             transfer_type: Some("transfer-type-1".to_string()),
@@ -124,11 +126,13 @@ mod dataplane_selector_api_test {
                 at_type: Some("test-dst1".to_string()),
                 r#type: Some("test-dst1".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             })),
             source: Some(Box::new(DataAddress {
                 at_type: Some("test-src1".to_string()),
                 r#type: Some("test-src1".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             })),
             strategy: None,
             transfer_type: Some("wrong-type-1".to_string()),
@@ -0,0 +1,155 @@
+mod common;
+
+#[cfg(test)]
+mod dsp_client_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+
+    use std::time::Duration;
+
+    use crate::common::{setup_provider_configuration, setup_provider_protocol_configuration, setup_random_contract_definition};
+    use edc_api::dsp::{CatalogRequestMessage, ContractAgreementVerificationMessage, ContractNegotiationEventMessage, ContractNegotiationTerminationMessage,
+                       ContractRequestMessage, DataAddress, NegotiationEventType, NegotiationState, TransferCompletionMessage, TransferRequestMessage, TransferState,
+                       TransferStartMessage, TransferSuspensionMessage};
+    use edc_client::configuration::Configuration;
+    use edc_client::dsp_client::{self, DspError};
+    use edc_client::{contract_negotiation_api, Error};
+    use uuid::Uuid;
+
+    const CALLBACK_ADDRESS: &str = "http://consumer-connector:9194/protocol";
+
+    async fn wait_for_negotiation(protocol: &Configuration, provider_pid: &str, state: NegotiationState) {
+        for _ in 0..50 {
+            if dsp_client::get_negotiation(protocol, provider_pid).await.unwrap().state == state {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("Negotiation {} did not reach {:?}", provider_pid, state);
+    }
+
+    async fn wait_for_transfer(protocol: &Configuration, provider_pid: &str, state: TransferState) {
+        for _ in 0..50 {
+            if dsp_client::get_transfer_process(protocol, provider_pid).await.unwrap().state == state {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("Transfer process {} did not reach {:?}", provider_pid, state);
+    }
+
+    /// Negotiates the first offer of the asset up to an agreement and returns provider and consumer pid
+    async fn negotiate(protocol: &Configuration, asset_id: &str) -> (String, String) {
+        let dataset = dsp_client::get_dataset(protocol, asset_id).await.unwrap();
+        let consumer_pid = Uuid::new_v4().to_string();
+
+        let request = ContractRequestMessage::new(consumer_pid.clone(), dataset.has_policy[0].clone(), CALLBACK_ADDRESS.to_string());
+        let negotiation = dsp_client::request_contract(protocol, &request).await.unwrap();
+
+        assert_eq!(consumer_pid, negotiation.consumer_pid);
+        assert_eq!(NegotiationState::Requested, negotiation.state);
+
+        wait_for_negotiation(protocol, &negotiation.provider_pid, NegotiationState::Agreed).await;
+        (negotiation.provider_pid, consumer_pid)
+    }
+
+    #[tokio::test]
+    async fn test_request_catalog() {
+        let provider = setup_provider_configuration();
+        let protocol = setup_provider_protocol_configuration();
+
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let catalog = dsp_client::request_catalog(&protocol, &CatalogRequestMessage::new()).await.unwrap();
+
+        let dataset = catalog.find_dataset(&asset_id);
+        assert!(dataset.is_some());
+        assert!(!dataset.unwrap().has_policy.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_and_transfer() {
+        let provider = setup_provider_configuration();
+        let protocol = setup_provider_protocol_configuration();
+
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+        let (provider_pid, consumer_pid) = negotiate(&protocol, &asset_id).await;
+
+        let verification = ContractAgreementVerificationMessage::new(provider_pid.clone(), consumer_pid.clone());
+        dsp_client::verify_agreement(&protocol, &verification).await.unwrap();
+        wait_for_negotiation(&protocol, &provider_pid, NegotiationState::Finalized).await;
+
+        // The provider keeps the negotiation as provider process in its management api
+        let agreement = contract_negotiation_api::get_agreement_for_negotiation(&provider, &provider_pid).await.unwrap();
+        assert_eq!(Some(asset_id), agreement.asset_id);
+
+        let consumer_pid = Uuid::new_v4().to_string();
+        let mut request = TransferRequestMessage::new(consumer_pid.clone(), agreement.at_id.unwrap(), CALLBACK_ADDRESS.to_string());
+        request.format = Some("HttpData-PULL".to_string());
+        request.data_address = Some(DataAddress::new("https://w3id.org/idsa/v4.1/HTTP".to_string()));
+
+        let transfer_process = dsp_client::request_transfer(&protocol, &request).await.unwrap();
+        assert_eq!(TransferState::Requested, transfer_process.state);
+
+        let provider_pid = transfer_process.provider_pid;
+        wait_for_transfer(&protocol, &provider_pid, TransferState::Started).await;
+
+        let suspension = TransferSuspensionMessage::new(provider_pid.clone(), consumer_pid.clone());
+        dsp_client::suspend_transfer(&protocol, &suspension).await.unwrap();
+        wait_for_transfer(&protocol, &provider_pid, TransferState::Suspended).await;
+
+        // Only started transfers can be completed
+        let completion = TransferCompletionMessage::new(provider_pid.clone(), consumer_pid.clone());
+        assert!(dsp_client::complete_transfer(&protocol, &completion).await.is_err());
+
+        let start = TransferStartMessage::new(provider_pid.clone(), consumer_pid.clone());
+        dsp_client::start_transfer(&protocol, &start).await.unwrap();
+        dsp_client::complete_transfer(&protocol, &completion).await.unwrap();
+        wait_for_transfer(&protocol, &provider_pid, TransferState::Completed).await;
+    }
+
+    #[tokio::test]
+    async fn test_terminate_negotiation() {
+        let provider = setup_provider_configuration();
+        let protocol = setup_provider_protocol_configuration();
+
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+        let (provider_pid, consumer_pid) = negotiate(&protocol, &asset_id).await;
+
+        let termination = ContractNegotiationTerminationMessage::new(provider_pid.clone(), consumer_pid.clone());
+        dsp_client::terminate_negotiation(&protocol, &termination).await.unwrap();
+        wait_for_negotiation(&protocol, &provider_pid, NegotiationState::Terminated).await;
+
+        // A terminated negotiation cannot be accepted anymore
+        let event = ContractNegotiationEventMessage::new(provider_pid.clone(), consumer_pid, NegotiationEventType::Accepted);
+        let error = dsp_client::send_negotiation_event(&protocol, &event).await.unwrap_err();
+
+        match error {
+            Error::ResponseError(response) => {
+                assert_eq!(400, response.status.as_u16());
+                match response.entity {
+                    Some(DspError::ProtocolError(protocol_error)) => {
+                        assert_eq!(Some("dspace:ContractNegotiationError".to_string()), protocol_error.at_type);
+                        assert_eq!(Some(provider_pid), protocol_error.provider_pid);
+                    }
+                    entity => panic!("Unexpected entity {:?}", entity),
+                }
+            }
+            error => panic!("Unexpected error {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_negotiation() {
+        let protocol = setup_provider_protocol_configuration();
+
+        let error = dsp_client::get_negotiation(&protocol, &Uuid::new_v4().to_string()).await.unwrap_err();
+
+        match error {
+            Error::ResponseError(response) => assert_eq!(404, response.status.as_u16()),
+            error => panic!("Unexpected error {:?}", error),
+        }
+    }
+
+}
@@ -0,0 +1,130 @@
+mod common;
+
+#[cfg(test)]
+mod edc_client_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+
+    use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_contract_definition, PROVIDER_PROTOCOL};
+    use edc_api::{AssetInput, CatalogRequest, DataAddress, QuerySpec};
+    use edc_client::{EdcClient, Error};
+    use odrl::name_spaces::EDC_NS;
+
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_asset_lifecycle() {
+        let client = EdcClient::new(setup_provider_configuration());
+
+        let id = Uuid::new_v4().to_string();
+        let mut asset = AssetInput::default();
+        asset.context = std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(EDC_NS.to_string()))]);
+        asset.at_id = Some(id.clone());
+        asset.data_address = Box::new(DataAddress {
+            at_type: Some("DataAddress".to_string()),
+            r#type: Some("HttpData".to_string()),
+            base_url: Some("https://jsonplaceholder.typicode.com/users".to_string()),
+            additional_properties: std::collections::HashMap::new(),
+        });
+        asset.properties = std::collections::HashMap::from([("name".to_string(), serde_json::Value::String("client".to_string()))]);
+
+        let response = client.assets().create(asset.clone()).await.unwrap();
+        assert_eq!(Some(id.clone()), response.at_id);
+
+        let created = client.assets().get(&id).await.unwrap();
+        assert_eq!(Some(id.clone()), created.at_id);
+
+        asset.properties.insert("name".to_string(), serde_json::Value::String("updated".to_string()));
+        client.assets().update(asset).await.unwrap();
+
+        let updated = client.assets().get(&id).await.unwrap();
+        assert_eq!(Some(&serde_json::Value::String("updated".to_string())), updated.properties.unwrap_or_default().get("name").cloned().as_ref());
+
+        let assets = client.assets().query(Some(QuerySpec { limit: Some(1000), offset: Some(0), sort_field: None, ..QuerySpec::default() })).await.unwrap();
+        assert!(assets.iter().any(|asset| asset.at_id == Some(id.clone())));
+
+        client.assets().delete(&id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_error_response_is_decoded() {
+        let client = EdcClient::new(setup_provider_configuration());
+
+        let response = client.assets().get(&Uuid::new_v4().to_string()).await;
+
+        match response {
+            Err(Error::ResponseError(response)) => {
+                assert_eq!(reqwest::StatusCode::NOT_FOUND, response.status);
+                assert!(response.entity.is_some_and(|error| error.is_not_found()));
+            }
+            _ => panic!("Expected a not found response, got {:?}", response),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sub_clients_share_configuration() {
+        let provider = EdcClient::new(setup_provider_configuration());
+        let consumer = EdcClient::from(setup_consumer_configuration());
+
+        let (asset_id, policy_id, definition_id) = setup_random_contract_definition(provider.configuration()).await;
+
+        let definition = provider.contract_definitions().get(&definition_id).await.unwrap();
+        assert_eq!(Some(policy_id.clone()), definition.access_policy_id);
+        assert_eq!(Some(policy_id.clone()), provider.policies().get(&policy_id).await.unwrap().at_id);
+
+        let catalog = consumer.catalog().request(CatalogRequest {
+            context: std::collections::HashMap::from([("@vocab".to_string(), serde_json::Value::String(EDC_NS.to_string()))]),
+            at_type: Some("CatalogRequest".to_string()),
+            counter_party_address: PROVIDER_PROTOCOL.to_string(),
+            counter_party_id: None,
+            protocol: "dataspace-protocol-http".to_string(),
+            query_spec: None,
+        }).await.unwrap();
+
+        assert!(catalog.find_dataset(&asset_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_traced_without_credentials() {
+        let configuration = setup_provider_configuration();
+        let api_key = configuration.api_key.clone().unwrap().key;
+        let client = EdcClient::new(configuration);
+
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let id = Uuid::new_v4().to_string();
+        let _ = client.assets().get(&id).await;
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("management_api"), "{}", output);
+        assert!(output.contains("method=GET"), "{}", output);
+        assert!(output.contains(&format!("path=/v3/assets/{}", id)), "{}", output);
+        assert!(output.contains("status=404"), "{}", output);
+        assert!(output.contains("latency_ms="), "{}", output);
+        assert!(!output.contains(&api_key), "{}", output);
+    }
+
+}
@@ -6,7 +6,7 @@ mod edr_cache_test {
     extern crate edc_client;
 
     use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_transfer_process, wait_for_transfer_state};
-    use edc_api::{Criterion, QuerySpec, TransferState};
+    use edc_api::{Criterion, CriterionOperator, QuerySpec, TransferState};
     use edc_api::transfer_state::TransferProcessState;
     use edc_client::Error;
     use odrl::name_spaces::EDC_NS;
@@ -55,7 +55,7 @@ mod edr_cache_test {
         let criterion = Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("assetId"),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
             operand_right: serde_json::Value::from(asset_id.clone()),
         };
 
@@ -0,0 +1,85 @@
+mod common;
+
+#[cfg(test)]
+mod error_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use crate::common::{asset_input, setup_provider_configuration};
+    use edc_client::configuration::{Configuration, RetryPolicy};
+    use edc_client::{asset_api, EdcError, Error};
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn edc_error(error: Error<EdcError>) -> EdcError {
+        match error {
+            Error::ResponseError(response) => response.entity.expect("Error responses always decode into an EdcError"),
+            other => panic!("Expected an error response, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let configuration = setup_provider_configuration();
+
+        let error = asset_api::get_asset(&configuration, &Uuid::new_v4().to_string()).await.unwrap_err();
+
+        assert!(error.is_not_found());
+        assert!(!error.is_retryable());
+        let error = edc_error(error);
+        assert!(error.is_not_found());
+        assert!(!error.is_conflict());
+        assert!(!error.details.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_conflict() {
+        let configuration = setup_provider_configuration();
+        let id = Uuid::new_v4().to_string();
+
+        asset_api::create_asset(&configuration, Some(asset_input(&id, "error"))).await.unwrap();
+        let error = asset_api::create_asset(&configuration, Some(asset_input(&id, "error"))).await.unwrap_err();
+
+        assert!(error.is_conflict());
+        let error = edc_error(error);
+        assert_eq!(reqwest::StatusCode::CONFLICT, error.status);
+        assert!(error.messages().count() > 0);
+
+        asset_api::remove_asset(&configuration, &id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_details_and_retryability() {
+        // A single detail instead of a list is accepted as well
+        let server = MockServer::start().await.unwrap();
+        let detail = serde_json::json!({"message": "invalid", "type": "ValidationFailure", "path": "properties"});
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::json(StatusCode::BAD_REQUEST, &detail).times(1));
+        let configuration = Configuration { base_path: server.url(), ..Configuration::default() };
+
+        let error = edc_error(asset_api::get_asset(&configuration, "asset-1").await.unwrap_err());
+        assert!(error.is_bad_request());
+        assert!(!error.retryable);
+        assert_eq!(vec!["invalid"], error.messages().collect::<Vec<_>>());
+        assert_eq!(Some("properties".to_string()), error.details[0].path);
+        assert_eq!("status code 400 Bad Request: invalid", error.to_string());
+
+        // Bodies that are no details still produce an error, just without details
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE).with_body("<html>unavailable</html>"));
+        let configuration = Configuration { base_path: server.url(), ..Configuration::default() }
+            .with_retry(RetryPolicy::default().with_max_attempts(2).with_backoff(Duration::from_millis(1), Duration::from_millis(5)));
+
+        let error = asset_api::get_asset(&configuration, "asset-1").await.unwrap_err();
+        assert!(error.is_retryable());
+        let error = edc_error(error);
+        assert!(error.retryable);
+        assert!(error.is_server_error());
+        assert!(error.details.is_empty());
+        // The bad request and both attempts of the unavailable one
+        assert_eq!(3, server.requests_to(Method::GET, "/v3/assets/asset-1").len());
+    }
+
+}
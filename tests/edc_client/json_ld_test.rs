@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod json_ld_test {
+    extern crate edc_client;
+
+    use edc_client::json_ld::{self, EDC_NS};
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_prefixed_and_expanded_keys() {
+        let response = json!({
+            "@context": { "edc": EDC_NS, "odrl": "http://www.w3.org/ns/odrl/2/" },
+            "@id": "asset-1",
+            "@type": "edc:Asset",
+            "edc:properties": {
+                "https://w3id.org/edc/v0.0.1/ns/name": "test",
+                "https://example.com/custom": 1
+            },
+            "https://w3id.org/edc/v0.0.1/ns/createdAt": 42
+        });
+
+        let normalized = json_ld::normalize(&response);
+
+        assert_eq!(normalized["@type"], "Asset");
+        assert_eq!(normalized["@id"], "asset-1");
+        assert_eq!(normalized["createdAt"], 42);
+        assert_eq!(normalized["properties"]["name"], "test");
+        assert_eq!(normalized["properties"]["https://example.com/custom"], 1);
+        assert_eq!(normalized["@context"], json_ld::client_context());
+    }
+
+    #[test]
+    fn test_normalize_keeps_other_prefixes() {
+        let catalog = json!({
+            "@context": { "@vocab": EDC_NS, "dcat": "http://www.w3.org/ns/dcat#", "dct": "http://purl.org/dc/terms/" },
+            "@type": "dcat:Catalog",
+            "dcat:dataset": [{
+                "@id": "asset-1",
+                "http://www.w3.org/ns/odrl/2/hasPolicy": {
+                    "@id": "offer-1",
+                    "@type": "http://www.w3.org/ns/odrl/2/Offer",
+                    "http://www.w3.org/ns/odrl/2/permission": []
+                },
+                "dcat:distribution": { "dct:format": { "@id": "HttpData-PULL" } }
+            }]
+        });
+
+        let normalized = json_ld::normalize(&catalog);
+
+        assert_eq!(normalized["@type"], "dcat:Catalog");
+        let dataset = &normalized["dcat:dataset"][0];
+        assert_eq!(dataset["dcat:distribution"]["dct:format"]["@id"], "HttpData-PULL");
+        assert_eq!(dataset["hasPolicy"]["@type"], "Offer");
+        assert_eq!(dataset["hasPolicy"]["permission"], json!([]));
+    }
+
+    #[test]
+    fn test_normalize_custom_context() {
+        let response = json!({
+            "@context": { "@vocab": "https://example.com/ns/", "edc": EDC_NS, "assetId": "edc:assetId" },
+            "assetId": "asset-1",
+            "name": "example",
+            "edc:state": "FINALIZED"
+        });
+
+        let normalized = json_ld::normalize(&response);
+
+        assert_eq!(normalized["assetId"], "asset-1");
+        assert_eq!(normalized["state"], "FINALIZED");
+        assert_eq!(normalized["https://example.com/ns/name"], "example");
+    }
+
+    #[test]
+    fn test_normalize_policy_scope() {
+        let agreement = json!({
+            "@context": { "@vocab": EDC_NS, "edc": EDC_NS, "odrl": "http://www.w3.org/ns/odrl/2/" },
+            "@type": "ContractAgreement",
+            "assetId": "asset-1",
+            "policy": {
+                "@type": "odrl:Agreement",
+                "odrl:target": { "@id": "asset-1" },
+                "odrl:permission": {
+                    "odrl:action": { "@id": "odrl:use" },
+                    "odrl:constraint": {
+                        "odrl:leftOperand": { "@id": "edc:inForceDate" },
+                        "odrl:operator": { "@id": "odrl:gteq" },
+                        "odrl:rightOperand": "contractAgreement+0s"
+                    }
+                },
+                "edc:participantId": "provider"
+            }
+        });
+
+        let normalized = json_ld::normalize(&agreement);
+
+        assert_eq!(normalized["assetId"], "asset-1");
+        let policy = &normalized["policy"];
+        assert_eq!(policy["@type"], "Agreement");
+        assert_eq!(policy["target"]["@id"], "asset-1");
+        assert_eq!(policy["permission"]["constraint"]["operator"]["@id"], "odrl:gteq");
+        // Ids are values, not terms of the context
+        assert_eq!(policy["permission"]["constraint"]["leftOperand"]["@id"], "edc:inForceDate");
+        assert_eq!(policy["edc:participantId"], "provider");
+    }
+
+    #[test]
+    fn test_normalize_colliding_keys() {
+        // Within a policy the EDC target must not be mistaken for the ODRL target
+        let policy_definition = json!({
+            "@context": { "@vocab": EDC_NS, "odrl": "http://www.w3.org/ns/odrl/2/" },
+            "policy": {
+                "@context": "http://www.w3.org/ns/odrl.jsonld",
+                "@type": "Set",
+                "uid": "policy-1",
+                "target": "asset-1",
+                "https://w3id.org/edc/v0.0.1/ns/target": "other"
+            }
+        });
+
+        let normalized = json_ld::normalize(&policy_definition);
+
+        let policy = &normalized["policy"];
+        assert_eq!(policy["@id"], "policy-1");
+        assert_eq!(policy["@type"], "Set");
+        assert_eq!(policy["target"], "asset-1");
+        assert_eq!(policy["edc:target"], "other");
+    }
+
+    #[test]
+    fn test_normalize_arrays() {
+        let response = json!([
+            { "@context": { "edc": EDC_NS }, "edc:state": "STARTED" },
+            { "@context": { "@vocab": EDC_NS }, "state": "COMPLETED" }
+        ]);
+
+        let normalized = json_ld::normalize(&response);
+
+        assert_eq!(normalized[0]["state"], "STARTED");
+        assert_eq!(normalized[1]["state"], "COMPLETED");
+        assert_eq!(normalized[1]["@context"], json_ld::client_context());
+    }
+}
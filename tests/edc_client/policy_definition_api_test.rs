@@ -8,6 +8,12 @@ mod policy_definition_api_create_test {
     use crate::common::setup_provider_configuration;
     use edc_api::PolicyDefinitionInput;
     use edc_client::{Error, policy_definition_api};
+    use odrl::functions::edc_policy::{self, PolicyDefinitionExt};
+    use odrl::model::action::Action;
+    use odrl::model::asset::Asset;
+    use odrl::model::constraint::{Constraint, LeftOperand, Operator, RightOperand};
+    use odrl::model::policy::{Policy, SetPolicy};
+    use odrl::model::rule::{Permission, Rule};
     use odrl::name_spaces::EDC_NS;
 
     use uuid::Uuid;
@@ -39,6 +45,28 @@ mod policy_definition_api_create_test {
         assert_eq!(response.at_id.unwrap(), policy_definition_id);
     }
 
+    #[tokio::test]
+    async fn test_create_typed_policy_definition() {
+        let provider_configuration = setup_provider_configuration();
+
+        let policy_definition_id = Uuid::new_v4().to_string();
+
+        let target = Asset::new(None, Some("api_test_asset".to_string()), None, vec![], None, None, None, None, None, None);
+        let constraint = Constraint::new(None, LeftOperand::IRI(format!("{}inForceDate", EDC_NS)), Operator::GreaterThanOrEqual, RightOperand::Literal("contractAgreement+0s".to_string()), None, None, String::new());
+        let permission = Permission::new(None, Action::new("use", None, None, vec![]), None, vec![], vec![], vec![constraint], target, None, None, vec![]);
+        let policy = SetPolicy::new(String::new(), vec![Rule::Permission(permission)], vec![], vec![], None, vec![]);
+
+        let policy_definition = edc_policy::policy_definition(policy_definition_id.clone(), policy.clone());
+        let response = policy_definition_api::create_policy_definition(&provider_configuration, Some(policy_definition)).await.unwrap();
+        assert_eq!(response.at_id.unwrap(), policy_definition_id);
+
+        let fetched = policy_definition_api::get_policy_definition(&provider_configuration, &policy_definition_id).await.unwrap();
+        match fetched.odrl_policy().unwrap() {
+            Policy::SetPolicy(fetched) => assert_eq!(fetched.rules, policy.rules),
+            other => panic!("Expected a set policy, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_create_duplicate_id_policy_definition() {
         let provider_configuration = setup_provider_configuration();
@@ -83,7 +111,7 @@ mod policy_definition_api_request_test {
     extern crate edc_client;
 
     use crate::common::{setup_provider_configuration, setup_random_contract_definition};
-    use edc_api::{Criterion, QuerySpec};
+    use edc_api::{Criterion, CriterionOperator, QuerySpec};
     use edc_client::policy_definition_api;
     use odrl::name_spaces::EDC_NS;
 
@@ -97,7 +125,7 @@ mod policy_definition_api_request_test {
         let criterion = Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("id"),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
             operand_right: serde_json::Value::from(first_policy_definition_id.clone()),
         };
 
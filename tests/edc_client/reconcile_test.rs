@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod reconcile_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use std::sync::Arc;
+
+    use edc_client::configuration::Configuration;
+    use edc_client::reconcile::{Action, Kind, Manifest, ReconcileError, Step};
+    use edc_client::EdcClient;
+    use edc_server::InMemoryStore;
+
+    const MANIFEST: &str = r#"
+policyDefinitions:
+  - "@id": allow-all
+    policy:
+      "@context": http://www.w3.org/ns/odrl.jsonld
+      "@type": Set
+      permission:
+        - action: use
+assets:
+  - "@id": asset-1
+    properties:
+      name: First asset
+    dataAddress:
+      type: HttpData
+      baseUrl: https://example.com/1
+  - "@id": asset-2
+    properties:
+      name: Second asset
+    dataAddress:
+      type: HttpData
+      baseUrl: https://example.com/2
+contractDefinitions:
+  - "@id": all-assets
+    accessPolicyId: allow-all
+    contractPolicyId: allow-all
+    assetsSelector: []
+"#;
+
+    /// Every test gets a connector of its own, pruning would remove the definitions of other tests otherwise
+    async fn setup_client() -> EdcClient {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(edc_server::serve(listener, "/management", Arc::new(InMemoryStore::with_participant_id("provider"))));
+        EdcClient::new(Configuration {
+            base_path: format!("http://{}/management", address),
+            ..Configuration::default()
+        })
+    }
+
+    fn step(kind: Kind, id: &str, action: Action, changes: &[&str]) -> Step {
+        Step { kind, id: id.to_string(), action, changes: changes.iter().map(|change| change.to_string()).collect() }
+    }
+
+    #[tokio::test]
+    async fn test_plan_and_apply() {
+        let client = setup_client().await;
+        let manifest = Manifest::from_yaml(MANIFEST).unwrap();
+
+        let plan = client.reconcile(manifest.clone()).plan().await.unwrap();
+        assert_eq!(vec![
+            step(Kind::PolicyDefinition, "allow-all", Action::Create, &[]),
+            step(Kind::Asset, "asset-1", Action::Create, &[]),
+            step(Kind::Asset, "asset-2", Action::Create, &[]),
+            step(Kind::ContractDefinition, "all-assets", Action::Create, &[]),
+        ], plan.steps);
+        assert!(client.assets().query(None).await.unwrap().is_empty());
+
+        let applied = client.reconcile(manifest.clone()).apply().await.unwrap();
+        assert_eq!(plan, applied);
+        assert_eq!(2, client.assets().query(None).await.unwrap().len());
+        assert_eq!(Some("allow-all".to_string()), client.contract_definitions().get("all-assets").await.unwrap().access_policy_id);
+
+        let plan = client.reconcile(manifest).plan().await.unwrap();
+        assert!(plan.is_empty());
+        assert!(plan.steps.iter().all(|step| step.action == Action::Unchanged));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_prune() {
+        let client = setup_client().await;
+        client.reconcile(Manifest::from_yaml(MANIFEST).unwrap()).apply().await.unwrap();
+
+        let mut manifest = Manifest::from_yaml(MANIFEST).unwrap();
+        manifest.assets[0].properties.insert("name".to_string(), serde_json::json!("Renamed asset"));
+        manifest.assets.remove(1);
+        manifest.contract_definitions.clear();
+
+        // Without pruning the definitions that left the manifest stay
+        let plan = client.reconcile(manifest.clone()).plan().await.unwrap();
+        assert_eq!(vec![step(Kind::Asset, "asset-1", Action::Update, &["properties"])], plan.changes().cloned().collect::<Vec<_>>());
+
+        let plan = client.reconcile(manifest.clone()).with_prune(true).apply().await.unwrap();
+        assert_eq!(vec![
+            step(Kind::ContractDefinition, "all-assets", Action::Delete, &[]),
+            step(Kind::Asset, "asset-1", Action::Update, &["properties"]),
+            step(Kind::Asset, "asset-2", Action::Delete, &[]),
+        ], plan.changes().cloned().collect::<Vec<_>>());
+
+        let assets = client.assets().query(None).await.unwrap();
+        assert_eq!(1, assets.len());
+        assert_eq!(Some(&serde_json::json!("Renamed asset")), assets[0].properties.as_ref().unwrap().get("name"));
+        assert!(client.contract_definitions().get("all-assets").await.unwrap_err().is_not_found());
+        assert!(client.reconcile(manifest).with_prune(true).plan().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compare_normalized() {
+        let client = setup_client().await;
+        client.reconcile(Manifest::from_yaml(MANIFEST).unwrap()).apply().await.unwrap();
+
+        // Same definitions with prefixed terms and a single permission without array
+        let manifest = Manifest::from_json(r#"{
+            "policyDefinitions": [{
+                "@context": {"edc": "https://w3id.org/edc/v0.0.1/ns/", "odrl": "http://www.w3.org/ns/odrl/2/"},
+                "@id": "allow-all",
+                "edc:policy": {"@type": "odrl:Set", "odrl:permission": {"odrl:action": "use"}}
+            }],
+            "assets": [{
+                "@context": {"edc": "https://w3id.org/edc/v0.0.1/ns/"},
+                "@id": "asset-1",
+                "edc:properties": {"edc:name": "First asset"},
+                "edc:dataAddress": {"@type": "edc:DataAddress", "edc:type": "HttpData", "edc:baseUrl": "https://example.com/1"}
+            }]
+        }"#).unwrap();
+
+        let plan = client.reconcile(manifest).plan().await.unwrap();
+        assert!(plan.is_empty(), "{:?}", plan);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_manifest() {
+        let client = setup_client().await;
+
+        let mut manifest = Manifest::from_yaml(MANIFEST).unwrap();
+        manifest.assets[1].at_id = None;
+        match client.reconcile(manifest).plan().await {
+            Err(ReconcileError::MissingId { kind: Kind::Asset }) => {},
+            other => panic!("Expected a missing id, got {:?}", other),
+        }
+
+        let mut manifest = Manifest::from_yaml(MANIFEST).unwrap();
+        manifest.assets[1].at_id = Some("asset-1".to_string());
+        match client.reconcile(manifest).apply().await {
+            Err(ReconcileError::DuplicateId { kind: Kind::Asset, id }) => assert_eq!("asset-1", id),
+            other => panic!("Expected a duplicate id, got {:?}", other),
+        }
+        assert!(client.policies().query(None).await.unwrap().is_empty());
+
+        let error = Manifest::from_json(r#"{"assets": [], "secrets": []}"#).unwrap_err();
+        assert!(error.to_string().contains("secrets"), "{}", error);
+        let error = Manifest::from_json(r#"{"assets": [{"@id": "asset-1"}]}"#).unwrap_err();
+        assert!(error.to_string().starts_with("invalid asset 1 of the manifest"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_step_failure() {
+        let client = setup_client().await;
+        let mut manifest = Manifest::from_yaml(MANIFEST).unwrap();
+        manifest.contract_definitions[0].access_policy_id = String::new();
+
+        // The connector rejects the contract definition, after the policy and the assets have been created
+        match client.reconcile(manifest).apply().await {
+            Err(ReconcileError::Step { step, source }) => {
+                assert_eq!((Kind::ContractDefinition, "all-assets", Action::Create), (step.kind, step.id.as_str(), step.action));
+                assert!(source.edc_error().unwrap().is_bad_request(), "{:?}", source);
+            },
+            other => panic!("Expected a failed step, got {:?}", other),
+        }
+        // The steps before the failed one have been applied
+        assert_eq!(2, client.assets().query(None).await.unwrap().len());
+    }
+
+}
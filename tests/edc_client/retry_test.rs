@@ -0,0 +1,135 @@
+mod common;
+
+#[cfg(test)]
+mod retry_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use edc_api::AssetInput;
+    use edc_client::configuration::{Configuration, RetryPolicy};
+    use edc_client::{asset_api, contract_negotiation_api, dataplane_selector_api, Error};
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+    use crate::common::asset_response;
+
+    use std::time::Duration;
+
+    fn retrying_configuration(base_path: String, retry: RetryPolicy) -> Configuration {
+        Configuration {
+            base_path,
+            ..Configuration::default()
+        }.with_retry(retry.with_backoff(Duration::from_millis(1), Duration::from_millis(5)))
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE).times(2));
+        server.respond(Method::GET, "/v3/assets/asset-1", asset_response("asset-1"));
+        let configuration = retrying_configuration(server.url(), RetryPolicy::default());
+
+        let asset = asset_api::get_asset(&configuration, "asset-1").await.unwrap();
+
+        assert_eq!(Some("asset-1".to_string()), asset.at_id);
+        assert_eq!(3, server.requests_to(Method::GET, "/v3/assets/asset-1").len());
+    }
+
+    #[tokio::test]
+    async fn test_attempts_are_recorded() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE));
+        let configuration = retrying_configuration(server.url(), RetryPolicy::default().with_max_attempts(4));
+
+        match asset_api::get_asset(&configuration, "asset-1").await {
+            Err(Error::ResponseError(response)) => {
+                assert_eq!(reqwest::StatusCode::SERVICE_UNAVAILABLE, response.status);
+                assert_eq!(4, response.attempts);
+            },
+            _ => panic!("Expected Status Code 503 after all attempts"),
+        }
+        assert_eq!(4, server.requests().len());
+
+        // Status codes that are not retryable fail on the first attempt, the empty store has no asset
+        server.reset();
+        let error = asset_api::get_asset(&retrying_configuration(server.url(), RetryPolicy::default()), "asset-1").await.unwrap_err();
+        assert_eq!(Some(1), error.attempts());
+        assert_eq!(1, server.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_no_dataplane_found_after_retry() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/v2/dataplanes/select", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE).times(1));
+        server.respond(Method::POST, "/v2/dataplanes/select", MockResponse::new(StatusCode::NO_CONTENT));
+        let configuration = retrying_configuration(server.url(), RetryPolicy::default());
+
+        match dataplane_selector_api::find(&configuration, None).await {
+            Err(Error::ResponseError(response)) => {
+                assert_eq!(reqwest::StatusCode::NO_CONTENT, response.status);
+                assert_eq!("No suitable DataPlane instance was found", response.content);
+                assert_eq!(2, response.attempts);
+            },
+            _ => panic!("Expected Status Code 204, because no dataplane fits the selection"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_requests_need_opt_in() {
+        let server = MockServer::start().await.unwrap();
+        server.respond(Method::POST, "/v3/assets", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE).times(1));
+        server.respond(Method::POST, "/v3/assets", MockResponse::json(StatusCode::OK, &serde_json::json!({"@id": "asset-1", "createdAt": 1})));
+
+        let error = asset_api::create_asset(&retrying_configuration(server.url(), RetryPolicy::default()), Some(AssetInput::default())).await.unwrap_err();
+        assert_eq!(Some(1), error.attempts());
+        assert_eq!(1, server.requests_to(Method::POST, "/v3/assets").len());
+
+        server.reset();
+        server.respond(Method::POST, "/v3/assets", MockResponse::new(StatusCode::SERVICE_UNAVAILABLE).times(1));
+        server.respond(Method::POST, "/v3/assets", MockResponse::json(StatusCode::OK, &serde_json::json!({"@id": "asset-1", "createdAt": 1})));
+        let configuration = retrying_configuration(server.url(), RetryPolicy::default().with_retry_non_idempotent(true));
+        asset_api::create_asset(&configuration, Some(AssetInput::default())).await.unwrap();
+        assert_eq!(2, server.requests_to(Method::POST, "/v3/assets").len());
+
+        // Queries are idempotent even though they are sent with POST
+        server.respond(Method::POST, "/v2/contractnegotiations/request", MockResponse::new(StatusCode::BAD_GATEWAY).times(1));
+        let negotiations = contract_negotiation_api::query_negotiations(&retrying_configuration(server.url(), RetryPolicy::default()), None).await.unwrap();
+        assert!(negotiations.is_empty());
+        assert_eq!(2, server.requests_to(Method::POST, "/v2/contractnegotiations/request").len());
+    }
+
+    #[tokio::test]
+    async fn test_transport_errors() {
+        // Bind and drop a listener to get a port nobody listens on
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let configuration = retrying_configuration(format!("http://127.0.0.1:{}", port), RetryPolicy::default().with_max_attempts(2));
+
+        match asset_api::get_asset(&configuration, "asset-1").await {
+            Err(Error::Transport { attempts, .. }) => assert_eq!(2, attempts),
+            _ => panic!("Expected a transport error"),
+        }
+
+        let error = asset_api::get_asset(&configuration.with_retry(RetryPolicy::none()), "asset-1").await.unwrap_err();
+        assert_eq!(Some(1), error.attempts());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default()
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .with_jitter(false);
+
+        assert_eq!(Duration::from_millis(100), policy.backoff(1));
+        assert_eq!(Duration::from_millis(200), policy.backoff(2));
+        assert_eq!(Duration::from_millis(400), policy.backoff(3));
+        assert_eq!(Duration::from_millis(500), policy.backoff(10));
+
+        let policy = policy.with_jitter(true);
+        for attempt in 1..5 {
+            let backoff = policy.backoff(attempt);
+            let without_jitter = policy.clone().with_jitter(false).backoff(attempt);
+            assert!(backoff <= without_jitter && backoff >= without_jitter / 2);
+        }
+    }
+
+}
@@ -30,6 +30,7 @@ mod transfer_process_api_initiate_test {
                 at_type: Some("HttpProxy".to_string()),
                 r#type: Some("HttpProxy".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             }),
             private_properties: None,
             protocol: "dataspace-protocol-http".to_string(),
@@ -58,6 +59,7 @@ mod transfer_process_api_initiate_test {
                 at_type: Some("HttpProxy".to_string()),
                 r#type: Some("HttpProxy".to_string()),
                 base_url: None,
+                additional_properties: std::collections::HashMap::new(),
             }),
             private_properties: None,
             protocol: "dataspace-protocol-http".to_string(),
@@ -76,7 +78,7 @@ mod transfer_process_api_request_test {
     extern crate edc_client;
 
     use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_transfer_process};
-    use edc_api::{Criterion, QuerySpec};
+    use edc_api::{Criterion, CriterionOperator, QuerySpec};
     use edc_client::transfer_process_api;
     use odrl::name_spaces::EDC_NS;
 
@@ -93,7 +95,7 @@ mod transfer_process_api_request_test {
         let criterion = Criterion {
             at_type: None,
             operand_left: serde_json::Value::from("id"),
-            operator: "=".to_string(),
+            operator: CriterionOperator::Eq,
             operand_right: serde_json::Value::from(first_transfer_id.clone()),
         };
 
@@ -218,6 +220,7 @@ mod transfer_process_api_resume_test {
                 at_type: None,
                 r#type: Some("HttpProxy".to_string()),
                 base_url: Some(PROVIDER_PROTOCOL.to_string()),
+                additional_properties: std::collections::HashMap::new(),
             }),
             private_properties: None,
             protocol: "dataspace-protocol-http".to_string(),
@@ -0,0 +1,134 @@
+mod common;
+
+#[cfg(test)]
+mod watch_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_contract_negotiation, setup_random_transfer_process, DATASPACE_PROTOCOL, PROVIDER_ID, PROVIDER_PROTOCOL};
+    use edc_api::transfer_state::TransferProcessState;
+    use edc_api::{ContractNegotiation, ContractNegotiationState, ContractRequest, Offer, TerminateTransfer};
+    use edc_client::configuration::Configuration;
+    use edc_client::watch::{PollInterval, WaitError};
+    use edc_client::{contract_negotiation_api, transfer_process_api, EdcClient};
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+
+    use futures::TryStreamExt;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_poll_interval() {
+        let poll_interval = PollInterval::new(Duration::from_millis(100), Duration::from_millis(400)).with_multiplier(2.0);
+        assert_eq!(Duration::from_millis(100), poll_interval.after(0));
+        assert_eq!(Duration::from_millis(200), poll_interval.after(1));
+        assert_eq!(Duration::from_millis(400), poll_interval.after(2));
+        assert_eq!(Duration::from_millis(400), poll_interval.after(100));
+
+        let fixed = PollInterval::from(Duration::from_millis(50));
+        assert_eq!(Duration::from_millis(50), fixed.after(0));
+        assert_eq!(Duration::from_millis(50), fixed.after(10));
+    }
+
+    #[tokio::test]
+    async fn test_watch_negotiation() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (negotiation_id, _) = setup_random_contract_negotiation(&consumer, &provider).await;
+
+        let poll_interval = PollInterval::new(Duration::from_millis(10), Duration::from_millis(100));
+        let negotiations: Vec<_> = contract_negotiation_api::watch_negotiation(&consumer, &negotiation_id, poll_interval)
+            .try_collect().await.unwrap();
+        let states: Vec<_> = negotiations.iter().map(|negotiation| negotiation.state).collect();
+
+        // Every state is reported once, the stream ends with the terminal state
+        assert!(states.windows(2).all(|pair| pair[0] != pair[1]));
+        assert_eq!(Some(&ContractNegotiationState::Finalized), states.last());
+        assert!(states.contains(&ContractNegotiationState::Requested));
+    }
+
+    #[tokio::test]
+    async fn test_watch_transfer_process() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (transfer_process_id, _, _) = setup_random_transfer_process(&consumer, &provider).await;
+
+        let client = EdcClient::new(consumer.clone());
+        let mut transfer_processes = Box::pin(client.transfers().watch(&transfer_process_id));
+        let mut started = false;
+        while let Some(transfer_process) = transfer_processes.try_next().await.unwrap() {
+            if transfer_process.state == Some(TransferProcessState::Started) {
+                started = true;
+                let terminate = TerminateTransfer { reason: Some("done".to_string()), ..TerminateTransfer::default() };
+                transfer_process_api::terminate_transfer_process(&consumer, &transfer_process_id, Some(terminate)).await.unwrap();
+            }
+        }
+        assert!(started);
+
+        let transfer_process = transfer_process_api::get_transfer_process(&consumer, &transfer_process_id).await.unwrap();
+        assert_eq!(Some(TransferProcessState::Terminated), transfer_process.state);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (transfer_process_id, _, _) = setup_random_transfer_process(&consumer, &provider).await;
+
+        let client = EdcClient::new(consumer);
+        let transfer_process = client.transfers()
+            .wait_until(&transfer_process_id, |state| *state == TransferProcessState::Started, Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert_eq!(Some(transfer_process_id), transfer_process.at_id);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_timeout() {
+        // The negotiation stays requested, so waiting for the consumer to verify it can only time out
+        let server = MockServer::start().await.unwrap();
+        let configuration = Configuration { base_path: server.url(), ..Configuration::default() };
+        let negotiation = ContractNegotiation {
+            at_id: Some("negotiation-1".to_string()),
+            state: ContractNegotiationState::Requested,
+            ..ContractNegotiation::default()
+        };
+        server.respond(Method::GET, "/v2/contractnegotiations/negotiation-1", MockResponse::json(StatusCode::OK, &negotiation));
+
+        let result = contract_negotiation_api::wait_for_negotiation(&configuration, "negotiation-1", |state| *state == ContractNegotiationState::Verified, Duration::from_millis(300)).await;
+
+        assert!(matches!(result, Err(WaitError::Timeout { .. })), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_unreachable() {
+        let consumer = setup_consumer_configuration();
+
+        // The provider terminates negotiations for assets it doesn't have
+        let asset_id = Uuid::new_v4().to_string();
+        let contract_request = ContractRequest {
+            counter_party_address: PROVIDER_PROTOCOL.to_string(),
+            policy: Some(Offer {
+                at_id: Uuid::new_v4().to_string(),
+                assigner: PROVIDER_ID.to_string(),
+                target: asset_id,
+                ..Offer::default()
+            }),
+            protocol: DATASPACE_PROTOCOL.to_string(),
+            ..ContractRequest::default()
+        };
+        let negotiation_id = contract_negotiation_api::initiate_contract_negotiation(&consumer, Some(contract_request)).await.unwrap().at_id.unwrap();
+
+        let result = EdcClient::new(consumer).negotiations()
+            .wait_until(&negotiation_id, |state| *state == ContractNegotiationState::Finalized, Duration::from_secs(10))
+            .await;
+
+        match result {
+            Err(WaitError::Unreachable(negotiation)) => assert_eq!(ContractNegotiationState::Terminated, negotiation.state),
+            other => panic!("Expected the negotiation to be terminated, got {:?}", other),
+        }
+    }
+
+}
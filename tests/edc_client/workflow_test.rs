@@ -0,0 +1,168 @@
+mod common;
+
+#[cfg(test)]
+mod workflow_test {
+
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use crate::common::{setup_consumer_configuration, setup_provider_configuration, setup_random_contract_definition, PROVIDER_ID, PROVIDER_PROTOCOL};
+    use edc_api::transfer_state::TransferProcessState;
+    use edc_api::{ContractNegotiationState, ContractOffer};
+    use edc_client::workflow::{NegotiateAndTransfer, WorkflowError};
+    use edc_client::configuration::Configuration;
+    use edc_client::{contract_agreement_api, contract_negotiation_api, EdcClient};
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_negotiate_and_transfer() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let transfer = EdcClient::new(consumer.clone())
+            .negotiate_and_transfer(PROVIDER_PROTOCOL, PROVIDER_ID, asset_id.clone())
+            .with_poll_interval(Duration::from_millis(50))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(Some(TransferProcessState::Started), transfer.transfer_process.state);
+        assert_eq!(Some(asset_id.clone()), transfer.transfer_process.asset_id);
+        let agreement = contract_agreement_api::get_agreement_by_id(&consumer, &transfer.contract_agreement_id).await.unwrap();
+        assert_eq!(Some(asset_id), agreement.asset_id);
+    }
+
+    #[tokio::test]
+    async fn test_terminated_negotiation() {
+        let consumer = setup_consumer_configuration();
+
+        // Offers for assets the provider does not have are terminated by the provider
+        let asset_id = Uuid::new_v4().to_string();
+        let offer = ContractOffer {
+            at_id: Uuid::new_v4().to_string(),
+            assigner: Some(PROVIDER_ID.to_string()),
+            target: Some(asset_id.clone()),
+            ..ContractOffer::default()
+        };
+
+        let result = NegotiateAndTransfer::new(&consumer, PROVIDER_PROTOCOL, PROVIDER_ID, asset_id.clone())
+            .with_offer(offer)
+            .with_poll_interval(Duration::from_millis(50))
+            .run()
+            .await;
+
+        match result {
+            Err(WorkflowError::NegotiationTerminated { error_detail, .. }) => assert!(error_detail.unwrap().contains(&asset_id)),
+            other => panic!("Expected a terminated negotiation, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_matching_offer() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let result = NegotiateAndTransfer::new(&consumer, PROVIDER_PROTOCOL, PROVIDER_ID, asset_id)
+            .with_offer_filter(|offer| offer.permission.len() > 1)
+            .run()
+            .await;
+
+        assert!(matches!(result, Err(WorkflowError::NoOffer { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let result = NegotiateAndTransfer::new(&consumer, PROVIDER_PROTOCOL, PROVIDER_ID, asset_id)
+            .with_timeout(Duration::from_millis(20))
+            .run()
+            .await;
+
+        match result {
+            Err(WorkflowError::NegotiationTimeout { state, .. }) => assert_eq!(ContractNegotiationState::Requested, state),
+            other => panic!("Expected a timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_terminates_the_negotiation() {
+        let provider = setup_provider_configuration();
+        let consumer = setup_consumer_configuration();
+        let (asset_id, _, _) = setup_random_contract_definition(&provider).await;
+
+        let (cancel, cancelled) = tokio::sync::oneshot::channel::<()>();
+        let workflow = tokio::spawn({
+            let consumer = consumer.clone();
+            async move {
+                NegotiateAndTransfer::new(&consumer, PROVIDER_PROTOCOL, PROVIDER_ID, asset_id)
+                    .with_poll_interval(Duration::from_millis(10))
+                    .run_until(async { let _ = cancelled.await; })
+                    .await
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cancel.send(()).unwrap();
+
+        let negotiation_id = match workflow.await.unwrap() {
+            Err(WorkflowError::Cancelled { negotiation_id: Some(negotiation_id), transfer_process_id: None }) => negotiation_id,
+            other => panic!("Expected the workflow to be cancelled during the negotiation, got {:?}", other),
+        };
+        let negotiation = contract_negotiation_api::get_negotiation(&consumer, &negotiation_id).await.unwrap();
+        assert_eq!(ContractNegotiationState::Terminated, negotiation.state);
+    }
+
+    fn mock_offer() -> ContractOffer {
+        ContractOffer {
+            at_id: "offer-1".to_string(),
+            assigner: Some(PROVIDER_ID.to_string()),
+            target: Some("asset-1".to_string()),
+            ..ContractOffer::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_missing_negotiation_id() {
+        let server = MockServer::start().await.unwrap();
+        let configuration = Configuration { base_path: server.url(), ..Configuration::default() };
+        server.respond(Method::POST, "/v2/contractnegotiations", MockResponse::json(StatusCode::OK, &serde_json::json!({})));
+
+        let result = NegotiateAndTransfer::new(&configuration, PROVIDER_PROTOCOL, PROVIDER_ID, "asset-1")
+            .with_offer(mock_offer())
+            .run()
+            .await;
+
+        assert!(matches!(result, Err(WorkflowError::MissingId { step: "contract negotiation" })), "{:?}", result);
+        assert!(server.requests_to(Method::GET, "/v2/contractnegotiations/*").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_while_initiating() {
+        let server = MockServer::start().await.unwrap();
+        let configuration = Configuration { base_path: server.url(), ..Configuration::default() };
+        let initiated = MockResponse::json(StatusCode::OK, &serde_json::json!({ "@id": "negotiation-1" })).with_delay(Duration::from_millis(300));
+        server.respond(Method::POST, "/v2/contractnegotiations", initiated);
+        server.respond(Method::POST, "/v2/contractnegotiations/*/terminate", MockResponse::new(StatusCode::NO_CONTENT));
+
+        // The negotiation is started after the cancellation, and is terminated as soon as its id is known
+        let result = NegotiateAndTransfer::new(&configuration, PROVIDER_PROTOCOL, PROVIDER_ID, "asset-1")
+            .with_offer(mock_offer())
+            .run_until(tokio::time::sleep(Duration::from_millis(50)))
+            .await;
+
+        match result {
+            Err(WorkflowError::Cancelled { negotiation_id, transfer_process_id: None }) => assert_eq!(Some("negotiation-1".to_string()), negotiation_id),
+            other => panic!("Expected the workflow to be cancelled, got {:?}", other),
+        }
+        assert_eq!(1, server.requests_to(Method::POST, "/v2/contractnegotiations/negotiation-1/terminate").len());
+    }
+
+}
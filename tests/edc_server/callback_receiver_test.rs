@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod callback_receiver_test {
+    extern crate edc_api;
+    extern crate edc_server;
+
+    use edc_api::Event;
+    use edc_server::callbacks::CallbackReceiver;
+    use tokio::sync::mpsc;
+
+    const AUTH_KEY: &str = "X-Callback-Key";
+    const SECRET: &str = "callback-secret";
+
+    async fn spawn_receiver(receiver: CallbackReceiver) -> (String, mpsc::Receiver<edc_api::EventEnvelope>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let events = receiver.spawn(listener, "/callbacks");
+        (format!("http://{}/callbacks", address), events)
+    }
+
+    fn transfer_started() -> serde_json::Value {
+        serde_json::json!({
+            "id": "a0d5c9c4-1d5b-4f0b-9f5e-6f0e2f7b1c3d",
+            "at": 1718000000000u64,
+            "payload": {
+                "transferProcessId": "transfer-1",
+                "callbackAddresses": [{ "uri": "http://consumer-backend/callbacks", "events": ["transfer.process"], "transactional": false, "authKey": AUTH_KEY, "authCodeId": "callback-secret-alias" }],
+                "assetId": "asset-1",
+                "type": "CONSUMER",
+                "contractId": "agreement-1",
+                "dataAddress": { "properties": { "https://w3id.org/edc/v0.0.1/ns/endpoint": "http://provider-connector:9291/public" } }
+            },
+            "type": "TransferProcessStarted"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_receive_event() {
+        let (url, mut events) = spawn_receiver(CallbackReceiver::new().with_auth(AUTH_KEY, SECRET)).await;
+
+        let response = reqwest::Client::new().post(&url).header(AUTH_KEY, SECRET).json(&transfer_started()).send().await.unwrap();
+        assert_eq!(204, response.status().as_u16());
+
+        let envelope = events.recv().await.unwrap();
+        assert_eq!("transfer.process.started", envelope.event.name());
+        let Event::TransferProcessStarted(transfer_process) = envelope.event else {
+            panic!("Unexpected event {:?}", envelope.event);
+        };
+        assert_eq!("transfer-1", transfer_process.transfer_process_id);
+        assert_eq!(Some("agreement-1".to_string()), transfer_process.contract_id);
+        assert!(transfer_process.data_address.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reject_unauthenticated_event() {
+        let (url, mut events) = spawn_receiver(CallbackReceiver::new().with_auth(AUTH_KEY, SECRET)).await;
+        let client = reqwest::Client::new();
+
+        let response = client.post(&url).json(&transfer_started()).send().await.unwrap();
+        assert_eq!(401, response.status().as_u16());
+        let response = client.post(&url).header(AUTH_KEY, "wrong-secret").json(&transfer_started()).send().await.unwrap();
+        assert_eq!(401, response.status().as_u16());
+
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reject_invalid_event() {
+        let (url, mut events) = spawn_receiver(CallbackReceiver::new()).await;
+
+        let response = reqwest::Client::new().post(&url).json(&serde_json::json!({ "type": "TransferProcessStarted" })).send().await.unwrap();
+        assert_eq!(400, response.status().as_u16());
+        let details: Vec<edc_api::ApiErrorDetail> = response.json().await.unwrap();
+        assert_eq!(Some("InvalidRequest".to_string()), details[0].r#type);
+
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_closed_receiver() {
+        let (url, events) = spawn_receiver(CallbackReceiver::new()).await;
+        drop(events);
+
+        // The connector retries callbacks that fail
+        let response = reqwest::Client::new().post(&url).json(&transfer_started()).send().await.unwrap();
+        assert_eq!(503, response.status().as_u16());
+    }
+
+}
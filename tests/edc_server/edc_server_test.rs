@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod edc_server_test {
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use edc_api::{AssetInput, ContractRequest, Criterion, CriterionOperator, DataAddress, NegotiationState, Offer, QuerySpec, TransferRequest, TransferState};
+    use edc_api::query_spec::SortOrder;
+    use edc_api::transfer_state::TransferProcessState;
+    use edc_client::configuration::Configuration;
+    use edc_client::{asset_api, contract_negotiation_api, transfer_process_api, Error};
+    use edc_server::InMemoryStore;
+
+    async fn setup_server() -> Configuration {
+        let mut store = InMemoryStore::new();
+        store.processing_delay = Duration::from_millis(10);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(edc_server::serve(listener, "/management", Arc::new(store)));
+
+        let mut configuration = Configuration::default();
+        configuration.base_path = format!("http://{}/management", address);
+        configuration
+    }
+
+    async fn create_asset(configuration: &Configuration, id: &str, name: &str, version: i64) {
+        let asset = AssetInput {
+            at_id: Some(id.to_string()),
+            data_address: Box::new(DataAddress::new(None, Some("HttpData".to_string()), Some("https://example.com".to_string()))),
+            properties: std::collections::HashMap::from([
+                ("name".to_string(), serde_json::Value::from(name)),
+                ("version".to_string(), serde_json::Value::from(version)),
+            ]),
+            ..AssetInput::default()
+        };
+        asset_api::create_asset(configuration, Some(asset)).await.unwrap();
+    }
+
+    fn query(filter_expression: Vec<Criterion>) -> QuerySpec {
+        QuerySpec {
+            at_context: None,
+            at_type: Some("QuerySpec".to_string()),
+            filter_expression,
+            limit: None,
+            offset: None,
+            sort_field: None,
+            sort_order: None,
+        }
+    }
+
+    fn criterion(operand_left: &str, operator: CriterionOperator, operand_right: serde_json::Value) -> Criterion {
+        Criterion::new(None, serde_json::Value::from(operand_left), operand_right, operator)
+    }
+
+    async fn query_ids(configuration: &Configuration, query_spec: QuerySpec) -> Vec<String> {
+        asset_api::request_assets(configuration, Some(query_spec)).await.unwrap()
+            .into_iter()
+            .map(|asset| asset.at_id.unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_query_assets() {
+        let configuration = setup_server().await;
+        create_asset(&configuration, "asset-1", "Weather Data", 1).await;
+        create_asset(&configuration, "asset-2", "weather forecast", 2).await;
+        create_asset(&configuration, "asset-3", "Traffic Data", 3).await;
+
+        let ids = query_ids(&configuration, query(vec![criterion("https://w3id.org/edc/v0.0.1/ns/id", CriterionOperator::Eq, "asset-2".into())])).await;
+        assert_eq!(ids, vec!["asset-2"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("name", CriterionOperator::Like, "%Data".into())])).await;
+        assert_eq!(ids, vec!["asset-1", "asset-3"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("name", CriterionOperator::ILike, "weather%".into())])).await;
+        assert_eq!(ids, vec!["asset-1", "asset-2"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("id", CriterionOperator::In, serde_json::json!(["asset-1", "asset-3"]))])).await;
+        assert_eq!(ids, vec!["asset-1", "asset-3"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("version", CriterionOperator::Gt, 1.into()), criterion("id", CriterionOperator::Ne, "asset-3".into())])).await;
+        assert_eq!(ids, vec!["asset-2"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("version", CriterionOperator::Le, 2.into())])).await;
+        assert_eq!(ids, vec!["asset-1", "asset-2"]);
+
+        let ids = query_ids(&configuration, query(vec![criterion("version", CriterionOperator::Ge, 2.into())])).await;
+        assert_eq!(ids, vec!["asset-2", "asset-3"]);
+
+        let mut sorted = query(vec![]);
+        sorted.sort_field = Some("version".to_string());
+        sorted.sort_order = Some(SortOrder::Desc);
+        sorted.offset = Some(1);
+        sorted.limit = Some(1);
+        assert_eq!(query_ids(&configuration, sorted).await, vec!["asset-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_query_with_unknown_operator() {
+        let configuration = setup_server().await;
+
+        // Operators the server does not implement are rejected instead of matching nothing
+        let response = configuration.client.post(format!("{}/v3/assets/request", configuration.base_path))
+            .json(&serde_json::json!({ "filterExpression": [{ "operandLeft": "id", "operator": "~", "operandRight": "asset" }] }))
+            .send().await.unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST, "Expected Status Code 400, because the operator is not supported");
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_and_transfer() {
+        let configuration = setup_server().await;
+        create_asset(&configuration, "asset", "Weather Data", 1).await;
+
+        let contract_request = ContractRequest {
+            counter_party_address: "http://localhost/protocol".to_string(),
+            policy: Some(Offer::new(Default::default(), Some("Offer".to_string()), "offer".to_string(), "provider".to_string(), "asset".to_string())),
+            protocol: "dataspace-protocol-http".to_string(),
+            ..ContractRequest::default()
+        };
+        let negotiation_id = contract_negotiation_api::initiate_contract_negotiation(&configuration, Some(contract_request)).await.unwrap().at_id.unwrap();
+
+        let mut state = contract_negotiation_api::get_negotiation_state(&configuration, &negotiation_id).await.unwrap();
+        while state == (NegotiationState { state: edc_api::ContractNegotiationState::Requested }) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            state = contract_negotiation_api::get_negotiation_state(&configuration, &negotiation_id).await.unwrap();
+        }
+        assert_eq!(state.state, edc_api::ContractNegotiationState::Finalized);
+
+        let agreement = contract_negotiation_api::get_agreement_for_negotiation(&configuration, &negotiation_id).await.unwrap();
+        assert_eq!(agreement.asset_id, Some("asset".to_string()));
+        assert_eq!(agreement.provider_id, Some("provider".to_string()));
+
+        let transfer_request = TransferRequest {
+            asset_id: "asset".to_string(),
+            contract_id: agreement.at_id.unwrap(),
+            counter_party_address: "http://localhost/protocol".to_string(),
+            data_destination: Box::new(DataAddress::new(None, Some("HttpProxy".to_string()), None)),
+            protocol: "dataspace-protocol-http".to_string(),
+            transfer_type: "HttpData-PULL".to_string(),
+            ..TransferRequest::default()
+        };
+        let transfer_id = transfer_process_api::initiate_transfer_process(&configuration, Some(transfer_request)).await.unwrap().at_id.unwrap();
+
+        let mut state = transfer_process_api::get_transfer_process_state(&configuration, &transfer_id).await.unwrap();
+        while state == (TransferState { state: TransferProcessState::Requested }) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            state = transfer_process_api::get_transfer_process_state(&configuration, &transfer_id).await.unwrap();
+        }
+        assert_eq!(state.state, TransferProcessState::Started);
+
+        // Resuming is only possible for suspended transfers
+        let response = transfer_process_api::resume_transfer_process(&configuration, &transfer_id).await;
+        match response {
+            Err(Error::ResponseError(response)) => {
+                assert_eq!(response.status, reqwest::StatusCode::CONFLICT);
+            },
+            _ => panic!("Expected Status Code 409, because the transfer process is not suspended"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_negotiation_for_unknown_asset_terminates() {
+        let configuration = setup_server().await;
+
+        let contract_request = ContractRequest {
+            counter_party_address: "http://localhost/protocol".to_string(),
+            policy: Some(Offer::new(Default::default(), None, "offer".to_string(), "provider".to_string(), "unknown".to_string())),
+            protocol: "dataspace-protocol-http".to_string(),
+            ..ContractRequest::default()
+        };
+        let negotiation_id = contract_negotiation_api::initiate_contract_negotiation(&configuration, Some(contract_request)).await.unwrap().at_id.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let negotiation = contract_negotiation_api::get_negotiation(&configuration, &negotiation_id).await.unwrap();
+        assert_eq!(negotiation.state, edc_api::ContractNegotiationState::Terminated);
+        assert!(negotiation.error_detail.is_some());
+    }
+
+}
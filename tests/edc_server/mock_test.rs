@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod mock_test {
+    extern crate edc_api;
+    extern crate edc_client;
+    extern crate edc_server;
+
+    use std::time::Duration;
+
+    use edc_api::{AssetInput, AssetOutput, DataAddress};
+    use edc_client::configuration::{ApiKey, Configuration, RetryPolicy};
+    use edc_client::EdcClient;
+    use edc_server::mock::{Method, MockResponse, MockServer, StatusCode};
+
+    fn client(server: &MockServer) -> EdcClient {
+        let configuration = Configuration {
+            base_path: server.url(),
+            api_key: Some(ApiKey::new("123456")),
+            ..Configuration::default()
+        };
+        EdcClient::new(configuration.with_retry(RetryPolicy::default().with_backoff(Duration::from_millis(10), Duration::from_millis(10))))
+    }
+
+    fn asset(id: &str) -> AssetInput {
+        AssetInput {
+            at_id: Some(id.to_string()),
+            data_address: Box::new(DataAddress::new(None, Some("HttpData".to_string()), Some("https://example.com".to_string()))),
+            properties: std::collections::HashMap::from([("name".to_string(), serde_json::Value::from("Example"))]),
+            ..AssetInput::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_and_recording() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        client.assets().create(asset("asset-1")).await.unwrap();
+        let output = client.assets().get("asset-1").await.unwrap();
+        assert_eq!(Some("asset-1".to_string()), output.at_id);
+
+        let requests = server.requests();
+        assert_eq!(vec![(Method::POST, "/v3/assets"), (Method::GET, "/v3/assets/asset-1")],
+                   requests.iter().map(|request| (request.method.clone(), request.path.as_str())).collect::<Vec<_>>());
+        assert_eq!(Some("123456"), requests[0].header("x-api-key"));
+        assert_eq!(Some("asset-1"), requests[0].json().unwrap()["@id"].as_str());
+        assert_eq!(1, server.requests_to(Method::GET, "/v3/assets/*").len());
+    }
+
+    #[tokio::test]
+    async fn test_scripted_response() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let scripted = AssetOutput {
+            at_id: Some("scripted".to_string()),
+            ..AssetOutput::default()
+        };
+        server.respond(Method::GET, "/v3/assets/scripted", MockResponse::json(StatusCode::OK, &scripted));
+
+        assert_eq!(Some("scripted".to_string()), client.assets().get("scripted").await.unwrap().at_id);
+        assert_eq!(Some("scripted".to_string()), client.assets().get("scripted").await.unwrap().at_id);
+        assert!(client.assets().get("other").await.unwrap_err().is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_error_injection() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        server.respond(Method::POST, "/v3/assets", MockResponse::bad_request("properties must not be empty").times(1));
+        let error = client.assets().create(asset("asset-1")).await.unwrap_err();
+        let edc_error = error.edc_error().unwrap();
+        assert!(edc_error.is_bad_request());
+        assert_eq!(vec!["properties must not be empty"], edc_error.messages().collect::<Vec<_>>());
+
+        server.respond(Method::GET, "/v3/assets/*", MockResponse::not_found("No such asset").times(1));
+        client.assets().create(asset("asset-1")).await.unwrap();
+        assert!(client.assets().get("asset-1").await.unwrap_err().is_not_found());
+        assert!(client.assets().get("asset-1").await.is_ok());
+
+        server.respond(Method::DELETE, "/v3/assets/asset-1", MockResponse::conflict("Asset is referenced by an agreement").times(1));
+        let error = client.assets().delete("asset-1").await.unwrap_err();
+        assert!(error.is_conflict());
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_server_errors() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+        client.assets().create(asset("asset-1")).await.unwrap();
+        server.reset();
+
+        // Unavailable connectors are retried, the third attempt reaches the store
+        let unavailable = MockResponse::error(StatusCode::SERVICE_UNAVAILABLE, "ServiceUnavailable", "Starting up");
+        server.respond(Method::GET, "/v3/assets/asset-1", unavailable.times(2));
+        assert!(client.assets().get("asset-1").await.is_ok());
+        assert_eq!(3, server.requests_to(Method::GET, "/v3/assets/asset-1").len());
+
+        server.reset();
+        server.respond(Method::GET, "/v3/assets/asset-1", MockResponse::server_error("Database unavailable"));
+        let error = client.assets().get("asset-1").await.unwrap_err();
+        assert!(error.edc_error().unwrap().is_server_error());
+        assert!(!error.is_retryable());
+        assert_eq!(Some(1), error.attempts());
+        assert_eq!(1, server.requests().len());
+    }
+
+    #[tokio::test]
+    async fn test_delay() {
+        let server = MockServer::start().await.unwrap();
+        let configuration = Configuration {
+            base_path: server.url(),
+            client: reqwest::Client::builder().timeout(Duration::from_millis(50)).build().unwrap(),
+            retry: RetryPolicy::none(),
+            ..Configuration::default()
+        };
+
+        server.respond(Method::GET, "/v3/assets/slow", MockResponse::not_found("No such asset").with_delay(Duration::from_secs(5)));
+        let error = EdcClient::new(configuration).assets().get("slow").await.unwrap_err();
+        assert!(error.status().is_none(), "{:?}", error);
+    }
+
+}
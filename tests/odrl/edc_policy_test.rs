@@ -0,0 +1,202 @@
+#[cfg(test)]
+mod edc_policy_test {
+    extern crate odrl;
+
+    use edc_api::{ContractAgreement, ContractOffer, PolicyDefinitionInput};
+    use odrl::functions::edc_policy::{self, ContractAgreementExt, PolicyConversionError, PolicyDefinitionExt};
+    use odrl::model::action::Action;
+    use odrl::model::asset::Asset;
+    use odrl::model::constraint::{Constraint, LeftOperand, Operator, RightOperand};
+    use odrl::model::party::{Function, Party};
+    use odrl::model::policy::{OfferPolicy, Policy, SetPolicy};
+    use odrl::model::rule::{Permission, Prohibition, Rule};
+
+    fn asset(uid: &str) -> Asset {
+        Asset::new(None, Some(uid.to_string()), None, vec![], None, None, None, None, None, None)
+    }
+
+    fn expected_permission() -> Permission {
+        let constraint = Constraint::new(None, LeftOperand::Literal("dateTime".to_string()), Operator::LessThan, RightOperand::Literal("2030-01-01".to_string()), Some("xsd:date".to_string()), None, String::new());
+        Permission::new(None, Action::new("use", None, None, vec![]), None, vec![], vec![], vec![constraint], asset("asset-1"), None, None, vec![])
+    }
+
+    #[test]
+    fn test_compacted_policy_from_edc() {
+        let policy = serde_json::json!({
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "@id": "policy-1",
+            "permission": [{
+                "action": "use",
+                "target": "asset-1",
+                "constraint": [{
+                    "leftOperand": "dateTime",
+                    "operator": "lt",
+                    "rightOperand": { "@value": "2030-01-01", "@type": "xsd:date" }
+                }]
+            }],
+            "prohibition": [],
+            "obligation": []
+        });
+
+        let expected = SetPolicy::new("policy-1".to_string(), vec![Rule::Permission(expected_permission())], vec![], vec![], None, vec![]);
+        assert_eq!(edc_policy::from_edc(&policy).unwrap(), Policy::SetPolicy(expected));
+    }
+
+    #[test]
+    fn test_prefixed_policy_from_edc() {
+        let policy = serde_json::json!({
+            "@id": "policy-1",
+            "@type": "odrl:Set",
+            "odrl:permission": {
+                "odrl:action": { "@id": "odrl:use" },
+                "odrl:target": { "@id": "asset-1" },
+                "odrl:constraint": {
+                    "odrl:and": [{
+                        "odrl:leftOperand": { "@id": "odrl:dateTime" },
+                        "odrl:operator": { "@id": "odrl:lt" },
+                        "odrl:rightOperand": { "@value": "2030-01-01", "@type": "xsd:date" }
+                    }]
+                }
+            },
+            "odrl:prohibition": [],
+            "odrl:obligation": []
+        });
+
+        let expected = SetPolicy::new("policy-1".to_string(), vec![Rule::Permission(expected_permission())], vec![], vec![], None, vec![]);
+        assert_eq!(edc_policy::from_edc(&policy).unwrap(), Policy::SetPolicy(expected));
+    }
+
+    #[test]
+    fn test_expanded_policy_from_edc() {
+        let policy = serde_json::json!([{
+            "@id": "policy-1",
+            "@type": ["http://www.w3.org/ns/odrl/2/Set"],
+            "http://www.w3.org/ns/odrl/2/permission": [{
+                "http://www.w3.org/ns/odrl/2/action": [{ "@id": "http://www.w3.org/ns/odrl/2/use" }],
+                "http://www.w3.org/ns/odrl/2/target": [{ "@id": "asset-1" }],
+                "http://www.w3.org/ns/odrl/2/constraint": [{
+                    "http://www.w3.org/ns/odrl/2/leftOperand": [{ "@id": "http://www.w3.org/ns/odrl/2/dateTime" }],
+                    "http://www.w3.org/ns/odrl/2/operator": [{ "@id": "http://www.w3.org/ns/odrl/2/lt" }],
+                    "http://www.w3.org/ns/odrl/2/rightOperand": [{ "@value": "2030-01-01", "@type": "xsd:date" }]
+                }]
+            }]
+        }]);
+
+        let expected = SetPolicy::new("policy-1".to_string(), vec![Rule::Permission(expected_permission())], vec![], vec![], None, vec![]);
+        assert_eq!(edc_policy::from_edc(&policy).unwrap(), Policy::SetPolicy(expected));
+    }
+
+    #[test]
+    fn test_offer_policy_round_trip() {
+        let assigner = Party::new(Some("provider".to_string()), vec![], Function::Assigner, None);
+        let mut permission = expected_permission();
+        permission.assigner = Some(assigner.clone());
+        let constraint = Constraint::new(None, LeftOperand::IRI("https://w3id.org/edc/v0.0.1/ns/region".to_string()), Operator::IsAnyOf, RightOperand::Literal(r#"["eu","us"]"#.to_string()), None, None, String::new());
+        let prohibition = Prohibition::new(None, Action::new("distribute", None, None, vec![]), None, vec![], vec![], vec![constraint], asset("asset-1"), Some(assigner.clone()), None, vec![]);
+
+        let offer = OfferPolicy::new("offer-1".to_string(), assigner, vec![Rule::Permission(permission), Rule::Prohibition(prohibition)], vec![], vec![], None, vec![]);
+
+        let json = edc_policy::to_edc(&Policy::OfferPolicy(offer.clone()));
+        assert_eq!(json["@type"], "Offer");
+        assert_eq!(json["assigner"], "provider");
+        assert_eq!(json["prohibition"][0]["constraint"][0]["rightOperand"], serde_json::json!(["eu", "us"]));
+
+        assert_eq!(edc_policy::from_edc(&json).unwrap(), Policy::OfferPolicy(offer));
+    }
+
+    #[test]
+    fn test_policy_definition_from_typed_policy() {
+        let policy = SetPolicy::new(String::new(), vec![Rule::Permission(expected_permission())], vec![], vec![], None, vec![]);
+
+        let policy_definition = PolicyDefinitionInput::from(policy.clone());
+        assert_eq!(policy_definition.at_id, None);
+        assert_eq!(policy_definition.policy["@context"], edc_policy::ODRL_CONTEXT);
+        assert_eq!(policy_definition.odrl_policy().unwrap(), Policy::SetPolicy(policy.clone()));
+
+        let policy_definition = edc_policy::policy_definition("definition-1", policy);
+        assert_eq!(policy_definition.at_id, Some("definition-1".to_string()));
+    }
+
+    #[test]
+    fn test_agreement_policy() {
+        let mut agreement = ContractAgreement::default();
+        agreement.at_id = Some("agreement-1".to_string());
+        agreement.asset_id = Some("asset-1".to_string());
+        agreement.provider_id = Some("provider".to_string());
+        agreement.consumer_id = Some("consumer".to_string());
+        agreement.policy = Some(serde_json::json!({
+            "@id": "agreement-1",
+            "@type": "odrl:Agreement",
+            "odrl:permission": { "odrl:action": { "@id": "odrl:use" } },
+            "odrl:prohibition": [],
+            "odrl:obligation": [],
+            "odrl:assigner": "provider-did",
+            "odrl:target": { "@id": "asset-1" }
+        }));
+
+        let policy = agreement.agreement_policy().unwrap();
+        assert_eq!(policy.uid, "agreement-1");
+        assert_eq!(policy.assigner.uid, Some("provider-did".to_string()));
+        assert_eq!(policy.assignee.uid, Some("consumer".to_string()));
+        match &policy.rules[..] {
+            [Rule::Permission(permission)] => {
+                assert_eq!(permission.action.name, "use");
+                assert_eq!(permission.target.uid, Some("asset-1".to_string()));
+            },
+            rules => panic!("Expected one permission, got {:?}", rules),
+        }
+
+        agreement.policy = None;
+        assert_eq!(agreement.agreement_policy(), Err(PolicyConversionError::MissingPolicy));
+    }
+
+    #[test]
+    fn test_offer_policy() {
+        let offer: ContractOffer = serde_json::from_value(serde_json::json!({
+            "@id": "definition-1:asset-1:1234",
+            "@type": "odrl:Offer",
+            "odrl:assigner": { "@id": "provider" },
+            "odrl:target": { "@id": "asset-1" },
+            "odrl:permission": {
+                "odrl:action": { "@id": "odrl:use" },
+                "odrl:constraint": {
+                    "odrl:leftOperand": { "@id": "odrl:dateTime" },
+                    "odrl:operator": { "@id": "odrl:lt" },
+                    "odrl:rightOperand": { "@value": "2030-01-01", "@type": "xsd:date" }
+                }
+            },
+            "odrl:prohibition": [],
+            "odrl:obligation": []
+        })).unwrap();
+
+        let mut permission = expected_permission();
+        permission.assigner = Some(Party::new(Some("provider".to_string()), vec![], Function::Assigner, None));
+        let policy = OfferPolicy::try_from(&offer).unwrap();
+        assert_eq!(policy.uid, "definition-1:asset-1:1234");
+        assert_eq!(policy.assigner.uid, Some("provider".to_string()));
+        assert_eq!(policy.rules, vec![Rule::Permission(permission)]);
+        assert_eq!(edc_policy::offer_policy(&offer), Ok(policy));
+    }
+
+    #[test]
+    fn test_unsupported_policies() {
+        assert_eq!(edc_policy::from_edc(&serde_json::json!("policy")), Err(PolicyConversionError::NotAnObject));
+        assert_eq!(edc_policy::from_edc(&serde_json::json!({ "@type": "Ticket" })), Err(PolicyConversionError::UnsupportedType("Ticket".to_string())));
+
+        let policy = serde_json::json!({
+            "@type": "Set",
+            "permission": [{
+                "action": "use",
+                "constraint": [{ "leftOperand": "spatial", "operator": "within", "rightOperand": "eu" }]
+            }]
+        });
+        assert_eq!(edc_policy::from_edc(&policy), Err(PolicyConversionError::UnsupportedOperator("within".to_string())));
+
+        let policy = serde_json::json!({
+            "@type": "Set",
+            "permission": [{ "action": "use", "constraint": [{ "or": [] }] }]
+        });
+        assert_eq!(edc_policy::from_edc(&policy), Err(PolicyConversionError::UnsupportedConstraint("or".to_string())));
+    }
+}
@@ -0,0 +1,250 @@
+#[cfg(test)]
+mod evaluator_test {
+    extern crate odrl;
+
+    use odrl::functions::evaluator::{Decision, Evaluator, RequestContext};
+    use odrl::functions::json_parser;
+    use odrl::model::action::Action;
+    use odrl::model::rule::Rule;
+
+    fn evaluate(json_policy: &str, request: &RequestContext) -> (Decision, Vec<Rule>) {
+        let parsed_data = json_parser::parse(json_policy).unwrap();
+        let evaluator = Evaluator::with_constraints(parsed_data.parsed_constraints);
+        let result = evaluator.evaluate(&parsed_data.parsed_policies[0], request);
+        (result.decision, result.matched_rules)
+    }
+
+    #[test]
+    fn test_permit_matching_permission() {
+        let json_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play"
+            }]
+        }
+        "#;
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie");
+        let (decision, matched_rules) = evaluate(json_policy, &request);
+        assert_eq!(decision, Decision::Permit);
+        assert_eq!(matched_rules.len(), 1);
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:1111.movie");
+        assert_eq!(evaluate(json_policy, &request).0, Decision::NotApplicable);
+
+        let request = RequestContext::new(Action::new("print", None, None, vec![]), "https://example.com/asset:9898.movie");
+        assert_eq!(evaluate(json_policy, &request).0, Decision::NotApplicable);
+    }
+
+    #[test]
+    fn test_included_in_action_hierarchy() {
+        let json_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "use"
+            }]
+        }
+        "#;
+
+        let play = Action::new("play", None, Some(Action::new("use", None, None, vec![])), vec![]);
+        let request = RequestContext::new(play, "https://example.com/asset:9898.movie");
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Permit);
+    }
+
+    #[test]
+    fn test_date_time_constraint() {
+        let json_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play",
+                "constraint": [{
+                    "leftOperand": "dateTime",
+                    "operator": "lt",
+                    "rightOperand": { "@value": "2025-12-31", "@type": "xsd:date" }
+                }]
+            }]
+        }
+        "#;
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("dateTime", "2025-06-01T12:00:00Z".into());
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Permit);
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("dateTime", "2026-01-01T12:00:00Z".into());
+        let (decision, matched_rules) = evaluate(json_policy, &request);
+        assert_eq!(decision, Decision::Deny);
+        assert_eq!(matched_rules.len(), 1);
+    }
+
+    #[test]
+    fn test_count_and_set_constraints() {
+        let json_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play",
+                "constraint": [{
+                    "leftOperand": "count",
+                    "operator": "lteq",
+                    "rightOperand": 10
+                }, {
+                    "leftOperand": "spatial",
+                    "operator": "isAnyOf",
+                    "rightOperand": ["https://www.wikidata.org/wiki/Q183", "https://www.wikidata.org/wiki/Q40"]
+                }]
+            }]
+        }
+        "#;
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("count", 3.into())
+            .with_operand("spatial", "https://www.wikidata.org/wiki/Q183".into());
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Permit);
+
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("count", 11.into())
+            .with_operand("spatial", "https://www.wikidata.org/wiki/Q183".into());
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Deny);
+
+        // Unknown left operands can not be satisfied
+        let request = RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("count", 3.into());
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Deny);
+    }
+
+    #[test]
+    fn test_multi_valued_set_constraints() {
+        let policy = |operator: &str| format!(r#"
+        {{
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play",
+                "constraint": [{{
+                    "leftOperand": "spatial",
+                    "operator": "{operator}",
+                    "rightOperand": ["https://www.wikidata.org/wiki/Q183", "https://www.wikidata.org/wiki/Q40"]
+                }}]
+            }}]
+        }}
+        "#);
+        let request = |spatial: serde_json::Value| RequestContext::new(Action::new("play", None, None, vec![]), "https://example.com/asset:9898.movie")
+            .with_operand("spatial", spatial);
+
+        // One of the left values is enough for isAnyOf, isPartOf needs all of them
+        let mixed = serde_json::json!(["https://www.wikidata.org/wiki/Q183", "https://www.wikidata.org/wiki/Q142"]);
+        assert_eq!(evaluate(&policy("isAnyOf"), &request(mixed.clone())).0, Decision::Permit);
+        assert_eq!(evaluate(&policy("isPartOf"), &request(mixed)).0, Decision::Deny);
+
+        let contained = serde_json::json!(["https://www.wikidata.org/wiki/Q183", "https://www.wikidata.org/wiki/Q40"]);
+        assert_eq!(evaluate(&policy("isAnyOf"), &request(contained.clone())).0, Decision::Permit);
+        assert_eq!(evaluate(&policy("isPartOf"), &request(contained)).0, Decision::Permit);
+
+        let disjoint = serde_json::json!(["https://www.wikidata.org/wiki/Q142"]);
+        assert_eq!(evaluate(&policy("isAnyOf"), &request(disjoint)).0, Decision::Deny);
+    }
+
+    #[test]
+    fn test_prohibition_and_conflict_strategy() {
+        let json_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "conflict": "CONFLICT",
+            "permission": [{
+                "target": "https://example.com/photoAlbum:55",
+                "action": "archive"
+            }],
+            "prohibition": [{
+                "target": "https://example.com/photoAlbum:55",
+                "assignee": "https://example.com/assignee:55",
+                "action": "archive"
+            }]
+        }
+        "#;
+
+        let request = RequestContext::new(Action::new("archive", None, None, vec![]), "https://example.com/photoAlbum:55");
+        assert_eq!(evaluate(json_policy, &request).0, Decision::Permit);
+
+        let request = request.with_assignee("https://example.com/assignee:55");
+        let (decision, matched_rules) = evaluate(json_policy, &request);
+        assert_eq!(decision, Decision::Deny);
+        assert!(matches!(matched_rules[0], Rule::Prohibition(_)));
+
+        let json_policy = json_policy.replace("\"CONFLICT\"", "\"perm\"");
+        assert_eq!(evaluate(&json_policy, &request).0, Decision::Permit);
+    }
+
+    #[test]
+    fn test_logical_constraint_refinement() {
+        let json_policy_and_constraints = r#"
+        [{
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:88",
+            "permission": [{
+                "target": "https://example.com/book/1999",
+                "action": [{
+                    "rdf:value": { "@id": "odrl:reproduce" },
+                    "refinement": {
+                        "xone": {
+                            "@list": [
+                                { "@id": "https://example.com/p:88/C1" },
+                                { "@id": "https://example.com/p:88/C2" }
+                            ]
+                        }
+                    }
+                }]
+            }]
+        },
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Constraint",
+            "uid": "https://example.com/p:88/C1",
+            "leftOperand": "media",
+            "operator": "eq",
+            "rightOperand": { "@value": "online", "@type": "xsd:string" }
+        },
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Constraint",
+            "uid": "https://example.com/p:88/C2",
+            "leftOperand": "media",
+            "operator": "eq",
+            "rightOperand": { "@value": "print", "@type": "xsd:string" }
+        }]
+        "#;
+
+        let parsed_data = json_parser::parse(json_policy_and_constraints).unwrap();
+        let evaluator = Evaluator::with_constraints(parsed_data.parsed_constraints);
+        let policy = &parsed_data.parsed_policies[0];
+
+        let request = RequestContext::new(Action::new("reproduce", None, None, vec![]), "https://example.com/book/1999")
+            .with_operand("media", "online".into());
+        assert_eq!(evaluator.evaluate(policy, &request).decision, Decision::Permit);
+
+        let request = RequestContext::new(Action::new("reproduce", None, None, vec![]), "https://example.com/book/1999")
+            .with_operand("media", "broadcast".into());
+        assert_eq!(evaluator.evaluate(policy, &request).decision, Decision::Deny);
+    }
+
+}
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod state_machine_test {
+    extern crate edc_api;
+    extern crate odrl;
+
+    use edc_api::dsp::{Agreement, ContractAgreementMessage, ContractAgreementVerificationMessage, ContractNegotiationEventMessage,
+                       ContractNegotiationTerminationMessage, ContractOfferMessage, ContractRequestMessage, NegotiationEventType};
+    use edc_api::ContractOffer;
+    use odrl::functions::state_machine::{ConsumerState, ConsumerStateMachine, NegotiationMessage, ProviderState, ProviderStateMachine, StateMachineError};
+    use tokio::sync::{mpsc, oneshot};
+
+    const CONSUMER: &str = "http://example.com/consumer";
+    const PROVIDER: &str = "http://example.com/provider";
+
+    fn offer() -> ContractOffer {
+        ContractOffer {
+            at_id: "offer-1".to_string(),
+            assigner: Some(PROVIDER.to_string()),
+            target: Some("http://example.com/asset".to_string()),
+            ..ContractOffer::default()
+        }
+    }
+
+    fn agreement(provider_pid: &str, consumer_pid: &str) -> ContractAgreementMessage {
+        let agreement = Agreement::new("agreement-1".to_string(), Some("http://example.com/asset".to_string()), Some(PROVIDER.to_string()), Some(CONSUMER.to_string()));
+        ContractAgreementMessage::new(provider_pid.to_string(), consumer_pid.to_string(), agreement)
+    }
+
+    #[test]
+    fn test_consumer_initiated_negotiation() {
+        let mut consumer = ConsumerStateMachine::new(CONSUMER, PROVIDER);
+        let mut provider = ProviderStateMachine::new(PROVIDER, CONSUMER);
+        let consumer_pid = consumer.consumer_pid.clone();
+        let provider_pid = provider.provider_pid.clone();
+
+        let request = ContractRequestMessage::new(consumer_pid.clone(), offer(), format!("{}/protocol", CONSUMER));
+        consumer.transition_to_requesting(request.clone()).unwrap();
+        consumer.send_contract_request(&mut provider, request.clone()).unwrap();
+        consumer.transition_to_requested().unwrap();
+
+        assert!(matches!(provider.state, ProviderState::Requested(_)));
+        assert_eq!(Some(consumer_pid.clone()), provider.consumer_pid);
+        assert_eq!(Some(&NegotiationMessage::ContractRequest(request)), consumer.state.message());
+
+        let agreement = agreement(&provider_pid, &consumer_pid);
+        provider.transition_to_agreeing(agreement.clone()).unwrap();
+        provider.send_contract_agreement(&mut consumer, agreement).unwrap();
+        provider.transition_to_agreed().unwrap();
+
+        assert!(matches!(consumer.state, ConsumerState::Agreed(_)));
+        assert_eq!(Some(provider_pid.clone()), consumer.provider_pid);
+
+        let verification = ContractAgreementVerificationMessage::new(provider_pid.clone(), consumer_pid.clone());
+        consumer.transition_to_verifying(verification.clone()).unwrap();
+        consumer.send_contract_agreement_verification(&mut provider, verification).unwrap();
+        consumer.transition_to_verified().unwrap();
+
+        let finalized = ContractNegotiationEventMessage::new(provider_pid, consumer_pid, NegotiationEventType::Finalized);
+        provider.transition_to_finalizing(finalized.clone()).unwrap();
+        provider.send_contract_negotiation_event(&mut consumer, finalized).unwrap();
+        provider.transition_to_finalized().unwrap();
+
+        assert!(consumer.state.is_final());
+        assert!(provider.state.is_final());
+        assert_eq!("Finalized", consumer.state.name());
+        assert_eq!("Finalized", provider.state.name());
+    }
+
+    #[test]
+    fn test_invalid_transitions_are_errors() {
+        let mut consumer = ConsumerStateMachine::new(CONSUMER, PROVIDER);
+
+        assert_eq!(
+            Err(StateMachineError::InvalidTransition { state: "Initial", target: "Requested" }),
+            consumer.transition_to_requested(),
+        );
+
+        // An agreement cannot be received before anything was requested, the state stays untouched
+        let error = consumer.receive(agreement("provider-pid", &consumer.consumer_pid.clone()).into()).unwrap_err();
+        assert_eq!(StateMachineError::UnexpectedMessage { state: "Initial", message: "ContractAgreementMessage" }, error);
+        assert!(matches!(consumer.state, ConsumerState::Initial(_)));
+        assert_eq!(None, consumer.provider_pid);
+
+        // Consumers only accept offers, they never finalize
+        let mut provider = ProviderStateMachine::new(PROVIDER, CONSUMER);
+        let contract_offer = ContractOfferMessage::new(provider.provider_pid.clone(), offer());
+        provider.transition_to_offering(contract_offer.clone()).unwrap();
+        provider.send_contract_offer(&mut consumer, contract_offer).unwrap();
+        provider.transition_to_offered().unwrap();
+
+        let finalized = ContractNegotiationEventMessage::new(provider.provider_pid.clone(), consumer.consumer_pid.clone(), NegotiationEventType::Finalized);
+        assert!(consumer.transition_to_accepting(finalized.clone()).is_err());
+        assert!(provider.receive(finalized.into()).is_err());
+        assert!(matches!(provider.state, ProviderState::Offered(_)));
+    }
+
+    #[test]
+    fn test_messages_of_other_negotiations_are_rejected() {
+        let mut consumer = ConsumerStateMachine::new(CONSUMER, PROVIDER);
+        let mut provider = ProviderStateMachine::new(PROVIDER, CONSUMER);
+        let consumer_pid = consumer.consumer_pid.clone();
+
+        let request = ContractRequestMessage::new("other-consumer-pid".to_string(), offer(), format!("{}/protocol", CONSUMER));
+        assert!(matches!(consumer.transition_to_requesting(request), Err(StateMachineError::ProcessIdMismatch { .. })));
+
+        let request = ContractRequestMessage::new(consumer_pid.clone(), offer(), format!("{}/protocol", CONSUMER));
+        consumer.transition_to_requesting(request.clone()).unwrap();
+        consumer.send_contract_request(&mut provider, request).unwrap();
+        consumer.transition_to_requested().unwrap();
+
+        // The provider pid is learned from the first message of the provider
+        consumer.receive(agreement("other-provider-pid", &consumer_pid).into()).unwrap();
+
+        let termination = ContractNegotiationTerminationMessage::new("another-provider-pid".to_string(), consumer_pid.clone());
+        assert_eq!(
+            Err(StateMachineError::ProcessIdMismatch { expected: "other-provider-pid".to_string(), actual: "another-provider-pid".to_string() }),
+            consumer.receive(termination.into()),
+        );
+
+        let termination = ContractNegotiationTerminationMessage::new(provider.provider_pid.clone(), "other-consumer-pid".to_string());
+        assert!(matches!(provider.receive(termination.into()), Err(StateMachineError::ProcessIdMismatch { .. })));
+
+        let termination = ContractNegotiationTerminationMessage::new(provider.provider_pid.clone(), consumer_pid);
+        provider.receive(termination.into()).unwrap();
+        assert!(matches!(provider.state, ProviderState::Terminated(_)));
+    }
+
+    type Envelope = (NegotiationMessage, oneshot::Sender<Result<(), StateMachineError>>);
+
+    /// Provider runtime that owns its state machine in a task and answers every message like a protocol endpoint would
+    fn spawn_provider(mut provider: ProviderStateMachine<ProviderState>) -> (mpsc::Sender<Envelope>, tokio::task::JoinHandle<ProviderStateMachine<ProviderState>>) {
+        let (sender, mut receiver) = mpsc::channel::<Envelope>(8);
+        let handle = tokio::spawn(async move {
+            while let Some((message, reply)) = receiver.recv().await {
+                let _ = reply.send(provider.receive(message));
+                if provider.state.is_final() {
+                    break;
+                }
+            }
+            provider
+        });
+        (sender, handle)
+    }
+
+    async fn send(sender: &mpsc::Sender<Envelope>, message: impl Into<NegotiationMessage>) -> Result<(), StateMachineError> {
+        let (reply, response) = oneshot::channel();
+        sender.send((message.into(), reply)).await.unwrap();
+        response.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_state_machines_in_async_runtime() {
+        let provider = ProviderStateMachine::new(PROVIDER, CONSUMER);
+        let provider_pid = provider.provider_pid.clone();
+        let (provider_endpoint, provider_task) = spawn_provider(provider);
+
+        let mut consumer = ConsumerStateMachine::new(CONSUMER, PROVIDER);
+        let consumer_pid = consumer.consumer_pid.clone();
+
+        let request = ContractRequestMessage::new(consumer_pid.clone(), offer(), format!("{}/protocol", CONSUMER));
+        consumer.transition_to_requesting(request.clone()).unwrap();
+        send(&provider_endpoint, request).await.unwrap();
+        consumer.transition_to_requested().unwrap();
+
+        // Verifying before anything was agreed is rejected by the provider task, which keeps running
+        let verification = ContractAgreementVerificationMessage::new(provider_pid.clone(), consumer_pid.clone());
+        assert!(matches!(send(&provider_endpoint, verification).await, Err(StateMachineError::UnexpectedMessage { state: "Requested", .. })));
+
+        let termination = ContractNegotiationTerminationMessage::new(provider_pid, consumer_pid);
+        consumer.transition_to_terminating(termination.clone()).unwrap();
+        send(&provider_endpoint, termination).await.unwrap();
+        consumer.transition_to_terminated().unwrap();
+
+        let provider = provider_task.await.unwrap();
+        assert!(matches!(provider.state, ProviderState::Terminated(_)));
+        assert!(matches!(consumer.state, ConsumerState::Terminated(_)));
+    }
+
+}
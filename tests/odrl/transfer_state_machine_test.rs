@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod transfer_state_machine_test {
+    extern crate edc_api;
+    extern crate odrl;
+
+    use edc_api::dsp::{TransferCompletionMessage, TransferRequestMessage, TransferStartMessage, TransferSuspensionMessage, TransferTerminationMessage};
+    use edc_api::transfer_state::TransferProcessState;
+    use odrl::functions::state_machine::StateMachineError;
+    use odrl::functions::transfer_state_machine::{is_legal_transition, is_reachable, TransferRole, TransferStateMachine};
+
+    #[test]
+    fn test_consumer_transfer() {
+        let mut consumer = TransferStateMachine::new(TransferRole::Consumer);
+        let consumer_pid = consumer.consumer_pid.clone().unwrap();
+
+        for state in [TransferProcessState::Provisioning, TransferProcessState::Provisioned, TransferProcessState::Requesting, TransferProcessState::Requested] {
+            consumer.transition_to(state).unwrap();
+        }
+
+        consumer.receive_transfer_start(TransferStartMessage::new("provider-pid".to_string(), consumer_pid.clone())).unwrap();
+        assert_eq!(TransferProcessState::Started, consumer.state);
+        assert_eq!(Some("provider-pid".to_string()), consumer.provider_pid);
+
+        consumer.suspend().unwrap();
+        consumer.transition_to(TransferProcessState::Suspended).unwrap();
+
+        // The provider resumes the transfer with another start message
+        consumer.receive_transfer_start(TransferStartMessage::new("provider-pid".to_string(), consumer_pid.clone())).unwrap();
+        assert_eq!(TransferProcessState::Started, consumer.state);
+
+        consumer.receive_transfer_completion(TransferCompletionMessage::new("provider-pid".to_string(), consumer_pid)).unwrap();
+        assert_eq!(TransferProcessState::Completed, consumer.state);
+        assert!(consumer.is_final());
+
+        consumer.transition_to(TransferProcessState::Deprovisioning).unwrap();
+        consumer.transition_to(TransferProcessState::Deprovisioned).unwrap();
+    }
+
+    #[test]
+    fn test_provider_transfer() {
+        let mut provider = TransferStateMachine::new(TransferRole::Provider);
+        let provider_pid = provider.provider_pid.clone().unwrap();
+
+        provider.receive_transfer_request(TransferRequestMessage::new("consumer-pid".to_string(), "agreement-id".to_string(), "http://consumer/protocol".to_string())).unwrap();
+        assert_eq!(TransferProcessState::Initial, provider.state);
+        assert_eq!(Some("consumer-pid".to_string()), provider.consumer_pid);
+
+        // Only consumers request transfers
+        assert_eq!(
+            Err(StateMachineError::InvalidTransition { state: "PROVISIONED", target: "REQUESTING" }),
+            TransferStateMachine::with_state(TransferRole::Provider, TransferProcessState::Provisioned).transition_to(TransferProcessState::Requesting),
+        );
+
+        for state in [TransferProcessState::Provisioning, TransferProcessState::Provisioned, TransferProcessState::Starting, TransferProcessState::Started] {
+            provider.transition_to(state).unwrap();
+        }
+
+        provider.receive_transfer_suspension(TransferSuspensionMessage::new(provider_pid.clone(), "consumer-pid".to_string())).unwrap();
+        assert_eq!(TransferProcessState::Suspended, provider.state);
+
+        // Messages of other transfer processes are rejected without changing the state
+        let termination = TransferTerminationMessage::new(provider_pid.clone(), "other-consumer-pid".to_string());
+        assert!(matches!(provider.receive_transfer_termination(termination), Err(StateMachineError::ProcessIdMismatch { .. })));
+        assert_eq!(TransferProcessState::Suspended, provider.state);
+
+        provider.receive_transfer_termination(TransferTerminationMessage::new(provider_pid.clone(), "consumer-pid".to_string())).unwrap();
+        assert_eq!(TransferProcessState::Terminated, provider.state);
+
+        // Terminated transfers cannot be completed anymore
+        assert_eq!(
+            Err(StateMachineError::UnexpectedMessage { state: "TERMINATED", message: "TransferCompletionMessage" }),
+            provider.receive_transfer_completion(TransferCompletionMessage::new(provider_pid, "consumer-pid".to_string())),
+        );
+        assert!(provider.complete().is_err());
+        assert!(provider.resume().is_err());
+    }
+
+    #[test]
+    fn test_resume_transfer() {
+        for role in [TransferRole::Consumer, TransferRole::Provider] {
+            let mut machine = TransferStateMachine::with_state(role, TransferProcessState::Started);
+            let provider_pid = machine.provider_pid.clone().unwrap_or("provider-pid".to_string());
+            let consumer_pid = machine.consumer_pid.clone().unwrap_or("consumer-pid".to_string());
+
+            machine.suspend().unwrap();
+            machine.transition_to(TransferProcessState::Suspended).unwrap();
+            machine.resume().unwrap();
+            assert_eq!(TransferProcessState::Resuming, machine.state);
+
+            machine.receive_transfer_start(TransferStartMessage::new(provider_pid, consumer_pid)).unwrap();
+            assert_eq!(TransferProcessState::Started, machine.state);
+            assert!(is_legal_transition(role, TransferProcessState::Resuming, TransferProcessState::Started));
+            assert!(is_legal_transition(role, TransferProcessState::Resumed, TransferProcessState::Started));
+        }
+    }
+
+    #[test]
+    fn test_legal_transitions() {
+        use TransferProcessState::*;
+
+        assert!(is_legal_transition(TransferRole::Consumer, Requested, Started));
+        assert!(is_legal_transition(TransferRole::Consumer, Requesting, Requesting));
+        assert!(!is_legal_transition(TransferRole::Consumer, Requested, Completed));
+        assert!(!is_legal_transition(TransferRole::Consumer, Completed, Started));
+        assert!(!is_legal_transition(TransferRole::Provider, Provisioned, Requesting));
+
+        // Polling may skip states, but can never go back
+        assert!(is_reachable(TransferRole::Consumer, Requested, Completed));
+        assert!(is_reachable(TransferRole::Provider, Initial, Deprovisioned));
+        assert!(!is_reachable(TransferRole::Consumer, Completed, Started));
+        assert!(!is_reachable(TransferRole::Provider, Initial, Requested));
+    }
+
+    #[test]
+    fn test_observe_reported_states() {
+        let mut consumer = TransferStateMachine::with_state(TransferRole::Consumer, TransferProcessState::Requested);
+
+        consumer.observe(TransferProcessState::Requested).unwrap();
+        consumer.observe(TransferProcessState::Suspended).unwrap();
+        consumer.observe(TransferProcessState::Completed).unwrap();
+
+        assert_eq!(
+            Err(StateMachineError::InvalidTransition { state: "COMPLETED", target: "STARTED" }),
+            consumer.observe(TransferProcessState::Started),
+        );
+        assert_eq!(TransferProcessState::Completed, consumer.state);
+    }
+
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod validator_test {
+    extern crate odrl;
+
+    use odrl::functions::validator::{Severity, Validator};
+
+    #[test]
+    fn test_validate_valid_policy_and_constraints() {
+        let json_policy_and_constraints = r#"
+        [{
+            "@context": {
+                "@vocab": "https://w3id.org/edc/v0.0.1/ns/"
+            },
+            "@id": "definition-id",
+            "policy": {
+                "@context": "http://www.w3.org/ns/odrl.jsonld",
+                "@type": "Set",
+                "uid": "https://example.com/policy:1010",
+                "permission": [{
+                    "target": "https://example.com/book/1999",
+                    "assigner": "https://example.com/org/paisley-park",
+                    "action": [{
+                        "rdf:value": { "@id": "odrl:reproduce" },
+                        "refinement": {
+                            "xone": {
+                                "@list": [
+                                    { "@id": "https://example.com/p:88/C1" },
+                                    { "@id": "https://example.com/p:88/C2" }
+                                ]
+                            }
+                        }
+                    }]
+                }],
+                "prohibition": [{
+                    "target": "https://example.com/photoAlbum:55",
+                    "action": "archive",
+                    "assigner": "https://example.com/MyPix:55",
+                    "assignee": "https://example.com/assignee:55"
+                }]
+            }
+        },
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Constraint",
+            "uid": "https://example.com/p:88/C1",
+            "leftOperand": "media",
+            "operator": "eq",
+            "rightOperand": { "@value": "online", "@type": "xsd:string" }
+        }]
+        "#;
+
+        let report = Validator::new().validate(json_policy_and_constraints);
+        assert!(report.is_valid(), "{:?}", report);
+        assert!(report.violations.is_empty());
+    }
+
+    #[test]
+    fn test_validate_reports_every_violation_with_path() {
+        let json_offer_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Offer",
+            "uid": "policy:1011",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play"
+            }, {
+                "target": "https://example.com/asset:9898.movie",
+                "assigner": "https://example.com/party:org:abc",
+                "action": "fly",
+                "constraint": [{
+                    "leftOperand": "dateTime",
+                    "operator": "before",
+                    "rightOperand": { "@value": "2025-12-31", "@type": "xsd:date" }
+                }]
+            }],
+            "conflict": "maybe"
+        }
+        "#;
+
+        let report = Validator::new().validate(json_offer_policy);
+        assert!(!report.is_valid());
+
+        let paths: Vec<&str> = report.errors().map(|violation| violation.path.as_str()).collect();
+        assert_eq!(paths, vec![
+            "$.uid",
+            "$.permission[0]",
+            "$.permission[1].action",
+            "$.permission[1].constraint[0].operator",
+            "$.conflict",
+        ]);
+        assert!(report.violations[1].rule.contains("Offer MUST have one assigner"));
+    }
+
+    #[test]
+    fn test_validate_nested_policy_with_its_type() {
+        let json_definition = r#"
+        {
+            "@context": {
+                "@vocab": "https://w3id.org/edc/v0.0.1/ns/"
+            },
+            "@id": "definition-id",
+            "policy": {
+                "@context": "http://www.w3.org/ns/odrl.jsonld",
+                "@type": "Offer",
+                "uid": "https://example.com/policy:1012",
+                "permission": [{
+                    "target": "https://example.com/asset:9898.movie",
+                    "action": "play"
+                }]
+            }
+        }
+        "#;
+
+        let report = Validator::new().validate(json_definition);
+        assert!(!report.is_valid());
+        let paths: Vec<&str> = report.errors().map(|violation| violation.path.as_str()).collect();
+        assert_eq!(paths, vec!["$.policy.permission[0]"]);
+        assert!(report.violations[0].rule.contains("Offer MUST have one assigner"));
+
+        // Without a type the nested policy is only checked as a plain policy
+        let report = Validator::new().validate(&json_definition.replace(r#""@type": "Offer","#, ""));
+        assert!(report.is_valid(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_validate_untyped_literal_is_warning() {
+        let json_set_policy = r#"
+        {
+            "@context": "http://www.w3.org/ns/odrl.jsonld",
+            "@type": "Set",
+            "uid": "https://example.com/policy:1010",
+            "permission": [{
+                "target": "https://example.com/asset:9898.movie",
+                "action": "play",
+                "constraint": [{
+                    "leftOperand": "media",
+                    "operator": "eq",
+                    "rightOperand": "online"
+                }]
+            }]
+        }
+        "#;
+
+        let report = Validator::new().validate(json_set_policy);
+        assert!(report.is_valid());
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].severity, Severity::Warning);
+        assert_eq!(report.violations[0].path, "$.permission[0].constraint[0].rightOperand");
+    }
+
+    #[test]
+    fn test_validate_malformed_input() {
+        let validator = Validator::new();
+
+        let report = validator.validate("{ \"@type\": \"Set\", ");
+        assert!(!report.is_valid());
+        assert_eq!(report.violations[0].path, "$");
+
+        // Unexpected shapes must not panic
+        let report = validator.validate(r#"[42, { "@context": 1, "@type": "Set", "uid": {}, "permission": "use", "profile": [null] }]"#);
+        assert!(!report.is_valid());
+        let paths: Vec<&str> = report.errors().map(|violation| violation.path.as_str()).collect();
+        assert_eq!(paths, vec!["$[0]", "$[1].@context", "$[1].uid", "$[1].permission", "$[1].profile[0]"]);
+    }
+
+}